@@ -0,0 +1,207 @@
+//! Alternative representations of the same data, which RFC 6350 ties together with the ALTID parameter, e.g. a name in several languages or its pronunciation.
+
+use crate::{property::Property, values::LanguageTag, vcard::VCard};
+
+/// Checks whether a property has the given language by a case-insensitive comparison.
+#[inline]
+fn has_language(language: Option<&LanguageTag>, range: &str) -> bool {
+    language.is_some_and(|language| language.as_str().eq_ignore_ascii_case(range))
+}
+
+/// Removes the last subtag of a language range for the lookup of RFC 4647, which also removes a singleton that would be left at the end, e.g. `zh-Hant-x-a` becomes `zh-Hant`.
+fn truncate_range(range: &str) -> Option<&str> {
+    let (range, _) = range.rsplit_once('-')?;
+
+    match range.rsplit_once('-') {
+        Some((rest, singleton)) if singleton.len() == 1 => Some(rest),
+        _ => Some(range),
+    }
+}
+
+impl VCard {
+    /// Groups properties by their ALTID parameters in the order of their first appearance, where a property without ALTID is a group of its own.
+    ///
+    /// ```rust
+    /// use vcard::{Title, VCard};
+    ///
+    /// let mut vcard = VCard::new("Magic Len");
+    ///
+    /// vcard.titles.push(Title::new(String::from("Engineer")));
+    /// vcard.titles.push(Title::new(String::from("Manager")));
+    ///
+    /// VCard::add_alternative(
+    ///     &mut vcard.titles,
+    ///     0,
+    ///     Title::new(String::from("工程師")),
+    /// );
+    ///
+    /// let groups = VCard::group_by_altid(&vcard.titles);
+    ///
+    /// assert_eq!(2, groups.len());
+    /// assert_eq!(
+    ///     ["Engineer", "工程師"],
+    ///     groups[0]
+    ///         .iter()
+    ///         .map(|title| title.value.as_str())
+    ///         .collect::<Vec<_>>()
+    ///         .as_slice()
+    /// );
+    /// ```
+    pub fn group_by_altid<V>(properties: &[Property<V>]) -> Vec<Vec<&Property<V>>> {
+        let mut groups: Vec<Vec<&Property<V>>> = Vec::new();
+
+        for property in properties {
+            let group = property.parameters.altid.as_ref().and_then(|altid| {
+                groups.iter_mut().find(|group| group[0].parameters.altid.as_ref() == Some(altid))
+            });
+
+            match group {
+                Some(group) => group.push(property),
+                None => groups.push(vec![property]),
+            }
+        }
+
+        groups
+    }
+
+    /// Returns the language of a property, which is its LANGUAGE parameter or else the LANGUAGE property of this vCard.
+    #[inline]
+    pub fn language_of<'a, V>(&'a self, property: &'a Property<V>) -> Option<&'a LanguageTag> {
+        property
+            .parameters
+            .language
+            .as_ref()
+            .or_else(|| self.language.as_ref().map(|language| &language.value))
+    }
+
+    /// Chooses the representation of a group of alternatives that best fits a list of language ranges in the order of preference, skipping the phonetic ones.
+    ///
+    /// Each range is looked up as RFC 4647 describes, so `zh-Hant-TW` falls back to `zh-Hant` and then `zh`. When no range matches, a representation without a language is chosen, or else the first one.
+    pub fn select_alternative<'a, V>(
+        &'a self,
+        alternatives: &[&'a Property<V>],
+        languages: &[&str],
+    ) -> Option<&'a Property<V>> {
+        let candidates: Vec<&'a Property<V>> = alternatives
+            .iter()
+            .copied()
+            .filter(|property| property.parameters.phonetic.is_none())
+            .collect();
+
+        for range in languages {
+            let mut range = Some(*range);
+
+            while let Some(r) = range {
+                if let Some(property) =
+                    candidates.iter().find(|property| has_language(self.language_of(property), r))
+                {
+                    return Some(property);
+                }
+
+                range = truncate_range(r);
+            }
+        }
+
+        candidates
+            .iter()
+            .find(|property| self.language_of(property).is_none())
+            .or_else(|| candidates.first())
+            .copied()
+    }
+
+    /// Chooses the best representation of every ALTID group with [`VCard::select_alternative`], in the order of the groups.
+    ///
+    /// ```rust
+    /// use vcard::{FormattedName, VCard};
+    ///
+    /// let mut vcard = VCard::new("Magic Len");
+    ///
+    /// vcard.formatted_names[0].parameters.language = Some("en".parse().unwrap());
+    ///
+    /// let mut chinese = FormattedName::new(String::from("萌萌哒"));
+    ///
+    /// chinese.parameters.language = Some("zh-Hant".parse().unwrap());
+    ///
+    /// VCard::add_alternative(&mut vcard.formatted_names, 0, chinese);
+    ///
+    /// let names = vcard.localized(&vcard.formatted_names, &["zh-Hant-TW", "en"]);
+    ///
+    /// assert_eq!("萌萌哒", names[0].value);
+    /// ```
+    pub fn localized<'a, V>(
+        &'a self,
+        properties: &'a [Property<V>],
+        languages: &[&str],
+    ) -> Vec<&'a Property<V>> {
+        Self::group_by_altid(properties)
+            .iter()
+            .filter_map(|group| self.select_alternative(group, languages))
+            .collect()
+    }
+
+    /// Returns the phonetic representation of a property, which has the same ALTID and the PHONETIC parameter, e.g. the pronunciation of an N property as RFC 9554 defines.
+    ///
+    /// When there are several, the one with the same language as the property is preferred.
+    pub fn phonetic_of<'a, V>(
+        &'a self,
+        properties: &'a [Property<V>],
+        property: &Property<V>,
+    ) -> Option<&'a Property<V>> {
+        let altid = property.parameters.altid.as_ref()?;
+
+        let mut phonetics = properties.iter().filter(|p| {
+            p.parameters.phonetic.is_some() && p.parameters.altid.as_ref() == Some(altid)
+        });
+
+        let language = property.parameters.language.as_ref();
+
+        phonetics
+            .clone()
+            .find(|p| language.is_some() && p.parameters.language.as_ref() == language)
+            .or_else(|| phonetics.next())
+    }
+
+    /// Adds an alternative representation of the property at `index`, giving both the same ALTID, and returns the ALTID.
+    ///
+    /// If the property has no ALTID yet, the smallest positive number that is not used by the other properties becomes its ALTID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn add_alternative<V>(
+        properties: &mut Vec<Property<V>>,
+        index: usize,
+        mut alternative: Property<V>,
+    ) -> String {
+        let altid = match &properties[index].parameters.altid {
+            Some(altid) => altid.clone(),
+            None => {
+                let altid = (1..)
+                    .map(|n: usize| n.to_string())
+                    .find(|altid| {
+                        !properties
+                            .iter()
+                            .any(|property| property.parameters.altid.as_ref() == Some(altid))
+                    })
+                    // The numbers never run out before the properties do.
+                    .unwrap();
+
+                properties[index].parameters.altid = Some(altid.clone());
+
+                altid
+            },
+        };
+
+        alternative.parameters.altid = Some(altid.clone());
+
+        // The alternatives are kept together after the last one of their group.
+        let position = properties
+            .iter()
+            .rposition(|property| property.parameters.altid.as_ref() == Some(&altid))
+            .map_or(properties.len(), |i| i + 1);
+
+        properties.insert(position, alternative);
+
+        altid
+    }
+}
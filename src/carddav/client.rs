@@ -0,0 +1,468 @@
+//! The CardDAV client, covering discovery, listing, fetching, writing and synchronization.
+
+use url::Url;
+
+use super::{
+    CardDavError, Request, Transport,
+    xml::{self, CARDDAV, DAV, Element},
+};
+use crate::vcard::VCard;
+
+const XML_DECLARATION: &str = "<?xml version=\"1.0\" encoding=\"utf-8\"?>";
+
+const NAMESPACES: &str = "xmlns:d=\"DAV:\" xmlns:c=\"urn:ietf:params:xml:ns:carddav\"";
+
+/// An address book collection found on the server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddressBook {
+    /// The URL of the collection.
+    pub url:          Url,
+    /// The DAV:displayname property.
+    pub display_name: Option<String>,
+    /// The CARDDAV:addressbook-description property.
+    pub description:  Option<String>,
+    /// The DAV:sync-token property defined by RFC 6578.
+    pub sync_token:   Option<String>,
+}
+
+/// An address object resource, known by its URL and ETag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Resource {
+    /// The URL of the resource.
+    pub url:  Url,
+    /// The entity tag, including its double quotes, which can be passed to [`Client::put`] and [`Client::delete`].
+    pub etag: Option<String>,
+}
+
+/// An address object resource together with its parsed vCard.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AddressObject {
+    /// The URL of the resource.
+    pub url:   Url,
+    /// The entity tag, including its double quotes.
+    pub etag:  Option<String>,
+    /// The vCard stored in the resource.
+    pub vcard: VCard,
+}
+
+/// The changes reported by a sync-collection report.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncChanges {
+    /// The new token to use for the next synchronization.
+    pub sync_token: String,
+    /// The resources that were created or modified.
+    pub changed:    Vec<Resource>,
+    /// The URLs of the resources that were removed.
+    pub removed:    Vec<Url>,
+}
+
+/// One `response` element of a multistatus body.
+struct DavResponse<'a> {
+    url:    Url,
+    status: Option<u16>,
+    /// The `prop` elements of the propstats whose status is successful.
+    props:  Vec<&'a Element>,
+}
+
+impl<'a> DavResponse<'a> {
+    /// Returns a successfully retrieved property.
+    #[inline]
+    fn prop(&self, namespace: &str, name: &str) -> Option<&'a Element> {
+        self.props.iter().copied().find_map(|prop| prop.child(namespace, name))
+    }
+
+    /// Returns the text of a successfully retrieved property.
+    #[inline]
+    fn prop_text(&self, namespace: &str, name: &str) -> Option<String> {
+        self.prop(namespace, name).map(|element| element.text().trim().to_string())
+    }
+
+    /// Checks whether the resource type of this resource contains the given element.
+    #[inline]
+    fn has_resource_type(&self, namespace: &str, name: &str) -> bool {
+        self.prop(DAV, "resourcetype").is_some_and(|types| types.child(namespace, name).is_some())
+    }
+}
+
+/// Parses the status code out of a status line like `HTTP/1.1 200 OK`.
+fn parse_status_line(line: &str) -> Option<u16> {
+    line.split_whitespace().nth(1)?.parse().ok()
+}
+
+/// Splits a multistatus body into its responses, resolving every href against the request URL.
+fn parse_multistatus<'a>(
+    root: &'a Element,
+    request_url: &Url,
+) -> Result<Vec<DavResponse<'a>>, CardDavError> {
+    if !root.is(DAV, "multistatus") {
+        return Err(CardDavError::InvalidResponse("the root element is not a multistatus"));
+    }
+
+    let mut responses = Vec::new();
+
+    for response in root.children_named(DAV, "response") {
+        let href = response
+            .child(DAV, "href")
+            .ok_or(CardDavError::InvalidResponse("a response has no href"))?
+            .text();
+
+        let url = request_url
+            .join(href.trim())
+            .map_err(|_| CardDavError::InvalidResponse("a response has an invalid href"))?;
+
+        let status = match response.child(DAV, "status") {
+            Some(status) => Some(
+                parse_status_line(&status.text())
+                    .ok_or(CardDavError::InvalidResponse("a response has an invalid status"))?,
+            ),
+            None => None,
+        };
+
+        let props = response
+            .children_named(DAV, "propstat")
+            .filter(|propstat| {
+                propstat
+                    .child(DAV, "status")
+                    .and_then(|status| parse_status_line(&status.text()))
+                    .is_some_and(|code| (200..300).contains(&code))
+            })
+            .filter_map(|propstat| propstat.child(DAV, "prop"))
+            .collect();
+
+        responses.push(DavResponse {
+            url,
+            status,
+            props,
+        });
+    }
+
+    Ok(responses)
+}
+
+/// Turns a status code that an operation does not accept into an error.
+#[inline]
+fn status_error(status: u16) -> CardDavError {
+    if status == 412 {
+        CardDavError::PreconditionFailed
+    } else {
+        CardDavError::UnexpectedStatus(status)
+    }
+}
+
+/// Parses the body of an address object.
+fn parse_vcard(data: &str) -> Result<VCard, CardDavError> {
+    data.parse().map_err(CardDavError::InvalidVCard)
+}
+
+/// A CardDAV client that sends its requests through a [`Transport`].
+///
+/// Every URL taken or returned by the methods is absolute, and relative hrefs in responses are resolved against the URL of the request.
+#[derive(Debug)]
+pub struct Client<T> {
+    transport: T,
+    base:      Url,
+}
+
+impl<T: Transport> Client<T> {
+    /// Creates a client for the server at the given base URL, which is where the discovery starts.
+    #[inline]
+    pub fn new(transport: T, base: Url) -> Self {
+        Self {
+            transport,
+            base,
+        }
+    }
+
+    /// Returns the base URL.
+    #[inline]
+    pub fn base(&self) -> &Url {
+        &self.base
+    }
+
+    /// Returns a reference to the transport.
+    #[inline]
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    /// Returns a mutable reference to the transport.
+    #[inline]
+    pub fn transport_mut(&mut self) -> &mut T {
+        &mut self.transport
+    }
+
+    /// Consumes this client and returns the transport.
+    #[inline]
+    pub fn into_transport(self) -> T {
+        self.transport
+    }
+
+    /// Finds the URL of the principal of the current user, using the DAV:current-user-principal property of the base URL.
+    pub fn current_user_principal(&mut self) -> Result<Url, CardDavError> {
+        let base = self.base.clone();
+
+        self.find_href(&base, "<d:current-user-principal/>", DAV, "current-user-principal")
+    }
+
+    /// Finds the collection that contains the address books of a principal, using the CARDDAV:addressbook-home-set property.
+    pub fn address_book_home_set(&mut self, principal: &Url) -> Result<Url, CardDavError> {
+        self.find_href(principal, "<c:addressbook-home-set/>", CARDDAV, "addressbook-home-set")
+    }
+
+    /// Lists the address books inside an address book home collection.
+    pub fn address_books(&mut self, home: &Url) -> Result<Vec<AddressBook>, CardDavError> {
+        let root = self.propfind(
+            home,
+            1,
+            "<d:resourcetype/><d:displayname/><c:addressbook-description/><d:sync-token/>",
+        )?;
+
+        Ok(parse_multistatus(&root, home)?
+            .into_iter()
+            .filter(|response| response.has_resource_type(CARDDAV, "addressbook"))
+            .map(|response| AddressBook {
+                display_name: response.prop_text(DAV, "displayname"),
+                description:  response.prop_text(CARDDAV, "addressbook-description"),
+                sync_token:   response.prop_text(DAV, "sync-token"),
+                url:          response.url,
+            })
+            .collect())
+    }
+
+    /// Lists the address object resources of an address book together with their ETags.
+    pub fn list(&mut self, address_book: &Url) -> Result<Vec<Resource>, CardDavError> {
+        let root = self.propfind(address_book, 1, "<d:resourcetype/><d:getetag/>")?;
+
+        Ok(parse_multistatus(&root, address_book)?
+            .into_iter()
+            .filter(|response| {
+                response.url != *address_book && !response.has_resource_type(DAV, "collection")
+            })
+            .map(|response| Resource {
+                etag: response.prop_text(DAV, "getetag"),
+                url:  response.url,
+            })
+            .collect())
+    }
+
+    /// Fetches a single address object resource.
+    pub fn get(&mut self, url: &Url) -> Result<AddressObject, CardDavError> {
+        let mut request = Request::new("GET", url.as_str());
+
+        request.headers.push((String::from("Accept"), String::from("text/vcard")));
+
+        let response = self.transport.send(request)?;
+
+        if response.status != 200 {
+            return Err(status_error(response.status));
+        }
+
+        let data = std::str::from_utf8(&response.body)
+            .map_err(|_| CardDavError::InvalidResponse("the body is not UTF-8"))?;
+
+        Ok(AddressObject {
+            url:   url.clone(),
+            etag:  response.header("ETag").map(String::from),
+            vcard: parse_vcard(data)?,
+        })
+    }
+
+    /// Fetches several address object resources of an address book with one addressbook-multiget report.
+    ///
+    /// Resources that the server cannot return, e.g. because they were removed in the meantime, are left out.
+    pub fn multiget(
+        &mut self,
+        address_book: &Url,
+        urls: &[Url],
+    ) -> Result<Vec<AddressObject>, CardDavError> {
+        let mut body = format!(
+            "{XML_DECLARATION}<c:addressbook-multiget \
+             {NAMESPACES}><d:prop><d:getetag/><c:address-data/></d:prop>"
+        );
+
+        for url in urls {
+            body.push_str("<d:href>");
+            body.push_str(&xml::escape(url.path()));
+            body.push_str("</d:href>");
+        }
+
+        body.push_str("</c:addressbook-multiget>");
+
+        let root = self.dav_request("REPORT", address_book, 1, body)?;
+
+        let mut objects = Vec::new();
+
+        for response in parse_multistatus(&root, address_book)? {
+            let Some(data) = response.prop(CARDDAV, "address-data") else {
+                continue;
+            };
+
+            objects.push(AddressObject {
+                etag:  response.prop_text(DAV, "getetag"),
+                vcard: parse_vcard(&data.text())?,
+                url:   response.url,
+            });
+        }
+
+        Ok(objects)
+    }
+
+    /// Stores a vCard at the given URL and returns the new ETag if the server tells it.
+    ///
+    /// With an ETag, the resource is only replaced when it still has that ETag.
+    /// Without one, the resource is only created when it does not exist yet.
+    /// In both cases a failed condition gives [`CardDavError::PreconditionFailed`].
+    pub fn put(
+        &mut self,
+        url: &Url,
+        vcard: &VCard,
+        if_match: Option<&str>,
+    ) -> Result<Option<String>, CardDavError> {
+        let mut request = Request::new("PUT", url.as_str());
+
+        request
+            .headers
+            .push((String::from("Content-Type"), String::from("text/vcard; charset=utf-8")));
+
+        match if_match {
+            Some(etag) => request.headers.push((String::from("If-Match"), etag.to_string())),
+            None => request.headers.push((String::from("If-None-Match"), String::from("*"))),
+        }
+
+        request.body = vcard.to_string().into_bytes();
+
+        let response = self.transport.send(request)?;
+
+        if !matches!(response.status, 200 | 201 | 204) {
+            return Err(status_error(response.status));
+        }
+
+        Ok(response.header("ETag").map(String::from))
+    }
+
+    /// Removes the resource at the given URL, only when it still has the given ETag if one is provided.
+    pub fn delete(&mut self, url: &Url, if_match: Option<&str>) -> Result<(), CardDavError> {
+        let mut request = Request::new("DELETE", url.as_str());
+
+        if let Some(etag) = if_match {
+            request.headers.push((String::from("If-Match"), etag.to_string()));
+        }
+
+        let response = self.transport.send(request)?;
+
+        if !matches!(response.status, 200 | 202 | 204) {
+            return Err(status_error(response.status));
+        }
+
+        Ok(())
+    }
+
+    /// Asks for the changes of an address book since the given sync token, using the sync-collection report of RFC 6578.
+    ///
+    /// Without a token, every resource of the address book is reported as changed.
+    pub fn sync_collection(
+        &mut self,
+        address_book: &Url,
+        sync_token: Option<&str>,
+    ) -> Result<SyncChanges, CardDavError> {
+        let mut body = format!("{XML_DECLARATION}<d:sync-collection {NAMESPACES}><d:sync-token>");
+
+        if let Some(sync_token) = sync_token {
+            body.push_str(&xml::escape(sync_token));
+        }
+
+        body.push_str(
+            "</d:sync-token><d:sync-level>1</d:sync-level><d:prop><d:getetag/></d:prop></d:\
+             sync-collection>",
+        );
+
+        let root = self.dav_request("REPORT", address_book, 0, body)?;
+
+        let sync_token = root
+            .child(DAV, "sync-token")
+            .ok_or(CardDavError::InvalidResponse("the multistatus has no sync-token"))?
+            .text()
+            .trim()
+            .to_string();
+
+        let mut changes = SyncChanges {
+            sync_token,
+            changed: Vec::new(),
+            removed: Vec::new(),
+        };
+
+        for response in parse_multistatus(&root, address_book)? {
+            if response.status == Some(404) {
+                changes.removed.push(response.url);
+            } else if response.url != *address_book {
+                changes.changed.push(Resource {
+                    etag: response.prop_text(DAV, "getetag"),
+                    url:  response.url,
+                });
+            }
+        }
+
+        Ok(changes)
+    }
+
+    /// Sends a PROPFIND request asking for the given properties.
+    fn propfind(&mut self, url: &Url, depth: u8, props: &str) -> Result<Element, CardDavError> {
+        let body = format!(
+            "{XML_DECLARATION}<d:propfind {NAMESPACES}><d:prop>{props}</d:prop></d:propfind>"
+        );
+
+        self.dav_request("PROPFIND", url, depth, body)
+    }
+
+    /// Sends a PROPFIND request for one property that contains an href and resolves that href.
+    fn find_href(
+        &mut self,
+        url: &Url,
+        prop: &str,
+        namespace: &str,
+        name: &str,
+    ) -> Result<Url, CardDavError> {
+        let root = self.propfind(url, 0, prop)?;
+
+        let responses = parse_multistatus(&root, url)?;
+
+        let href = responses
+            .iter()
+            .find_map(|response| response.prop(namespace, name)?.child(DAV, "href"))
+            .ok_or(CardDavError::InvalidResponse("the requested href property is missing"))?
+            .text();
+
+        url.join(href.trim())
+            .map_err(|_| CardDavError::InvalidResponse("the requested href property is invalid"))
+    }
+
+    /// Sends a WebDAV request with an XML body and parses the multistatus body of its response.
+    fn dav_request(
+        &mut self,
+        method: &str,
+        url: &Url,
+        depth: u8,
+        body: String,
+    ) -> Result<Element, CardDavError> {
+        let mut request = Request::new(method, url.as_str());
+
+        request.headers.push((String::from("Depth"), depth.to_string()));
+        request
+            .headers
+            .push((String::from("Content-Type"), String::from("application/xml; charset=utf-8")));
+
+        request.body = body.into_bytes();
+
+        let response = self.transport.send(request)?;
+
+        if response.status != 207 {
+            return Err(status_error(response.status));
+        }
+
+        let body = std::str::from_utf8(&response.body)
+            .map_err(|_| CardDavError::InvalidResponse("the body is not UTF-8"))?;
+
+        xml::parse(body)
+            .map_err(|_| CardDavError::InvalidResponse("the body is not well-formed XML"))
+    }
+}
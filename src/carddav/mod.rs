@@ -0,0 +1,151 @@
+//! CardDAV support based on RFC 6352, enabled by the `carddav` feature.
+//!
+//! The HTTP layer is abstracted by the [`Transport`] trait, so any HTTP library, or an in-process mock server in tests, can carry the requests.
+//! The same abstraction lets the [`Handler`] serve address books from inside any HTTP server.
+
+mod client;
+mod server;
+mod store;
+mod xml;
+
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    io,
+};
+
+pub use client::{AddressBook, AddressObject, Client, Resource, SyncChanges};
+pub use server::Handler;
+pub use store::{FileSystemStore, MemoryStore, Store};
+
+use crate::error::ParseError;
+
+/// An HTTP request, independent of any HTTP library.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    /// The method, e.g. `PROPFIND`.
+    pub method:  String,
+    /// The request target, which is an absolute URL or an absolute path.
+    pub target:  String,
+    /// The header fields as name and value pairs.
+    pub headers: Vec<(String, String)>,
+    /// The body, which is empty when there is no body.
+    pub body:    Vec<u8>,
+}
+
+impl Request {
+    /// Creates a request without headers and without a body.
+    #[inline]
+    pub fn new<M: Into<String>, T: Into<String>>(method: M, target: T) -> Self {
+        Self {
+            method:  method.into(),
+            target:  target.into(),
+            headers: Vec::new(),
+            body:    Vec::new(),
+        }
+    }
+
+    /// Returns the value of the first header field with the given name, which is compared case-insensitively.
+    #[inline]
+    pub fn header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+}
+
+/// An HTTP response, independent of any HTTP library.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    /// The status code, e.g. `207`.
+    pub status:  u16,
+    /// The header fields as name and value pairs.
+    pub headers: Vec<(String, String)>,
+    /// The body, which is empty when there is no body.
+    pub body:    Vec<u8>,
+}
+
+impl Response {
+    /// Creates a response without headers and without a body.
+    #[inline]
+    pub fn new(status: u16) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body: Vec::new(),
+        }
+    }
+
+    /// Returns the value of the first header field with the given name, which is compared case-insensitively.
+    #[inline]
+    pub fn header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+}
+
+#[inline]
+fn find_header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers.iter().find(|(n, _)| n.eq_ignore_ascii_case(name)).map(|(_, value)| value.as_str())
+}
+
+/// Sends HTTP requests on behalf of a CardDAV [`Client`].
+///
+/// Authentication, TLS and connection reuse are all up to the implementation.
+/// A closure taking a [`Request`] and returning a [`Response`] also implements this trait, which is handy for tests.
+pub trait Transport {
+    /// Sends a request and waits for its response.
+    fn send(&mut self, request: Request) -> Result<Response, io::Error>;
+}
+
+impl<F: FnMut(Request) -> Result<Response, io::Error>> Transport for F {
+    #[inline]
+    fn send(&mut self, request: Request) -> Result<Response, io::Error> {
+        self(request)
+    }
+}
+
+/// The error type of CardDAV operations.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum CardDavError {
+    /// The transport failed to send the request or to receive the response.
+    Transport(io::Error),
+    /// The server answered with a status code that the operation does not expect.
+    UnexpectedStatus(u16),
+    /// The server answered `412 Precondition Failed`, which usually means the ETag no longer matches.
+    PreconditionFailed,
+    /// The response does not have the expected structure.
+    InvalidResponse(&'static str),
+    /// An address object returned by the server is not a valid vCard.
+    InvalidVCard(ParseError),
+}
+
+impl Display for CardDavError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Transport(error) => write!(f, "the transport failed: {error}"),
+            Self::UnexpectedStatus(status) => {
+                write!(f, "the server answered with the unexpected status {status}")
+            },
+            Self::PreconditionFailed => f.write_str("the precondition of the request failed"),
+            Self::InvalidResponse(reason) => write!(f, "the response is invalid: {reason}"),
+            Self::InvalidVCard(error) => write!(f, "the address object is invalid: {error}"),
+        }
+    }
+}
+
+impl Error for CardDavError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Transport(error) => Some(error),
+            Self::InvalidVCard(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for CardDavError {
+    #[inline]
+    fn from(error: io::Error) -> Self {
+        Self::Transport(error)
+    }
+}
@@ -0,0 +1,756 @@
+//! The embeddable CardDAV server handler, covering discovery, listing, reports and conditional writes.
+
+use std::{fmt::Write, io};
+
+use super::{
+    Request, Response, Store,
+    xml::{self, CARDDAV, DAV, Element},
+};
+use crate::{
+    filter::{Collation, Filter, FilterTest, MatchType, ParamFilter, PropFilter, TextMatch},
+    values::TextOrUri,
+    vcard::VCard,
+};
+
+/// The content type of the address objects served by the handler.
+const VCARD_CONTENT_TYPE: &str = "text/vcard; charset=utf-8";
+
+/// The properties returned for `allprop` requests, by the kind of resource.
+const HOME_PROPERTIES: [(&str, &str); 3] =
+    [(DAV, "resourcetype"), (DAV, "current-user-principal"), (CARDDAV, "addressbook-home-set")];
+const ADDRESS_BOOK_PROPERTIES: [(&str, &str); 3] =
+    [(DAV, "resourcetype"), (DAV, "displayname"), (CARDDAV, "supported-address-data")];
+const OBJECT_PROPERTIES: [(&str, &str); 4] =
+    [(DAV, "resourcetype"), (DAV, "getetag"), (DAV, "getcontenttype"), (DAV, "getcontentlength")];
+
+/// The resource addressed by a request target.
+enum Target {
+    /// The collection containing all address books, which also serves as the principal.
+    Home,
+    AddressBook(String),
+    Object(String, String),
+}
+
+/// The properties requested by a `PROPFIND` or `REPORT` request.
+enum Requested<'a> {
+    All,
+    Names(Vec<&'a Element>),
+}
+
+/// A CardDAV server handler which turns a [`Request`] into a [`Response`] on top of a [`Store`].
+///
+/// All address books live directly under the root path, e.g. `/addressbooks/contacts/` and `/addressbooks/contacts/len.vcf`.
+/// The root collection is reported as both the current user principal and the address book home set, so clients can discover the address books from the root.
+/// Authentication is left to the embedding HTTP server.
+#[derive(Debug, Clone)]
+pub struct Handler<S> {
+    store: S,
+    root:  String,
+}
+
+impl<S: Store> Handler<S> {
+    /// Creates a handler serving the store under the given root path.
+    #[inline]
+    pub fn new(store: S, root: &str) -> Self {
+        let root = root.trim_matches('/');
+
+        let root = if root.is_empty() { String::from("/") } else { format!("/{root}/") };
+
+        Self {
+            store,
+            root,
+        }
+    }
+
+    /// Returns the root path, which always starts and ends with a slash.
+    #[inline]
+    pub fn root(&self) -> &str {
+        self.root.as_str()
+    }
+
+    /// Returns the store.
+    #[inline]
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Returns the store mutably.
+    #[inline]
+    pub fn store_mut(&mut self) -> &mut S {
+        &mut self.store
+    }
+
+    /// Returns the store, consuming the handler.
+    #[inline]
+    pub fn into_store(self) -> S {
+        self.store
+    }
+
+    /// Handles a request. Failures of the store are answered with `500 Internal Server Error`.
+    pub fn handle(&mut self, request: Request) -> Response {
+        self.dispatch(&request).unwrap_or_else(|_| Response::new(500))
+    }
+
+    fn dispatch(&mut self, request: &Request) -> Result<Response, io::Error> {
+        let Some(target) = self.resolve(&request.target) else {
+            return Ok(Response::new(404));
+        };
+
+        match request.method.to_ascii_uppercase().as_str() {
+            "OPTIONS" => Ok(options()),
+            "PROPFIND" => self.propfind(request, &target),
+            "REPORT" => self.report(request, &target),
+            "GET" => self.get(&target, true),
+            "HEAD" => self.get(&target, false),
+            "PUT" => self.put(request, &target),
+            "DELETE" => self.delete(request, &target),
+            _ => Ok(Response::new(405)),
+        }
+    }
+
+    /// Maps a request target, which is an absolute URL or an absolute path, to a resource.
+    fn resolve(&self, target: &str) -> Option<Target> {
+        let path = request_path(target);
+
+        let rest = path.strip_prefix(self.root.trim_end_matches('/'))?;
+
+        let rest = if rest.is_empty() { rest } else { rest.strip_prefix('/')? };
+
+        if rest.is_empty() {
+            return Some(Target::Home);
+        }
+
+        let (address_book, name) = rest.split_once('/').unwrap_or((rest, ""));
+
+        if !is_valid_name(address_book) {
+            return None;
+        }
+
+        if name.is_empty() {
+            return Some(Target::AddressBook(address_book.to_string()));
+        }
+
+        if !is_valid_name(name) {
+            return None;
+        }
+
+        Some(Target::Object(address_book.to_string(), name.to_string()))
+    }
+
+    /// Returns the path of a resource, with a trailing slash for collections.
+    fn href(&self, target: &Target) -> String {
+        match target {
+            Target::Home => self.root.clone(),
+            Target::AddressBook(address_book) => format!("{}{address_book}/", self.root),
+            Target::Object(address_book, name) => format!("{}{address_book}/{name}", self.root),
+        }
+    }
+
+    /// Checks whether a resource exists in the store.
+    fn exists(&self, target: &Target) -> Result<bool, io::Error> {
+        Ok(match target {
+            Target::Home => true,
+            Target::AddressBook(address_book) => self.store.objects(address_book)?.is_some(),
+            Target::Object(address_book, name) => self.store.get(address_book, name)?.is_some(),
+        })
+    }
+
+    fn get(&self, target: &Target, with_body: bool) -> Result<Response, io::Error> {
+        let Target::Object(address_book, name) = target else {
+            return Ok(Response::new(405));
+        };
+
+        let Some(data) = self.store.get(address_book, name)? else {
+            return Ok(Response::new(404));
+        };
+
+        let mut response = Response::new(200);
+
+        response.headers.push((String::from("Content-Type"), String::from(VCARD_CONTENT_TYPE)));
+        response.headers.push((String::from("ETag"), etag(&data)));
+
+        if with_body {
+            response.body = data.into_bytes();
+        }
+
+        Ok(response)
+    }
+
+    fn put(&mut self, request: &Request, target: &Target) -> Result<Response, io::Error> {
+        let Target::Object(address_book, name) = target else {
+            return Ok(Response::new(405));
+        };
+
+        if self.store.objects(address_book)?.is_none() {
+            return Ok(Response::new(409));
+        }
+
+        let current = self.store.get(address_book, name)?;
+
+        if let Some(response) = check_preconditions(request, current.as_deref()) {
+            return Ok(response);
+        }
+
+        if let Some(content_type) = request.header("Content-Type")
+            && !content_type
+                .split(';')
+                .next()
+                .is_some_and(|media_type| media_type.trim().eq_ignore_ascii_case("text/vcard"))
+        {
+            return Ok(error_response(403, "<C:supported-address-data/>"));
+        }
+
+        let Ok(data) = std::str::from_utf8(&request.body) else {
+            return Ok(error_response(403, "<C:valid-address-data/>"));
+        };
+
+        let Ok(vcard) = data.parse::<VCard>() else {
+            return Ok(error_response(403, "<C:valid-address-data/>"));
+        };
+
+        if let Some(uid) = &vcard.uid
+            && let Some(other) = self.find_uid(address_book, name, &uid.value)?
+        {
+            let href = self.href(&Target::Object(address_book.clone(), other));
+
+            return Ok(error_response(
+                409,
+                &format!(
+                    "<C:no-uid-conflict><D:href>{}</D:href></C:no-uid-conflict>",
+                    xml::escape(&href)
+                ),
+            ));
+        }
+
+        let etag = etag(data);
+
+        self.store.put(address_book, name, data.to_string())?;
+
+        let mut response = Response::new(if current.is_some() { 204 } else { 201 });
+
+        response.headers.push((String::from("ETag"), etag));
+
+        Ok(response)
+    }
+
+    /// Finds another object in the address book whose vCard has the given UID, as the CARDDAV:no-uid-conflict precondition requires.
+    fn find_uid(
+        &self,
+        address_book: &str,
+        name: &str,
+        uid: &TextOrUri,
+    ) -> Result<Option<String>, io::Error> {
+        for other in self.store.objects(address_book)?.unwrap_or_default() {
+            if other == name {
+                continue;
+            }
+
+            let Some(data) = self.store.get(address_book, &other)? else {
+                continue;
+            };
+
+            if data
+                .parse::<VCard>()
+                .is_ok_and(|vcard| vcard.uid.is_some_and(|other_uid| &other_uid.value == uid))
+            {
+                return Ok(Some(other));
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn delete(&mut self, request: &Request, target: &Target) -> Result<Response, io::Error> {
+        let Target::Object(address_book, name) = target else {
+            return Ok(Response::new(405));
+        };
+
+        let Some(current) = self.store.get(address_book, name)? else {
+            return Ok(Response::new(404));
+        };
+
+        if let Some(response) = check_preconditions(request, Some(&current)) {
+            return Ok(response);
+        }
+
+        self.store.delete(address_book, name)?;
+
+        Ok(Response::new(204))
+    }
+
+    fn propfind(&self, request: &Request, target: &Target) -> Result<Response, io::Error> {
+        let Ok(root) = parse_body(request) else {
+            return Ok(Response::new(400));
+        };
+
+        let requested = match &root {
+            None => Requested::All,
+            Some(root) if root.is(DAV, "propfind") => requested_properties(root),
+            Some(_) => return Ok(Response::new(400)),
+        };
+
+        if !self.exists(target)? {
+            return Ok(Response::new(404));
+        }
+
+        let mut out = multistatus_start();
+
+        self.write_response(&mut out, target, &requested)?;
+
+        // A missing Depth means infinity, which is answered like Depth 1 because the hierarchy is only two levels deep.
+        if request.header("Depth").map(str::trim) != Some("0") {
+            match target {
+                Target::Home => {
+                    for address_book in self.store.address_books()? {
+                        self.write_response(
+                            &mut out,
+                            &Target::AddressBook(address_book),
+                            &requested,
+                        )?;
+                    }
+                },
+                Target::AddressBook(address_book) => {
+                    for name in self.store.objects(address_book)?.unwrap_or_default() {
+                        self.write_response(
+                            &mut out,
+                            &Target::Object(address_book.clone(), name),
+                            &requested,
+                        )?;
+                    }
+                },
+                Target::Object(..) => (),
+            }
+        }
+
+        Ok(multistatus_finish(out))
+    }
+
+    fn report(&self, request: &Request, target: &Target) -> Result<Response, io::Error> {
+        let Target::AddressBook(address_book) = target else {
+            return Ok(error_response(403, "<D:supported-report/>"));
+        };
+
+        let Some(objects) = self.store.objects(address_book)? else {
+            return Ok(Response::new(404));
+        };
+
+        let Ok(Some(root)) = parse_body(request) else {
+            return Ok(Response::new(400));
+        };
+
+        let requested = requested_properties(&root);
+
+        let mut out = multistatus_start();
+
+        if root.is(CARDDAV, "addressbook-multiget") {
+            for href in root.children_named(DAV, "href") {
+                let href = href.text();
+                let href = href.trim();
+
+                let object = self.resolve(href);
+
+                let found = match &object {
+                    Some(Target::Object(book, name)) if book == address_book => {
+                        self.store.get(book, name)?.is_some()
+                    },
+                    _ => false,
+                };
+
+                match object {
+                    Some(object) if found => self.write_response(&mut out, &object, &requested)?,
+                    _ => write_missing_response(&mut out, href),
+                }
+            }
+        } else if root.is(CARDDAV, "addressbook-query") {
+            let filter = match root.child(CARDDAV, "filter").map(parse_filter).transpose() {
+                Ok(filter) => filter.unwrap_or_default(),
+                Err(condition) => return Ok(error_response(403, condition)),
+            };
+
+            for name in objects {
+                let Some(data) = self.store.get(address_book, &name)? else {
+                    continue;
+                };
+
+                // An object that cannot be parsed never satisfies a filter, but an empty filter still lists it.
+                if !filter.prop_filters.is_empty()
+                    && !data.parse::<VCard>().is_ok_and(|vcard| filter.matches(&vcard))
+                {
+                    continue;
+                }
+
+                self.write_response(
+                    &mut out,
+                    &Target::Object(address_book.clone(), name),
+                    &requested,
+                )?;
+            }
+        } else {
+            return Ok(error_response(403, "<D:supported-report/>"));
+        }
+
+        Ok(multistatus_finish(out))
+    }
+
+    /// Writes a `response` element with the found properties and the missing ones in separate `propstat` elements.
+    fn write_response(
+        &self,
+        out: &mut String,
+        target: &Target,
+        requested: &Requested,
+    ) -> Result<(), io::Error> {
+        let data = match target {
+            Target::Object(address_book, name) => self.store.get(address_book, name)?,
+            _ => None,
+        };
+
+        let names: Vec<(&str, &str)> = match requested {
+            Requested::All => match target {
+                Target::Home => HOME_PROPERTIES.to_vec(),
+                Target::AddressBook(_) => ADDRESS_BOOK_PROPERTIES.to_vec(),
+                Target::Object(..) => OBJECT_PROPERTIES.to_vec(),
+            },
+            Requested::Names(elements) => elements
+                .iter()
+                .map(|element| (element.namespace.as_str(), element.name.as_str()))
+                .collect(),
+        };
+
+        // The address-data element can list the vCard properties to return, e.g. `<C:prop name="TEL"/>`.
+        let projection: Option<Vec<&str>> = match requested {
+            Requested::All => None,
+            Requested::Names(elements) => elements
+                .iter()
+                .find(|element| element.is(CARDDAV, "address-data"))
+                .map(|element| {
+                    element
+                        .children_named(CARDDAV, "prop")
+                        .filter_map(|prop| prop.attribute("name"))
+                        .collect()
+                })
+                .filter(|names: &Vec<&str>| !names.is_empty()),
+        };
+
+        let mut found = String::new();
+        let mut missing = String::new();
+
+        for (namespace, name) in names {
+            match self.property(target, data.as_deref(), projection.as_deref(), namespace, name) {
+                Some(value) => write_property(&mut found, namespace, name, &value),
+                None => write_property(&mut missing, namespace, name, ""),
+            }
+        }
+
+        // Writing into a string never fails.
+        write!(out, "<D:response><D:href>{}</D:href>", xml::escape(&self.href(target))).unwrap();
+
+        if !found.is_empty() || missing.is_empty() {
+            write!(
+                out,
+                "<D:propstat><D:prop>{found}</D:prop><D:status>HTTP/1.1 200 \
+                 OK</D:status></D:propstat>"
+            )
+            .unwrap();
+        }
+
+        if !missing.is_empty() {
+            write!(
+                out,
+                "<D:propstat><D:prop>{missing}</D:prop><D:status>HTTP/1.1 404 Not \
+                 Found</D:status></D:propstat>"
+            )
+            .unwrap();
+        }
+
+        out.push_str("</D:response>");
+
+        Ok(())
+    }
+
+    /// Returns the content of a property as escaped XML, or `None` when the resource does not have it.
+    fn property(
+        &self,
+        target: &Target,
+        data: Option<&str>,
+        projection: Option<&[&str]>,
+        namespace: &str,
+        name: &str,
+    ) -> Option<String> {
+        match (target, namespace, name) {
+            (Target::Home, DAV, "resourcetype") => Some(String::from("<D:collection/>")),
+            (Target::AddressBook(_), DAV, "resourcetype") => {
+                Some(String::from("<D:collection/><C:addressbook/>"))
+            },
+            (Target::Object(..), DAV, "resourcetype") => Some(String::new()),
+            (_, DAV, "current-user-principal") | (_, CARDDAV, "addressbook-home-set") => {
+                Some(format!("<D:href>{}</D:href>", xml::escape(&self.root)))
+            },
+            (Target::AddressBook(address_book), DAV, "displayname") => {
+                Some(xml::escape(address_book))
+            },
+            (Target::AddressBook(_), CARDDAV, "supported-address-data") => Some(String::from(
+                "<C:address-data-type content-type=\"text/vcard\" version=\"4.0\"/>",
+            )),
+            (Target::Object(..), DAV, "getetag") => data.map(|data| xml::escape(&etag(data))),
+            (Target::Object(..), DAV, "getcontenttype") => Some(String::from(VCARD_CONTENT_TYPE)),
+            (Target::Object(..), DAV, "getcontentlength") => {
+                data.map(|data| data.len().to_string())
+            },
+            (Target::Object(..), CARDDAV, "address-data") => data.map(|data| {
+                // An object that cannot be parsed is returned as it is.
+                match projection.map(|names| VCard::parse_projected(data, names)) {
+                    Some(Ok(vcard)) => xml::escape(&vcard.to_string()),
+                    _ => xml::escape(data),
+                }
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Extracts the path from a request target, dropping the scheme, the authority, the query and the fragment.
+fn request_path(target: &str) -> &str {
+    let path = match target.find("://") {
+        Some(i) => {
+            let after = &target[i + 3..];
+
+            after.find('/').map_or("/", |j| &after[j..])
+        },
+        None => target,
+    };
+
+    path.split(['?', '#']).next().unwrap_or(path)
+}
+
+/// Checks whether a path segment can be used as the name of an address book or an address object.
+#[inline]
+fn is_valid_name(name: &str) -> bool {
+    !name.is_empty() && !name.starts_with('.') && !name.contains(['/', '\\'])
+}
+
+/// Computes a strong entity tag from the data with the 64-bit FNV-1a hash.
+fn etag(data: &str) -> String {
+    let mut hash: u64 = 0xCBF2_9CE4_8422_2325;
+
+    for b in data.bytes() {
+        hash ^= u64::from(b);
+        hash = hash.wrapping_mul(0x0100_0000_01B3);
+    }
+
+    format!("\"{hash:016x}\"")
+}
+
+/// Compares entity tags by the strong comparison of RFC 9110 section 8.8.3.2, where weak tags never match.
+fn strong_match(tag: &str, current: &str) -> bool {
+    !tag.starts_with("W/") && !current.starts_with("W/") && tag == current
+}
+
+/// Compares entity tags by the weak comparison of RFC 9110 section 8.8.3.2, which ignores the weak indicators.
+fn weak_match(tag: &str, current: &str) -> bool {
+    tag.trim_start_matches("W/") == current.trim_start_matches("W/")
+}
+
+/// Checks whether an `If-Match` or `If-None-Match` header field value matches the ETag of the current resource.
+fn matches_etag(value: &str, current: Option<&str>, compare: fn(&str, &str) -> bool) -> bool {
+    let Some(current) = current else {
+        return false;
+    };
+
+    value.split(',').map(str::trim).any(|tag| tag == "*" || compare(tag, current))
+}
+
+/// Evaluates the conditional header fields, returning `412 Precondition Failed` when they do not hold.
+fn check_preconditions(request: &Request, current: Option<&str>) -> Option<Response> {
+    let current = current.map(etag);
+
+    if let Some(value) = request.header("If-Match")
+        // If-Match uses the strong comparison.
+        && !matches_etag(value, current.as_deref(), strong_match)
+    {
+        return Some(Response::new(412));
+    }
+
+    if let Some(value) = request.header("If-None-Match")
+        && matches_etag(value, current.as_deref(), weak_match)
+    {
+        return Some(Response::new(412));
+    }
+
+    None
+}
+
+/// Parses the XML body of a request, which is `None` when the body is empty.
+fn parse_body(request: &Request) -> Result<Option<Element>, xml::XmlError> {
+    let body = std::str::from_utf8(&request.body).map_err(|_| xml::XmlError)?;
+
+    if body.trim().is_empty() {
+        return Ok(None);
+    }
+
+    xml::parse(body).map(Some)
+}
+
+/// The precondition that fails when a filter uses an element or an attribute value that is not supported.
+const SUPPORTED_FILTER: &str = "<C:supported-filter/>";
+
+/// Reads the `test` attribute of a `filter` or a `prop-filter` element.
+fn parse_test(element: &Element) -> Result<FilterTest, &'static str> {
+    element
+        .attribute("test")
+        .map_or(Ok(FilterTest::AnyOf), |test| test.parse().map_err(|_| SUPPORTED_FILTER))
+}
+
+/// Reads the `filter` element of an addressbook-query report, returning the failed precondition on error.
+fn parse_filter(element: &Element) -> Result<Filter, &'static str> {
+    let mut filter = Filter {
+        test: parse_test(element)?, prop_filters: Vec::new()
+    };
+
+    for child in element.elements() {
+        if !child.is(CARDDAV, "prop-filter") {
+            return Err(SUPPORTED_FILTER);
+        }
+
+        let mut prop_filter = PropFilter::new(child.attribute("name").ok_or(SUPPORTED_FILTER)?);
+
+        prop_filter.test = parse_test(child)?;
+
+        for test in child.elements() {
+            if test.is(CARDDAV, "is-not-defined") {
+                prop_filter.is_not_defined = true;
+            } else if test.is(CARDDAV, "text-match") {
+                prop_filter.text_matches.push(parse_text_match(test)?);
+            } else if test.is(CARDDAV, "param-filter") {
+                prop_filter.param_filters.push(parse_param_filter(test)?);
+            } else {
+                return Err(SUPPORTED_FILTER);
+            }
+        }
+
+        filter.prop_filters.push(prop_filter);
+    }
+
+    Ok(filter)
+}
+
+fn parse_param_filter(element: &Element) -> Result<ParamFilter, &'static str> {
+    let mut param_filter = ParamFilter::new(element.attribute("name").ok_or(SUPPORTED_FILTER)?);
+
+    for test in element.elements() {
+        if test.is(CARDDAV, "is-not-defined") {
+            param_filter.is_not_defined = true;
+        } else if test.is(CARDDAV, "text-match") {
+            param_filter.text_match = Some(parse_text_match(test)?);
+        } else {
+            return Err(SUPPORTED_FILTER);
+        }
+    }
+
+    Ok(param_filter)
+}
+
+fn parse_text_match(element: &Element) -> Result<TextMatch, &'static str> {
+    let mut text_match = TextMatch::new(element.text());
+
+    if let Some(collation) = element.attribute("collation") {
+        text_match.collation =
+            collation.parse::<Collation>().map_err(|_| "<C:supported-collation/>")?;
+    }
+
+    if let Some(match_type) = element.attribute("match-type") {
+        text_match.match_type = match_type.parse::<MatchType>().map_err(|_| SUPPORTED_FILTER)?;
+    }
+
+    text_match.negate = match element.attribute("negate-condition") {
+        None | Some("no") => false,
+        Some("yes") => true,
+        Some(_) => return Err(SUPPORTED_FILTER),
+    };
+
+    Ok(text_match)
+}
+
+/// Reads the `prop` element of a `propfind` or a report, where `allprop` and a missing `prop` request the default properties.
+fn requested_properties(root: &Element) -> Requested<'_> {
+    match root.child(DAV, "prop") {
+        Some(prop) => Requested::Names(prop.elements().collect()),
+        None => Requested::All,
+    }
+}
+
+fn write_property(out: &mut String, namespace: &str, name: &str, value: &str) {
+    let (prefix, declaration) = match namespace {
+        DAV => ("D:", String::new()),
+        CARDDAV => ("C:", String::new()),
+        "" => ("", String::from(" xmlns=\"\"")),
+        other => ("X:", format!(" xmlns:X=\"{}\"", xml::escape(other))),
+    };
+
+    // Writing into a string never fails.
+    if value.is_empty() {
+        write!(out, "<{prefix}{name}{declaration}/>").unwrap();
+    } else {
+        write!(out, "<{prefix}{name}{declaration}>{value}</{prefix}{name}>").unwrap();
+    }
+}
+
+fn write_missing_response(out: &mut String, href: &str) {
+    // Writing into a string never fails.
+    write!(
+        out,
+        "<D:response><D:href>{}</D:href><D:status>HTTP/1.1 404 Not Found</D:status></D:response>",
+        xml::escape(href)
+    )
+    .unwrap();
+}
+
+#[inline]
+fn multistatus_start() -> String {
+    String::from(
+        "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<D:multistatus xmlns:D=\"DAV:\" \
+         xmlns:C=\"urn:ietf:params:xml:ns:carddav\">",
+    )
+}
+
+fn multistatus_finish(mut out: String) -> Response {
+    out.push_str("</D:multistatus>");
+
+    let mut response = Response::new(207);
+
+    response
+        .headers
+        .push((String::from("Content-Type"), String::from("application/xml; charset=utf-8")));
+
+    response.body = out.into_bytes();
+
+    response
+}
+
+/// Builds an error response whose body names the precondition or postcondition that failed.
+fn error_response(status: u16, condition: &str) -> Response {
+    let mut response = Response::new(status);
+
+    response
+        .headers
+        .push((String::from("Content-Type"), String::from("application/xml; charset=utf-8")));
+
+    response.body = format!(
+        "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<D:error xmlns:D=\"DAV:\" \
+         xmlns:C=\"urn:ietf:params:xml:ns:carddav\">{condition}</D:error>"
+    )
+    .into_bytes();
+
+    response
+}
+
+fn options() -> Response {
+    let mut response = Response::new(200);
+
+    response.headers.push((String::from("DAV"), String::from("1, 3, addressbook")));
+    response.headers.push((
+        String::from("Allow"),
+        String::from("OPTIONS, GET, HEAD, PUT, DELETE, PROPFIND, REPORT"),
+    ));
+
+    response
+}
@@ -0,0 +1,194 @@
+//! The storage backends of the CardDAV server handler.
+
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// The storage behind a CardDAV [`Handler`](super::Handler).
+///
+/// A store holds named address books, and every address book holds named address objects whose data is the raw vCard text.
+/// Names never contain slashes, and ETags are derived from the data by the handler, so a store only needs to keep the text.
+pub trait Store {
+    /// Returns the names of all address books.
+    fn address_books(&self) -> Result<Vec<String>, io::Error>;
+
+    /// Returns the names of the address objects in an address book, or `None` when the address book does not exist.
+    fn objects(&self, address_book: &str) -> Result<Option<Vec<String>>, io::Error>;
+
+    /// Returns the data of an address object, or `None` when it does not exist.
+    fn get(&self, address_book: &str, name: &str) -> Result<Option<String>, io::Error>;
+
+    /// Creates or replaces an address object in an existing address book.
+    fn put(&mut self, address_book: &str, name: &str, data: String) -> Result<(), io::Error>;
+
+    /// Removes an address object, returning whether it existed.
+    fn delete(&mut self, address_book: &str, name: &str) -> Result<bool, io::Error>;
+}
+
+/// A store that keeps everything in memory, which is useful for tests and caches.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryStore {
+    address_books: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl MemoryStore {
+    /// Creates an empty store without any address book.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty address book if it does not exist yet.
+    #[inline]
+    pub fn create_address_book(&mut self, name: &str) {
+        self.address_books.entry(name.to_string()).or_default();
+    }
+}
+
+impl Store for MemoryStore {
+    #[inline]
+    fn address_books(&self) -> Result<Vec<String>, io::Error> {
+        Ok(self.address_books.keys().cloned().collect())
+    }
+
+    #[inline]
+    fn objects(&self, address_book: &str) -> Result<Option<Vec<String>>, io::Error> {
+        Ok(self.address_books.get(address_book).map(|objects| objects.keys().cloned().collect()))
+    }
+
+    #[inline]
+    fn get(&self, address_book: &str, name: &str) -> Result<Option<String>, io::Error> {
+        Ok(self.address_books.get(address_book).and_then(|objects| objects.get(name)).cloned())
+    }
+
+    fn put(&mut self, address_book: &str, name: &str, data: String) -> Result<(), io::Error> {
+        let objects = self
+            .address_books
+            .get_mut(address_book)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no such address book"))?;
+
+        objects.insert(name.to_string(), data);
+
+        Ok(())
+    }
+
+    #[inline]
+    fn delete(&mut self, address_book: &str, name: &str) -> Result<bool, io::Error> {
+        Ok(self
+            .address_books
+            .get_mut(address_book)
+            .is_some_and(|objects| objects.remove(name).is_some()))
+    }
+}
+
+/// A store backed by a directory, where every address book is a subdirectory and every address object is a file.
+///
+/// Files are replaced atomically by writing a hidden temporary file first and renaming it, and hidden files are never listed.
+#[derive(Debug, Clone)]
+pub struct FileSystemStore {
+    root: PathBuf,
+}
+
+impl FileSystemStore {
+    /// Creates a store on top of an existing directory.
+    #[inline]
+    pub fn new<P: Into<PathBuf>>(root: P) -> Self {
+        Self {
+            root: root.into()
+        }
+    }
+
+    /// Returns the root directory.
+    #[inline]
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Creates an empty address book if it does not exist yet.
+    #[inline]
+    pub fn create_address_book(&self, name: &str) -> Result<(), io::Error> {
+        fs::create_dir_all(self.root.join(checked_name(name)?))
+    }
+
+    fn object_path(&self, address_book: &str, name: &str) -> Result<PathBuf, io::Error> {
+        Ok(self.root.join(checked_name(address_book)?).join(checked_name(name)?))
+    }
+}
+
+/// Rejects names that could escape the root directory or that are reserved for temporary files.
+fn checked_name(name: &str) -> Result<&str, io::Error> {
+    if name.is_empty() || name.starts_with('.') || name.contains(['/', '\\']) {
+        Err(io::Error::new(io::ErrorKind::InvalidInput, "the name is not allowed"))
+    } else {
+        Ok(name)
+    }
+}
+
+/// Lists the names of the visible entries of a directory that pass the given file type check.
+fn list_directory(
+    path: &Path,
+    filter: impl Fn(&fs::FileType) -> bool,
+) -> Result<Vec<String>, io::Error> {
+    let mut names = Vec::new();
+
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+
+        if !filter(&entry.file_type()?) {
+            continue;
+        }
+
+        if let Some(name) = entry.file_name().to_str()
+            && !name.starts_with('.')
+        {
+            names.push(name.to_string());
+        }
+    }
+
+    names.sort();
+
+    Ok(names)
+}
+
+impl Store for FileSystemStore {
+    #[inline]
+    fn address_books(&self) -> Result<Vec<String>, io::Error> {
+        list_directory(&self.root, fs::FileType::is_dir)
+    }
+
+    fn objects(&self, address_book: &str) -> Result<Option<Vec<String>>, io::Error> {
+        match list_directory(&self.root.join(checked_name(address_book)?), fs::FileType::is_file) {
+            Ok(names) => Ok(Some(names)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    fn get(&self, address_book: &str, name: &str) -> Result<Option<String>, io::Error> {
+        match fs::read_to_string(self.object_path(address_book, name)?) {
+            Ok(data) => Ok(Some(data)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    fn put(&mut self, address_book: &str, name: &str, data: String) -> Result<(), io::Error> {
+        let path = self.object_path(address_book, name)?;
+
+        let temporary_path = path.with_file_name(format!(".{name}.tmp"));
+
+        fs::write(&temporary_path, data)?;
+
+        fs::rename(temporary_path, path)
+    }
+
+    fn delete(&mut self, address_book: &str, name: &str) -> Result<bool, io::Error> {
+        match fs::remove_file(self.object_path(address_book, name)?) {
+            Ok(()) => Ok(true),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(false),
+            Err(error) => Err(error),
+        }
+    }
+}
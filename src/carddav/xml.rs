@@ -0,0 +1,454 @@
+//! A minimal XML reader and the escaping helper used to build WebDAV bodies.
+//!
+//! It only supports what WebDAV needs: elements, attributes, namespaces, text, CDATA sections and the predefined and numeric character references.
+//! Comments, processing instructions and the XML declaration are skipped, and document type declarations are rejected.
+
+use std::fmt::{self, Write};
+
+/// The namespace of the WebDAV elements.
+pub(crate) const DAV: &str = "DAV:";
+
+/// The namespace of the CardDAV elements defined by RFC 6352.
+pub(crate) const CARDDAV: &str = "urn:ietf:params:xml:ns:carddav";
+
+/// The deepest nesting of elements that is accepted, which keeps untrusted bodies from exhausting the stack.
+const MAX_DEPTH: usize = 256;
+
+/// The error returned when the input is not well-formed or uses an unsupported construct.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct XmlError;
+
+/// An XML element whose name is resolved into a namespace and a local name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Element {
+    /// The namespace URI, which is empty when the element is not in any namespace.
+    pub(crate) namespace:  String,
+    /// The local name without the prefix.
+    pub(crate) name:       String,
+    /// The attributes as local names and decoded values, excluding the namespace declarations.
+    pub(crate) attributes: Vec<(String, String)>,
+    /// The child nodes in document order.
+    pub(crate) children:   Vec<Node>,
+}
+
+/// A child node of an element.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Node {
+    Element(Element),
+    Text(String),
+}
+
+impl Element {
+    /// Checks whether this element has the given namespace and local name.
+    #[inline]
+    pub(crate) fn is(&self, namespace: &str, name: &str) -> bool {
+        self.namespace == namespace && self.name == name
+    }
+
+    /// Returns the value of an attribute by its local name.
+    #[inline]
+    pub(crate) fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes.iter().find(|(n, _)| n == name).map(|(_, value)| value.as_str())
+    }
+
+    /// Iterates over the child elements, skipping text.
+    #[inline]
+    pub(crate) fn elements(&self) -> impl Iterator<Item = &Element> {
+        self.children.iter().filter_map(|node| match node {
+            Node::Element(element) => Some(element),
+            Node::Text(_) => None,
+        })
+    }
+
+    /// Returns the first child element with the given namespace and local name.
+    #[inline]
+    pub(crate) fn child(&self, namespace: &str, name: &str) -> Option<&Element> {
+        self.elements().find(|element| element.is(namespace, name))
+    }
+
+    /// Iterates over the child elements with the given namespace and local name.
+    #[inline]
+    pub(crate) fn children_named<'a>(
+        &'a self,
+        namespace: &'a str,
+        name: &'a str,
+    ) -> impl Iterator<Item = &'a Element> {
+        self.elements().filter(move |element| element.is(namespace, name))
+    }
+
+    /// Concatenates the text of this element and all of its descendants.
+    pub(crate) fn text(&self) -> String {
+        fn collect(element: &Element, out: &mut String) {
+            for node in &element.children {
+                match node {
+                    Node::Element(element) => collect(element, out),
+                    Node::Text(text) => out.push_str(text),
+                }
+            }
+        }
+
+        let mut out = String::new();
+
+        collect(self, &mut out);
+
+        out
+    }
+}
+
+/// Parses a whole XML document and returns its root element.
+pub(crate) fn parse(input: &str) -> Result<Element, XmlError> {
+    let mut parser = Parser {
+        input,
+        position: 0,
+        scopes: Vec::new(),
+    };
+
+    // A byte order mark is allowed before the document.
+    if input.starts_with('\u{FEFF}') {
+        parser.position = '\u{FEFF}'.len_utf8();
+    }
+
+    parser.skip_misc()?;
+
+    if !parser.rest().starts_with('<') {
+        return Err(XmlError);
+    }
+
+    let root = parser.parse_element()?;
+
+    parser.skip_misc()?;
+
+    if parser.position != input.len() {
+        return Err(XmlError);
+    }
+
+    Ok(root)
+}
+
+/// Writes text with the five special XML characters escaped, which is safe for both content and attribute values.
+pub(crate) fn write_escaped(w: &mut impl Write, s: &str) -> fmt::Result {
+    let mut start = 0;
+
+    for (i, c) in s.char_indices() {
+        let replacement = match c {
+            '&' => "&amp;",
+            '<' => "&lt;",
+            '>' => "&gt;",
+            '"' => "&quot;",
+            '\'' => "&apos;",
+            _ => continue,
+        };
+
+        w.write_str(&s[start..i])?;
+        w.write_str(replacement)?;
+
+        start = i + 1;
+    }
+
+    w.write_str(&s[start..])
+}
+
+/// Returns a copy of the text with the special XML characters escaped.
+#[inline]
+pub(crate) fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+
+    // Writing into a string never fails.
+    write_escaped(&mut out, s).unwrap();
+
+    out
+}
+
+#[inline]
+fn is_xml_whitespace(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\r' | '\n')
+}
+
+/// Checks whether a character can start an XML name, as the NameStartChar production defines.
+fn is_name_start_char(c: char) -> bool {
+    matches!(c,
+        ':' | 'A'..='Z' | '_' | 'a'..='z'
+        | '\u{C0}'..='\u{D6}' | '\u{D8}'..='\u{F6}' | '\u{F8}'..='\u{2FF}'
+        | '\u{370}'..='\u{37D}' | '\u{37F}'..='\u{1FFF}' | '\u{200C}'..='\u{200D}'
+        | '\u{2070}'..='\u{218F}' | '\u{2C00}'..='\u{2FEF}' | '\u{3001}'..='\u{D7FF}'
+        | '\u{F900}'..='\u{FDCF}' | '\u{FDF0}'..='\u{FFFD}' | '\u{10000}'..='\u{EFFFF}'
+    )
+}
+
+/// Checks whether a character can appear in an XML name, as the NameChar production defines.
+fn is_name_char(c: char) -> bool {
+    is_name_start_char(c)
+        || matches!(c,
+            '-' | '.' | '0'..='9' | '\u{B7}' | '\u{300}'..='\u{36F}' | '\u{203F}'..='\u{2040}'
+        )
+}
+
+struct Parser<'a> {
+    input:    &'a str,
+    position: usize,
+    /// The namespace declarations of the open elements, as prefix and URI pairs where the default namespace has an empty prefix.
+    scopes:   Vec<Vec<(String, String)>>,
+}
+
+impl<'a> Parser<'a> {
+    #[inline]
+    fn rest(&self) -> &'a str {
+        &self.input[self.position..]
+    }
+
+    #[inline]
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+
+        self.position += rest.len() - rest.trim_start_matches(is_xml_whitespace).len();
+    }
+
+    /// Moves to the position right after the next occurrence of `end`.
+    fn skip_past(&mut self, end: &str) -> Result<(), XmlError> {
+        let i = self.rest().find(end).ok_or(XmlError)?;
+
+        self.position += i + end.len();
+
+        Ok(())
+    }
+
+    /// Skips whitespace, comments, processing instructions and the XML declaration outside the root element.
+    fn skip_misc(&mut self) -> Result<(), XmlError> {
+        loop {
+            self.skip_whitespace();
+
+            let rest = self.rest();
+
+            if rest.starts_with("<?") {
+                self.skip_past("?>")?;
+            } else if rest.starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn parse_name(&mut self) -> Result<&'a str, XmlError> {
+        let rest = self.rest();
+
+        if !rest.starts_with(is_name_start_char) {
+            return Err(XmlError);
+        }
+
+        let length = rest.find(|c: char| !is_name_char(c)).unwrap_or(rest.len());
+
+        self.position += length;
+
+        Ok(&rest[..length])
+    }
+
+    /// Parses an element starting at its `<`.
+    fn parse_element(&mut self) -> Result<Element, XmlError> {
+        // Every open element has a scope, so the scopes tell the depth.
+        if self.scopes.len() >= MAX_DEPTH {
+            return Err(XmlError);
+        }
+
+        self.position += 1;
+
+        let qualified_name = self.parse_name()?;
+
+        let mut raw_attributes = Vec::new();
+
+        let empty = loop {
+            self.skip_whitespace();
+
+            let rest = self.rest();
+
+            if rest.starts_with("/>") {
+                self.position += 2;
+                break true;
+            }
+
+            if rest.starts_with('>') {
+                self.position += 1;
+                break false;
+            }
+
+            let name = self.parse_name()?;
+
+            self.skip_whitespace();
+
+            if !self.rest().starts_with('=') {
+                return Err(XmlError);
+            }
+
+            self.position += 1;
+
+            self.skip_whitespace();
+
+            let quote = match self.rest().chars().next() {
+                Some(quote @ ('"' | '\'')) => quote,
+                _ => return Err(XmlError),
+            };
+
+            self.position += 1;
+
+            let rest = self.rest();
+
+            let end = rest.find(quote).ok_or(XmlError)?;
+
+            raw_attributes.push((name, decode_references(&rest[..end])?));
+
+            self.position += end + 1;
+        };
+
+        // Namespace declarations also apply to the element that carries them, so they are collected first.
+        let mut scope = Vec::new();
+        let mut attributes = Vec::new();
+
+        for (name, value) in raw_attributes {
+            if name == "xmlns" {
+                scope.push((String::new(), value));
+            } else if let Some(prefix) = name.strip_prefix("xmlns:") {
+                scope.push((prefix.to_string(), value));
+            } else {
+                // Attribute prefixes are dropped because WebDAV and CardDAV only use unprefixed attributes.
+                let local_name = name.rsplit_once(':').map_or(name, |(_, local_name)| local_name);
+
+                attributes.push((local_name.to_string(), value));
+            }
+        }
+
+        self.scopes.push(scope);
+
+        let (prefix, local_name) = qualified_name.split_once(':').unwrap_or(("", qualified_name));
+
+        let mut element = Element {
+            namespace: self.resolve(prefix)?,
+            name: local_name.to_string(),
+            attributes,
+            children: Vec::new(),
+        };
+
+        if !empty {
+            self.parse_content(&mut element, qualified_name)?;
+        }
+
+        self.scopes.pop();
+
+        Ok(element)
+    }
+
+    /// Parses the content of an element until its end tag.
+    fn parse_content(
+        &mut self,
+        element: &mut Element,
+        qualified_name: &str,
+    ) -> Result<(), XmlError> {
+        loop {
+            let rest = self.rest();
+
+            if rest.is_empty() {
+                return Err(XmlError);
+            }
+
+            if let Some(after) = rest.strip_prefix("</") {
+                let length = after.find('>').ok_or(XmlError)?;
+
+                if after[..length].trim_end_matches(is_xml_whitespace) != qualified_name {
+                    return Err(XmlError);
+                }
+
+                self.position += length + 3;
+
+                return Ok(());
+            }
+
+            if rest.starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if let Some(after) = rest.strip_prefix("<![CDATA[") {
+                let end = after.find("]]>").ok_or(XmlError)?;
+
+                push_text(element, &after[..end]);
+
+                self.position += end + 12;
+            } else if rest.starts_with("<?") {
+                self.skip_past("?>")?;
+            } else if rest.starts_with("<!") {
+                return Err(XmlError);
+            } else if rest.starts_with('<') {
+                let child = self.parse_element()?;
+
+                element.children.push(Node::Element(child));
+            } else {
+                let end = rest.find('<').unwrap_or(rest.len());
+
+                push_text(element, &decode_references(&rest[..end])?);
+
+                self.position += end;
+            }
+        }
+    }
+
+    /// Finds the namespace URI bound to a prefix, where the empty prefix is the default namespace.
+    fn resolve(&self, prefix: &str) -> Result<String, XmlError> {
+        for scope in self.scopes.iter().rev() {
+            if let Some((_, uri)) = scope.iter().find(|(p, _)| p == prefix) {
+                return Ok(uri.clone());
+            }
+        }
+
+        match prefix {
+            "" => Ok(String::new()),
+            "xml" => Ok(String::from("http://www.w3.org/XML/1998/namespace")),
+            _ => Err(XmlError),
+        }
+    }
+}
+
+/// Appends text to an element, merging it with a directly preceding text node.
+fn push_text(element: &mut Element, text: &str) {
+    if let Some(Node::Text(last)) = element.children.last_mut() {
+        last.push_str(text);
+    } else {
+        element.children.push(Node::Text(text.to_string()));
+    }
+}
+
+/// Decodes the predefined entities and the numeric character references.
+fn decode_references(s: &str) -> Result<String, XmlError> {
+    let mut out = String::with_capacity(s.len());
+
+    let mut rest = s;
+
+    while let Some(i) = rest.find('&') {
+        out.push_str(&rest[..i]);
+
+        let after = &rest[i + 1..];
+
+        let end = after.find(';').ok_or(XmlError)?;
+
+        match &after[..end] {
+            "lt" => out.push('<'),
+            "gt" => out.push('>'),
+            "amp" => out.push('&'),
+            "quot" => out.push('"'),
+            "apos" => out.push('\''),
+            reference => {
+                let code = match reference.strip_prefix("#x") {
+                    Some(hex) => u32::from_str_radix(hex, 16),
+                    None => match reference.strip_prefix('#') {
+                        Some(decimal) => decimal.parse(),
+                        None => return Err(XmlError),
+                    },
+                }
+                .map_err(|_| XmlError)?;
+
+                out.push(char::from_u32(code).ok_or(XmlError)?);
+            },
+        }
+
+        rest = &after[end + 1..];
+    }
+
+    out.push_str(rest);
+
+    Ok(out)
+}
@@ -0,0 +1,373 @@
+//! The filter model of the CardDAV addressbook-query report, defined by RFC 6352 section 10.5.
+
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use unicode_normalization::UnicodeNormalization;
+
+use crate::{
+    error::InvalidValueError,
+    parameters::list_values,
+    parse::content_line::parse_content_line,
+    property::content_line,
+    syntax::unescape_text,
+    vcard::{VCard, for_each_property},
+};
+
+/// How the results of the child tests are combined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum FilterTest {
+    /// At least one child test has to match, which is the default.
+    #[default]
+    AnyOf,
+    /// All child tests have to match.
+    AllOf,
+}
+
+impl FromStr for FilterTest {
+    type Err = InvalidValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "anyof" => Ok(Self::AnyOf),
+            "allof" => Ok(Self::AllOf),
+            _ => Err(InvalidValueError::new("filter test")),
+        }
+    }
+}
+
+impl Display for FilterTest {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::AnyOf => "anyof",
+            Self::AllOf => "allof",
+        })
+    }
+}
+
+impl FilterTest {
+    /// Combines the results of the child tests.
+    fn combine(self, mut results: impl Iterator<Item = bool>) -> bool {
+        match self {
+            Self::AnyOf => results.any(|result| result),
+            Self::AllOf => results.all(|result| result),
+        }
+    }
+}
+
+/// The collations that CardDAV servers have to support.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Collation {
+    /// `i;unicode-casemap` from RFC 5051, which ignores case and compatibility differences in all scripts. This is the default.
+    #[default]
+    UnicodeCasemap,
+    /// `i;ascii-casemap` from RFC 4790, which only ignores the case of ASCII letters.
+    AsciiCasemap,
+}
+
+impl FromStr for Collation {
+    type Err = InvalidValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "i;unicode-casemap" => Ok(Self::UnicodeCasemap),
+            "i;ascii-casemap" => Ok(Self::AsciiCasemap),
+            _ => Err(InvalidValueError::new("collation")),
+        }
+    }
+}
+
+impl Display for Collation {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::UnicodeCasemap => "i;unicode-casemap",
+            Self::AsciiCasemap => "i;ascii-casemap",
+        })
+    }
+}
+
+/// Maps a character to its uppercase when that is a single character, like the simple case mappings of Unicode do.
+fn simple_uppercase(c: char) -> char {
+    let mut upper = c.to_uppercase();
+
+    match (upper.next(), upper.next()) {
+        (Some(upper), None) => upper,
+        _ => c,
+    }
+}
+
+impl Collation {
+    /// Maps a string to the form in which strings that are equal under this collation are identical.
+    fn key(self, s: &str) -> String {
+        match self {
+            // RFC 5051 maps every character by the simple titlecase mapping and then applies NFKD.
+            // The simple uppercase mapping puts the same characters together, while the full mapping would also match `ß` with `ss`, so a character whose uppercase expands is kept.
+            Self::UnicodeCasemap => s.chars().map(simple_uppercase).nfkd().collect(),
+            Self::AsciiCasemap => s.to_ascii_lowercase(),
+        }
+    }
+}
+
+/// How the text of a [`TextMatch`] is compared with a value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MatchType {
+    Equals,
+    /// The default match type.
+    #[default]
+    Contains,
+    StartsWith,
+    EndsWith,
+}
+
+impl FromStr for MatchType {
+    type Err = InvalidValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "equals" => Ok(Self::Equals),
+            "contains" => Ok(Self::Contains),
+            "starts-with" => Ok(Self::StartsWith),
+            "ends-with" => Ok(Self::EndsWith),
+            _ => Err(InvalidValueError::new("match type")),
+        }
+    }
+}
+
+impl Display for MatchType {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::Equals => "equals",
+            Self::Contains => "contains",
+            Self::StartsWith => "starts-with",
+            Self::EndsWith => "ends-with",
+        })
+    }
+}
+
+/// A substring test against a property value or a parameter value.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TextMatch {
+    /// The text to search for.
+    pub text:       String,
+    /// How the text and the value are compared, which is the `collation` attribute.
+    pub collation:  Collation,
+    /// Which part of the value has to match, which is the `match-type` attribute.
+    pub match_type: MatchType,
+    /// Whether the result of the test is inverted, which is the `negate-condition` attribute.
+    pub negate:     bool,
+}
+
+impl TextMatch {
+    /// Creates a case-insensitive `contains` test.
+    #[inline]
+    pub fn new<S: Into<String>>(text: S) -> Self {
+        Self {
+            text:       text.into(),
+            collation:  Collation::default(),
+            match_type: MatchType::default(),
+            negate:     false,
+        }
+    }
+
+    /// Tests a value.
+    pub fn matches(&self, value: &str) -> bool {
+        let value = self.collation.key(value);
+        let text = self.collation.key(&self.text);
+
+        let result = match self.match_type {
+            MatchType::Equals => value == text,
+            MatchType::Contains => value.contains(&text),
+            MatchType::StartsWith => value.starts_with(&text),
+            MatchType::EndsWith => value.ends_with(&text),
+        };
+
+        result != self.negate
+    }
+}
+
+/// A test on a parameter of a property.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParamFilter {
+    /// The parameter name, which is compared case-insensitively.
+    pub name:           String,
+    /// Whether the test matches when the parameter is absent instead, which is the `is-not-defined` element.
+    pub is_not_defined: bool,
+    /// The test on the parameter values, where any of the values has to match. Without it, the presence of the parameter is enough.
+    pub text_match:     Option<TextMatch>,
+}
+
+impl ParamFilter {
+    /// Creates a test that matches when the parameter is present.
+    #[inline]
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        Self {
+            name: name.into(), is_not_defined: false, text_match: None
+        }
+    }
+
+    fn matches(&self, instance: &Instance) -> bool {
+        let values = instance
+            .parameters
+            .iter()
+            .filter(|(name, _)| name.eq_ignore_ascii_case(&self.name))
+            .flat_map(|(_, values)| values)
+            .collect::<Vec<_>>();
+
+        if self.is_not_defined {
+            return values.is_empty();
+        }
+
+        match &self.text_match {
+            Some(text_match) => values.iter().any(|value| text_match.matches(value)),
+            None => !values.is_empty(),
+        }
+    }
+}
+
+/// A test on the properties with a given name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PropFilter {
+    /// The property name, which is compared case-insensitively and also matches extension properties.
+    pub name:           String,
+    /// How the text matches and the parameter filters are combined.
+    pub test:           FilterTest,
+    /// Whether the test matches when the property is absent instead, which is the `is-not-defined` element.
+    pub is_not_defined: bool,
+    pub text_matches:   Vec<TextMatch>,
+    pub param_filters:  Vec<ParamFilter>,
+}
+
+impl PropFilter {
+    /// Creates a test that matches when the property is present.
+    #[inline]
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        Self {
+            name:           name.into(),
+            test:           FilterTest::default(),
+            is_not_defined: false,
+            text_matches:   Vec::new(),
+            param_filters:  Vec::new(),
+        }
+    }
+
+    /// Tests a vCard. Every property instance is tested on its own, so `allof` needs a single instance that satisfies all the child tests.
+    pub fn matches(&self, vcard: &VCard) -> bool {
+        let instances = instances(vcard, &self.name);
+
+        if self.is_not_defined {
+            return instances.is_empty();
+        }
+
+        instances.iter().any(|instance| {
+            if self.text_matches.is_empty() && self.param_filters.is_empty() {
+                return true;
+            }
+
+            self.test.combine(
+                self.text_matches
+                    .iter()
+                    .map(|text_match| text_match.matches(&instance.value))
+                    .chain(self.param_filters.iter().map(|filter| filter.matches(instance))),
+            )
+        })
+    }
+}
+
+/// The filter of an addressbook-query report.
+///
+/// ```rust
+/// use vcard::{Filter, PropFilter, TextMatch, VCard};
+///
+/// let mut prop_filter = PropFilter::new("FN");
+///
+/// prop_filter.text_matches.push(TextMatch::new("LEN"));
+///
+/// let mut filter = Filter::new();
+///
+/// filter.prop_filters.push(prop_filter);
+///
+/// assert!(filter.matches(&VCard::new("Magic Len")));
+/// assert!(!filter.matches(&VCard::new("David Wang")));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Filter {
+    /// How the property filters are combined.
+    pub test:         FilterTest,
+    /// The property filters. Without any of them, every vCard matches.
+    pub prop_filters: Vec<PropFilter>,
+}
+
+impl Filter {
+    /// Creates a filter that matches every vCard.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tests a vCard.
+    pub fn matches(&self, vcard: &VCard) -> bool {
+        if self.prop_filters.is_empty() {
+            return true;
+        }
+
+        self.test.combine(self.prop_filters.iter().map(|filter| filter.matches(vcard)))
+    }
+}
+
+/// A property instance as the filters see it, with the decoded parameter values and the unescaped value.
+struct Instance {
+    parameters: Vec<(String, Vec<String>)>,
+    value:      String,
+}
+
+/// Collects the instances of the properties with the given name, including extension properties.
+fn instances(vcard: &VCard, name: &str) -> Vec<Instance> {
+    let mut lines = Vec::new();
+
+    // Both cardinalities can be iterated in the same way, so the cardinality is ignored.
+    macro_rules! collect_field {
+        ($card:tt, $field:ident, $name:literal) => {
+            if name.eq_ignore_ascii_case($name) {
+                lines.extend(vcard.$field.iter().map(|property| content_line($name, property)));
+            }
+        };
+    }
+
+    macro_rules! collect_fields {
+        ($(($field:ident, $name:literal, $card:tt)),* $(,)?) => {
+            $(collect_field!($card, $field, $name);)*
+        };
+    }
+
+    for_each_property!(collect_fields);
+
+    lines.extend(
+        vcard
+            .extensions
+            .iter()
+            .filter(|extension| extension.name.as_str().eq_ignore_ascii_case(name))
+            .map(|extension| extension.content_line()),
+    );
+
+    lines
+        .iter()
+        .filter_map(|line| {
+            // The lines come from the serializer, so splitting them again always succeeds.
+            let raw = parse_content_line(line).ok()?;
+
+            Some(Instance {
+                parameters: raw
+                    .params
+                    .into_iter()
+                    .map(|(name, raw)| (name.to_string(), list_values(raw, false)))
+                    .collect(),
+                value:      unescape_text(raw.value),
+            })
+        })
+        .collect()
+}
@@ -11,6 +11,7 @@ const MAX_LINE_OCTETS: usize = 75;
 pub struct FoldingWriter<'a> {
     out:         &'a mut (dyn Write + 'a),
     line_octets: usize,
+    fold:        bool,
 }
 
 impl<'a> FoldingWriter<'a> {
@@ -20,6 +21,17 @@ impl<'a> FoldingWriter<'a> {
         Self {
             out,
             line_octets: 0,
+            fold: true,
+        }
+    }
+
+    /// Wraps a plain writer without folding, which is used when a content line is shown as a single string.
+    #[inline]
+    pub(crate) fn unfolded(out: &'a mut (dyn Write + 'a)) -> Self {
+        Self {
+            out,
+            line_octets: 0,
+            fold: false,
         }
     }
 
@@ -34,6 +46,10 @@ impl<'a> FoldingWriter<'a> {
 
 impl Write for FoldingWriter<'_> {
     fn write_str(&mut self, mut s: &str) -> fmt::Result {
+        if !self.fold {
+            return self.out.write_str(s);
+        }
+
         while !s.is_empty() {
             let remaining = MAX_LINE_OCTETS - self.line_octets;
 
@@ -0,0 +1,268 @@
+//! Property groups, e.g. the `item1` of `item1.TEL` and `item1.X-ABLabel`, and the custom labels that Apple attaches through them.
+
+use std::collections::HashMap;
+
+use crate::{
+    property::{ExtensionProperty, GroupName, Property},
+    syntax::{unescape_text, write_escaped_text},
+    vcard::{VCard, for_each_property},
+};
+
+/// The extension property that Apple uses to label the other properties of its group.
+const LABEL: &str = "X-ABLabel";
+
+/// Parses the number of a group name like `item3`.
+fn item_number(group: &GroupName) -> Option<u32> {
+    let s = group.as_str();
+
+    let digits =
+        s.get(..4).filter(|prefix| prefix.eq_ignore_ascii_case("item")).map(|_| &s[4..])?;
+
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    digits.parse().ok()
+}
+
+fn item(number: u32) -> GroupName {
+    // `item` followed by digits is always a valid group name.
+    format!("item{number}").parse().unwrap()
+}
+
+/// Checks whether an optional group is the given one, where group names are case-insensitive.
+#[inline]
+fn in_group(group: Option<&GroupName>, name: &GroupName) -> bool {
+    group.is_some_and(|group| group.as_str().eq_ignore_ascii_case(name.as_str()))
+}
+
+impl VCard {
+    /// Calls a function on the group of every property, including the extension properties, in the order of serialization.
+    fn for_each_group_mut(&mut self, mut f: impl FnMut(&mut Option<GroupName>)) {
+        macro_rules! visit_fields {
+            ($(($field:ident, $name:literal, $card:tt)),* $(,)?) => {
+                // Both cardinalities can be iterated in the same way, so the cardinality is ignored.
+                $(
+                    for property in self.$field.iter_mut() {
+                        f(&mut property.group);
+                    }
+                )*
+            };
+        }
+
+        for_each_property!(visit_fields);
+
+        for extension in &mut self.extensions {
+            f(&mut extension.group);
+        }
+    }
+
+    /// Returns the groups used by the properties in the order of their first appearance, without duplicates.
+    pub fn groups(&self) -> Vec<GroupName> {
+        let mut groups: Vec<GroupName> = Vec::new();
+
+        let mut visit = |group: Option<&GroupName>| {
+            if let Some(group) = group
+                && !groups.iter().any(|g| in_group(Some(g), group))
+            {
+                groups.push(group.clone());
+            }
+        };
+
+        macro_rules! visit_fields {
+            ($(($field:ident, $name:literal, $card:tt)),* $(,)?) => {
+                $(
+                    for property in self.$field.iter() {
+                        visit(property.group.as_ref());
+                    }
+                )*
+            };
+        }
+
+        for_each_property!(visit_fields);
+
+        for extension in &self.extensions {
+            visit(extension.group.as_ref());
+        }
+
+        groups
+    }
+
+    /// Returns a vCard that has only the properties of a group, including the extension properties, where group names are case-insensitive.
+    ///
+    /// ```rust
+    /// use vcard::{Tel, VCard, values::TelValue};
+    ///
+    /// let mut vcard = VCard::new("Magic Len");
+    /// let mut tel = Tel::new(TelValue::Text(String::from("+886 2 1234 5678")));
+    ///
+    /// tel.group = Some("item1".parse().unwrap());
+    /// vcard.telephones.push(tel);
+    /// vcard.set_label(&"item1".parse().unwrap(), "Office");
+    ///
+    /// let group = vcard.group(&"ITEM1".parse().unwrap());
+    ///
+    /// assert_eq!(1, group.telephones.len());
+    /// assert_eq!(1, group.extensions.len());
+    /// assert!(group.formatted_names.is_empty());
+    /// ```
+    pub fn group(&self, group: &GroupName) -> VCard {
+        let mut grouped = VCard::default();
+
+        macro_rules! group_field {
+            (many, $field:ident) => {
+                grouped.$field = self
+                    .$field
+                    .iter()
+                    .filter(|property| in_group(property.group.as_ref(), group))
+                    .cloned()
+                    .collect();
+            };
+            (one, $field:ident) => {
+                grouped.$field =
+                    self.$field.clone().filter(|property| in_group(property.group.as_ref(), group));
+            };
+        }
+
+        macro_rules! group_fields {
+            ($(($field:ident, $name:literal, $card:tt)),* $(,)?) => {
+                $(group_field!($card, $field);)*
+            };
+        }
+
+        for_each_property!(group_fields);
+
+        grouped.extensions = self
+            .extensions
+            .iter()
+            .filter(|extension| in_group(extension.group.as_ref(), group))
+            .cloned()
+            .collect();
+
+        grouped
+    }
+
+    /// Renames a group on every property, and returns how many properties were changed.
+    ///
+    /// If the new group is already used, the two groups become one.
+    pub fn rename_group(&mut self, from: &GroupName, to: &GroupName) -> usize {
+        let mut count = 0;
+
+        self.for_each_group_mut(|group| {
+            if in_group(group.as_ref(), from) {
+                *group = Some(to.clone());
+                count += 1;
+            }
+        });
+
+        count
+    }
+
+    /// Renumbers the `itemN` groups to `item1`, `item2` and so on in the order of their first appearance, leaving the other groups alone.
+    pub fn renumber_groups(&mut self) {
+        let mut numbers: HashMap<u32, u32> = HashMap::new();
+
+        self.for_each_group_mut(|group| {
+            let Some(old) = group.as_ref().and_then(item_number) else {
+                return;
+            };
+
+            let next = numbers.len() as u32 + 1;
+            let new = *numbers.entry(old).or_insert(next);
+
+            *group = Some(item(new));
+        });
+    }
+
+    /// Returns an `itemN` group that no property uses yet.
+    pub fn next_group(&self) -> GroupName {
+        let last = self.groups().iter().filter_map(item_number).max().unwrap_or(0);
+
+        item(last.saturating_add(1))
+    }
+
+    /// Returns the Apple `X-ABLabel` of a group, where the built-in labels like `_$!<Work>!$_` are unwrapped to `Work`.
+    pub fn label(&self, group: &GroupName) -> Option<String> {
+        let extension = self.extensions.iter().find(|extension| {
+            extension.name.as_str().eq_ignore_ascii_case(LABEL)
+                && in_group(extension.group.as_ref(), group)
+        })?;
+
+        let label = unescape_text(&extension.value);
+
+        Some(match label.strip_prefix("_$!<").and_then(|label| label.strip_suffix(">!$_")) {
+            Some(built_in) => built_in.to_string(),
+            None => label,
+        })
+    }
+
+    /// Sets the Apple `X-ABLabel` of a group, replacing the existing one.
+    pub fn set_label(&mut self, group: &GroupName, label: &str) {
+        let mut value = String::new();
+
+        // Writing into a string never fails.
+        write_escaped_text(&mut value, label, false).unwrap();
+
+        if let Some(extension) = self.extensions.iter_mut().find(|extension| {
+            extension.name.as_str().eq_ignore_ascii_case(LABEL)
+                && in_group(extension.group.as_ref(), group)
+        }) {
+            extension.value = value;
+
+            return;
+        }
+
+        // The name is a valid x-name.
+        let mut extension = ExtensionProperty::new(LABEL.parse().unwrap(), value);
+
+        extension.group = Some(group.clone());
+
+        self.extensions.push(extension);
+    }
+
+    /// Removes the Apple `X-ABLabel` of a group, and returns whether one existed.
+    pub fn remove_label(&mut self, group: &GroupName) -> bool {
+        let count = self.extensions.len();
+
+        self.extensions.retain(|extension| {
+            !(extension.name.as_str().eq_ignore_ascii_case(LABEL)
+                && in_group(extension.group.as_ref(), group))
+        });
+
+        self.extensions.len() != count
+    }
+
+    /// Labels a property of this vCard with the Apple `X-ABLabel` convention, putting the property into a new group if it has none, and returns the group.
+    ///
+    /// ```rust
+    /// use vcard::{Email, VCard};
+    ///
+    /// let mut vcard = VCard::new("Magic Len");
+    ///
+    /// vcard.emails.push(Email::new("len@example.com".parse().unwrap()));
+    ///
+    /// let group = vcard.attach_label(|vcard| &mut vcard.emails[0], "School");
+    ///
+    /// assert_eq!("item1", group.as_str());
+    /// assert_eq!(Some("School"), vcard.label(&group).as_deref());
+    /// assert!(
+    ///     vcard.to_string().contains(
+    ///         "item1.EMAIL:len@example.com\r\nitem1.X-ABLabel:School\r\n"
+    ///     )
+    /// );
+    /// ```
+    pub fn attach_label<V>(
+        &mut self,
+        property: impl FnOnce(&mut VCard) -> &mut Property<V>,
+        label: &str,
+    ) -> GroupName {
+        let next = self.next_group();
+        let property = property(self);
+
+        let group = property.group.get_or_insert(next).clone();
+
+        self.set_label(&group, label);
+
+        group
+    }
+}
@@ -0,0 +1,235 @@
+//! Export of birthdays and anniversaries as an iCalendar (RFC 5545) calendar of yearly all-day events.
+
+use std::fmt::{self, Display, Formatter, Write};
+
+use chrono::{Days, NaiveDate, Utc};
+
+use crate::{
+    fold::FoldingWriter,
+    occasion::{LeapDayPolicy, OccasionKind},
+    property::Property,
+    syntax::write_escaped_text,
+    values::{CalendarSystem, DateAndOrTime, DateAndOrTimeOrText, TextOrUri},
+    vcard::VCard,
+};
+
+/// The year used for the first event of an occasion whose year is unknown, which is a leap year so that February 29 exists.
+const UNKNOWN_YEAR: i32 = 2000;
+
+/// A VCALENDAR with a yearly recurring all-day VEVENT for every BDAY and ANNIVERSARY property, which is created by [`VCard::occasion_calendar`].
+///
+/// Properties in the other calendar systems of [`CalendarSystem`] recur in their own calendar by the RSCALE rule part of RFC 7529. Properties in free-form text, without a month and a day, or in an unknown calendar system are left out.
+#[derive(Debug, Clone, Copy)]
+pub struct OccasionCalendar<'a> {
+    vcards: &'a [VCard],
+    policy: LeapDayPolicy,
+    stamp:  chrono::DateTime<Utc>,
+}
+
+impl<'a> OccasionCalendar<'a> {
+    /// Sets where February 29 occasions, or days missing from a month in other calendars, fall in other years, which is written with the RSCALE rule part of RFC 7529.
+    #[inline]
+    pub const fn with_leap_day_policy(mut self, policy: LeapDayPolicy) -> Self {
+        self.policy = policy;
+
+        self
+    }
+
+    /// Sets the DTSTAMP of the events, which is the creation time by default.
+    #[inline]
+    pub const fn with_timestamp(mut self, stamp: chrono::DateTime<Utc>) -> Self {
+        self.stamp = stamp;
+
+        self
+    }
+
+    fn write_event(
+        &self,
+        w: &mut FoldingWriter,
+        vcard: &VCard,
+        kind: OccasionKind,
+        property: &Property<DateAndOrTimeOrText>,
+    ) -> fmt::Result {
+        let Some(calendar) = CalendarSystem::from_calscale(property.parameters.calscale.as_ref())
+        else {
+            return Ok(());
+        };
+
+        let date = match &property.value {
+            DateAndOrTimeOrText::DateAndOrTime(DateAndOrTime::Date(date)) => *date,
+            DateAndOrTimeOrText::DateAndOrTime(DateAndOrTime::DateTime(date_time)) => {
+                date_time.date()
+            },
+            _ => return Ok(()),
+        };
+
+        let (Some(month), Some(day)) = (date.month(), date.day()) else {
+            return Ok(());
+        };
+
+        let start = if calendar == CalendarSystem::Gregorian {
+            let year = date.year().map_or(UNKNOWN_YEAR, i32::from);
+
+            NaiveDate::from_ymd_opt(year, u32::from(month), u32::from(day))
+        } else {
+            // Without a year, the event starts on the first day that really has the month and the day, so it recurs on the same day.
+            date.to_gregorian(calendar).or_else(|| {
+                NaiveDate::from_ymd_opt(UNKNOWN_YEAR, 1, 1).and_then(|from| {
+                    property.value.next_occurrence_in(calendar, from, LeapDayPolicy::Skip)
+                })
+            })
+        };
+
+        let Some(start) = start else {
+            return Ok(());
+        };
+
+        let (label, summary) = match kind {
+            OccasionKind::Birthday => ("birthday", "Birthday"),
+            OccasionKind::Anniversary => ("anniversary", "Anniversary"),
+        };
+
+        w.write_str("BEGIN:VEVENT")?;
+        w.end_line()?;
+
+        w.write_str("UID:")?;
+        write_escaped_text(w, &event_uid(vcard, label, &start), true)?;
+        w.end_line()?;
+
+        write!(w, "DTSTAMP:{}", self.stamp.format("%Y%m%dT%H%M%SZ"))?;
+        w.end_line()?;
+
+        write!(w, "DTSTART;VALUE=DATE:{}", start.format("%Y%m%d"))?;
+        w.end_line()?;
+
+        // An all-day event ends at the start of the next day.
+        let end = start.checked_add_days(Days::new(1)).unwrap_or(start);
+
+        write!(w, "DTEND;VALUE=DATE:{}", end.format("%Y%m%d"))?;
+        w.end_line()?;
+
+        // RFC 5545 skips the years without the date, while RSCALE can move the event to a nearby day instead.
+        // Other calendars always need RSCALE, and their days can be missing from a month in any year.
+        let skip = match self.policy {
+            LeapDayPolicy::February28 => ";SKIP=BACKWARD",
+            LeapDayPolicy::March1 => ";SKIP=FORWARD",
+            LeapDayPolicy::Skip => "",
+        };
+
+        match (calendar, month, day) {
+            (CalendarSystem::Gregorian, 2, 29) if !skip.is_empty() => {
+                write!(w, "RRULE:RSCALE=GREGORIAN;FREQ=YEARLY{skip}")?
+            },
+            (CalendarSystem::Gregorian, ..) => w.write_str("RRULE:FREQ=YEARLY")?,
+            _ => write!(w, "RRULE:RSCALE={};FREQ=YEARLY{skip}", calendar.rscale())?,
+        }
+        w.end_line()?;
+
+        w.write_str("SUMMARY:")?;
+        write_escaped_text(w, &format!("{summary}: {}", display_name(vcard)), true)?;
+        w.end_line()?;
+
+        w.write_str("TRANSP:TRANSPARENT")?;
+        w.end_line()?;
+
+        w.write_str("END:VEVENT")?;
+        w.end_line()
+    }
+}
+
+impl Display for OccasionCalendar<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut w = FoldingWriter::new(f);
+
+        for line in [
+            "BEGIN:VCALENDAR",
+            "VERSION:2.0",
+            "PRODID:-//vcard//Contact Occasions//EN",
+            "CALSCALE:GREGORIAN",
+        ] {
+            w.write_str(line)?;
+            w.end_line()?;
+        }
+
+        for vcard in self.vcards {
+            if let Some(birthday) = &vcard.birthday {
+                self.write_event(&mut w, vcard, OccasionKind::Birthday, birthday)?;
+            }
+
+            if let Some(anniversary) = &vcard.anniversary {
+                self.write_event(&mut w, vcard, OccasionKind::Anniversary, anniversary)?;
+            }
+        }
+
+        w.write_str("END:VCALENDAR")?;
+        w.end_line()
+    }
+}
+
+/// Returns the first FN value, which every valid vCard has.
+fn display_name(vcard: &VCard) -> &str {
+    vcard.formatted_names.first().map_or("", |formatted_name| formatted_name.value.as_str())
+}
+
+/// Derives a stable event UID from the UID of the vCard, or from its name and the date when it has no UID.
+fn event_uid(vcard: &VCard, label: &str, start: &NaiveDate) -> String {
+    match vcard.uid.as_ref().map(|uid| &uid.value) {
+        Some(TextOrUri::Uri(uri)) => {
+            let uri = uri.as_str();
+
+            // A `urn:uuid:` prefix adds nothing to the uniqueness.
+            let uid = uri
+                .get(..9)
+                .filter(|prefix| prefix.eq_ignore_ascii_case("urn:uuid:"))
+                .map_or(uri, |_| &uri[9..]);
+
+            format!("{uid}-{label}")
+        },
+        Some(TextOrUri::Text(text)) => format!("{text}-{label}"),
+        None => {
+            // FNV-1a 64-bit, which is enough to tell the contacts of one calendar apart.
+            let hash = format!("{}\n{start}", display_name(vcard))
+                .bytes()
+                .fold(0xCBF2_9CE4_8422_2325_u64, |hash, byte| {
+                    (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01B3)
+                });
+
+            format!("{hash:016x}-{label}")
+        },
+    }
+}
+
+impl VCard {
+    /// Creates an iCalendar calendar of the birthdays and anniversaries of some vCards, which is written by its `Display` implementation.
+    ///
+    /// The event UIDs are derived from the UIDs of the vCards, so importing the calendar again updates the same events.
+    ///
+    /// ```rust
+    /// use vcard::{
+    ///     Birthday, VCard,
+    ///     chrono::{TimeZone, Utc},
+    ///     values::Date,
+    /// };
+    ///
+    /// let mut vcard = VCard::new("Magic Len");
+    ///
+    /// vcard.birthday =
+    ///     Some(Birthday::new(Date::from_month_day(4, 12).unwrap().into()));
+    ///
+    /// let calendar = VCard::occasion_calendar(&[vcard])
+    ///     .with_timestamp(Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap())
+    ///     .to_string();
+    ///
+    /// assert!(calendar.contains("DTSTART;VALUE=DATE:20000412\r\n"));
+    /// assert!(calendar.contains("RRULE:FREQ=YEARLY\r\n"));
+    /// assert!(calendar.contains("SUMMARY:Birthday: Magic Len\r\n"));
+    /// ```
+    #[inline]
+    pub fn occasion_calendar(vcards: &[VCard]) -> OccasionCalendar<'_> {
+        OccasionCalendar {
+            vcards,
+            policy: LeapDayPolicy::default(),
+            stamp: Utc::now(),
+        }
+    }
+}
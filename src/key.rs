@@ -0,0 +1,757 @@
+//! Public keys embedded in KEY properties, i.e. OpenPGP keys, X.509 certificates and SSH public keys.
+
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter, Write},
+    fs, io,
+    path::Path,
+};
+
+use base64::Engine;
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use mime::Mime;
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    property::{Property, by_preference},
+    syntax::BASE64,
+    values::{TextOrUri, Uri},
+    vcard::VCard,
+};
+
+/// The kind of a public key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyKind {
+    /// An OpenPGP transferable public key of RFC 9580.
+    OpenPgp,
+    /// An X.509 certificate of RFC 5280.
+    X509,
+    /// An SSH public key in the OpenSSH format.
+    Ssh,
+}
+
+impl KeyKind {
+    /// Returns the media type of embedded keys of this kind, which SSH public keys do not have because they are embedded as text.
+    #[inline]
+    pub fn mime(self) -> Option<Mime> {
+        // The literals are always valid media types.
+        match self {
+            Self::OpenPgp => Some("application/pgp-keys".parse().unwrap()),
+            Self::X509 => Some("application/pkix-cert".parse().unwrap()),
+            Self::Ssh => None,
+        }
+    }
+}
+
+/// The error type of [`PublicKey::parse`] and [`Property::public_key`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum KeyError {
+    /// The value refers to an external key, which is not fetched.
+    NotEmbedded,
+    /// The key material is not an OpenPGP key, an X.509 certificate or an SSH public key.
+    Unrecognized,
+    /// The key material looks like the given kind but cannot be parsed.
+    Malformed(KeyKind),
+}
+
+impl Display for KeyError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::NotEmbedded => f.write_str("the key is not embedded"),
+            Self::Unrecognized => f.write_str("the kind of the key is not recognized"),
+            Self::Malformed(KeyKind::OpenPgp) => f.write_str("the OpenPGP key is malformed"),
+            Self::Malformed(KeyKind::X509) => f.write_str("the X.509 certificate is malformed"),
+            Self::Malformed(KeyKind::Ssh) => f.write_str("the SSH public key is malformed"),
+        }
+    }
+}
+
+impl Error for KeyError {}
+
+/// A public key decoded from a KEY property or from a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicKey {
+    /// The kind of the key.
+    pub kind:        KeyKind,
+    /// The binary form of the key: the OpenPGP packets, the DER certificate, or the SSH wire encoding.
+    pub data:        Vec<u8>,
+    /// The fingerprint of the key.
+    ///
+    /// It is SHA-1 for OpenPGP v4 keys and SHA-256 for OpenPGP v5 and v6 keys as defined by OpenPGP, and SHA-256 of the binary form for X.509 certificates and SSH public keys.
+    pub fingerprint: Vec<u8>,
+    /// The OpenPGP user IDs, the email addresses of an X.509 certificate, or the comment of an SSH public key.
+    pub user_ids:    Vec<String>,
+    /// The subject of an X.509 certificate as an RFC 4514 string, e.g. `CN=Magic Len,O=Example,C=TW`.
+    pub subject:     Option<String>,
+    /// When the key was created, or when an X.509 certificate becomes valid.
+    pub created:     Option<DateTime<Utc>>,
+    /// When the key expires, or when an X.509 certificate stops being valid.
+    pub expires:     Option<DateTime<Utc>>,
+}
+
+impl PublicKey {
+    /// Decodes a public key from ASCII-armored or binary OpenPGP data, a PEM or DER X.509 certificate, or an OpenSSH public key line.
+    ///
+    /// ```rust
+    /// use vcard::{KeyKind, PublicKey};
+    ///
+    /// let key = PublicKey::parse(
+    ///     b"ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIF+ipkPr1teFaC00d5XPjyj+oFTjakY93Z5B1Gyw+r14 len@example.com",
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(KeyKind::Ssh, key.kind);
+    /// assert_eq!(["len@example.com"], key.user_ids.as_slice());
+    /// ```
+    pub fn parse(bytes: &[u8]) -> Result<Self, KeyError> {
+        if let Ok(text) = std::str::from_utf8(bytes) {
+            let text = text.trim_start();
+
+            if text.starts_with("-----BEGIN PGP PUBLIC KEY BLOCK-----") {
+                return dearmor(text, "PGP PUBLIC KEY BLOCK")
+                    .and_then(parse_openpgp)
+                    .ok_or(KeyError::Malformed(KeyKind::OpenPgp));
+            }
+
+            if text.starts_with("-----BEGIN CERTIFICATE-----") {
+                return dearmor(text, "CERTIFICATE")
+                    .and_then(parse_x509)
+                    .ok_or(KeyError::Malformed(KeyKind::X509));
+            }
+
+            if ["ssh-", "ecdsa-sha2-", "sk-"].iter().any(|prefix| text.starts_with(prefix)) {
+                return text
+                    .lines()
+                    .next()
+                    .and_then(parse_ssh)
+                    .ok_or(KeyError::Malformed(KeyKind::Ssh));
+            }
+        }
+
+        match bytes.first() {
+            // A DER certificate starts with a SEQUENCE.
+            Some(0x30) => parse_x509(bytes.to_vec()).ok_or(KeyError::Malformed(KeyKind::X509)),
+            // An OpenPGP packet header always has the highest bit set.
+            Some(header) if header & 0x80 != 0 => {
+                parse_openpgp(bytes.to_vec()).ok_or(KeyError::Malformed(KeyKind::OpenPgp))
+            },
+            _ => Err(KeyError::Unrecognized),
+        }
+    }
+
+    /// Returns the fingerprint in uppercase hexadecimal.
+    pub fn fingerprint_hex(&self) -> String {
+        hex(&self.fingerprint, true)
+    }
+
+    /// Returns the email addresses in the user IDs, e.g. `len@example.com` for `Magic Len <len@example.com>`.
+    pub fn emails(&self) -> Vec<&str> {
+        self.user_ids
+            .iter()
+            .filter_map(|user_id| {
+                let address = match user_id.rsplit_once('<') {
+                    Some((_, address)) => address.strip_suffix('>')?,
+                    None => user_id.as_str(),
+                };
+
+                (address.contains('@') && !address.contains(char::is_whitespace)).then_some(address)
+            })
+            .collect()
+    }
+
+    /// Checks whether the key has expired at the given time.
+    #[inline]
+    pub fn is_expired_at(&self, time: &DateTime<Utc>) -> bool {
+        self.expires.is_some_and(|expires| expires <= *time)
+    }
+}
+
+/// Formats bytes in hexadecimal.
+fn hex(bytes: &[u8], uppercase: bool) -> String {
+    bytes.iter().fold(String::with_capacity(bytes.len() * 2), |mut s, byte| {
+        // Writing to a `String` never fails.
+        if uppercase {
+            write!(s, "{byte:02X}").unwrap();
+        } else {
+            write!(s, "{byte:02x}").unwrap();
+        }
+
+        s
+    })
+}
+
+/// Reads a big-endian unsigned integer of at most four bytes.
+fn be(bytes: &[u8]) -> usize {
+    bytes.iter().fold(0, |n, byte| (n << 8) | usize::from(*byte))
+}
+
+fn timestamp(seconds: u64) -> Option<DateTime<Utc>> {
+    Utc.timestamp_opt(i64::try_from(seconds).ok()?, 0).single()
+}
+
+/// Decodes the base64 body of an OpenPGP ASCII armor or a PEM block, skipping the armor headers and the CRC-24 checksum.
+fn dearmor(text: &str, label: &str) -> Option<Vec<u8>> {
+    let body = text
+        .split_once(&format!("-----BEGIN {label}-----"))?
+        .1
+        .split_once(&format!("-----END {label}-----"))?
+        .0;
+
+    let encoded: String = body
+        .lines()
+        .map(str::trim)
+        // Base64 never contains a colon, and the checksum is `=` with four characters.
+        .filter(|line| !(line.contains(':') || line.starts_with('=') && line.len() == 5))
+        .collect();
+
+    BASE64.decode(encoded).ok()
+}
+
+/// Reads an OpenPGP packet, returning its tag, its body and the rest.
+fn read_packet(data: &[u8]) -> Option<(u8, &[u8], &[u8])> {
+    let (&header, rest) = data.split_first()?;
+
+    if header & 0x80 == 0 {
+        return None;
+    }
+
+    let (tag, length, rest) = if header & 0x40 != 0 {
+        let (&first, rest) = rest.split_first()?;
+
+        let (length, rest) = match first {
+            0..=191 => (usize::from(first), rest),
+            192..=223 => {
+                let (&second, rest) = rest.split_first()?;
+
+                (((usize::from(first) - 192) << 8) + usize::from(second) + 192, rest)
+            },
+            255 => (be(rest.get(..4)?), &rest[4..]),
+            // Partial body lengths are not allowed in key packets.
+            _ => return None,
+        };
+
+        (header & 0x3F, length, rest)
+    } else {
+        let (length, rest) = match header & 0x03 {
+            0 => (be(rest.get(..1)?), &rest[1..]),
+            1 => (be(rest.get(..2)?), &rest[2..]),
+            2 => (be(rest.get(..4)?), &rest[4..]),
+            _ => (rest.len(), rest),
+        };
+
+        ((header >> 2) & 0x0F, length, rest)
+    };
+
+    Some((tag, rest.get(..length)?, &rest[length..]))
+}
+
+/// Returns the creation time and the key expiration time of a self-signature on a user ID or a direct-key signature, if the expiration is set.
+fn key_expiration(signature: &[u8]) -> Option<(u32, u32)> {
+    let (&version, rest) = signature.split_first()?;
+    let (&kind, rest) = rest.split_first()?;
+
+    // Only certifications and direct-key signatures can set the expiration of the primary key.
+    if !matches!(kind, 0x10..=0x13 | 0x1F) {
+        return None;
+    }
+
+    // Skip the public key and hash algorithms.
+    let rest = rest.get(2..)?;
+
+    let mut subpackets = match version {
+        4 => rest.get(2..2 + be(rest.get(..2)?))?,
+        5 | 6 => rest.get(4..4 + be(rest.get(..4)?))?,
+        _ => return None,
+    };
+
+    let mut created = 0;
+    let mut expiration = None;
+
+    while let Some((&first, rest)) = subpackets.split_first() {
+        let (length, rest) = match first {
+            0..=191 => (usize::from(first), rest),
+            192..=254 => {
+                let (&second, rest) = rest.split_first()?;
+
+                (((usize::from(first) - 192) << 8) + usize::from(second) + 192, rest)
+            },
+            255 => (be(rest.get(..4)?), &rest[4..]),
+        };
+
+        let subpacket = rest.get(..length)?;
+
+        subpackets = &rest[length..];
+
+        // The highest bit of the type is the critical flag.
+        match (subpacket.first().map(|kind| kind & 0x7F), subpacket.get(1..5)) {
+            (Some(2), Some(time)) => created = be(time) as u32,
+            (Some(9), Some(time)) => expiration = Some(be(time) as u32),
+            _ => (),
+        }
+    }
+
+    Some((created, expiration?))
+}
+
+fn parse_openpgp(data: Vec<u8>) -> Option<PublicKey> {
+    let (tag, body, mut rest) = read_packet(&data)?;
+
+    if tag != 6 {
+        return None;
+    }
+
+    let (&version, fields) = body.split_first()?;
+    let created = be(fields.get(..4)?) as u64;
+
+    let fingerprint = match version {
+        4 => Sha1::new()
+            .chain_update([0x99])
+            .chain_update(u16::try_from(body.len()).ok()?.to_be_bytes())
+            .chain_update(body)
+            .finalize()
+            .to_vec(),
+        5 | 6 => Sha256::new()
+            .chain_update([if version == 5 { 0x9A } else { 0x9B }])
+            .chain_update(u32::try_from(body.len()).ok()?.to_be_bytes())
+            .chain_update(body)
+            .finalize()
+            .to_vec(),
+        _ => return None,
+    };
+
+    let mut user_ids = Vec::new();
+    let mut lifetime: Option<(u32, u32)> = None;
+
+    while !rest.is_empty() {
+        let (tag, body, next) = read_packet(rest)?;
+
+        rest = next;
+
+        match tag {
+            13 => user_ids.push(String::from_utf8_lossy(body).into_owned()),
+            // The latest self-signature wins.
+            2 => {
+                if let Some((signed, expiration)) = key_expiration(body)
+                    && lifetime.is_none_or(|(latest, _)| signed >= latest)
+                {
+                    lifetime = Some((signed, expiration));
+                }
+            },
+            // The subkeys and their bindings follow the primary key and its user IDs.
+            14 => break,
+            _ => (),
+        }
+    }
+
+    Some(PublicKey {
+        kind: KeyKind::OpenPgp,
+        fingerprint,
+        user_ids,
+        subject: None,
+        created: timestamp(created),
+        // Zero means that the key does not expire.
+        expires: lifetime
+            .filter(|(_, expiration)| *expiration != 0)
+            .and_then(|(_, expiration)| timestamp(created + u64::from(expiration))),
+        data,
+    })
+}
+
+/// Reads a DER element, returning its tag, its contents and the rest.
+fn read_der(data: &[u8]) -> Option<(u8, &[u8], &[u8])> {
+    let (&tag, rest) = data.split_first()?;
+
+    // Tag numbers above 30 need more octets, which the parsed fields of certificates do not use.
+    if tag & 0x1F == 0x1F {
+        return None;
+    }
+
+    let (&first, rest) = rest.split_first()?;
+
+    let (length, rest) = if first < 0x80 {
+        (usize::from(first), rest)
+    } else {
+        let count = usize::from(first & 0x7F);
+
+        if count == 0 || count > 4 {
+            return None;
+        }
+
+        (be(rest.get(..count)?), &rest[count..])
+    };
+
+    Some((tag, rest.get(..length)?, &rest[length..]))
+}
+
+/// Decodes a UTCTime or a GeneralizedTime in UTC.
+fn der_time(tag: u8, contents: &[u8]) -> Option<DateTime<Utc>> {
+    let s = std::str::from_utf8(contents).ok()?.strip_suffix('Z')?;
+
+    if !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let (year, rest) = match tag {
+        // Two-digit years are within 1950 to 2049.
+        0x17 => {
+            let year: i32 = s.get(..2)?.parse().ok()?;
+
+            (if year < 50 { 2000 + year } else { 1900 + year }, &s[2..])
+        },
+        0x18 => (s.get(..4)?.parse().ok()?, &s[4..]),
+        _ => return None,
+    };
+
+    if rest.len() != 10 {
+        return None;
+    }
+
+    // The digits are checked above.
+    let field = |i: usize| rest[i..i + 2].parse::<u32>().unwrap();
+
+    NaiveDate::from_ymd_opt(year, field(0), field(2))?
+        .and_hms_opt(field(4), field(6), field(8))
+        .map(|date_time| date_time.and_utc())
+}
+
+/// Decodes a DER object identifier in the dotted form.
+fn oid(contents: &[u8]) -> String {
+    let mut arcs = Vec::new();
+    let mut arc: u64 = 0;
+
+    for byte in contents {
+        arc = (arc << 7) | u64::from(byte & 0x7F);
+
+        if byte & 0x80 == 0 {
+            if arcs.is_empty() {
+                let first = (arc / 40).min(2);
+
+                arcs.push(first);
+                arcs.push(arc - first * 40);
+            } else {
+                arcs.push(arc);
+            }
+
+            arc = 0;
+        }
+    }
+
+    arcs.iter().map(u64::to_string).collect::<Vec<_>>().join(".")
+}
+
+/// Returns the short name of an attribute type of RFC 4514, or its dotted object identifier.
+fn attribute_type(contents: &[u8]) -> String {
+    match contents {
+        [0x55, 0x04, 0x03] => "CN",
+        [0x55, 0x04, 0x06] => "C",
+        [0x55, 0x04, 0x07] => "L",
+        [0x55, 0x04, 0x08] => "ST",
+        [0x55, 0x04, 0x09] => "STREET",
+        [0x55, 0x04, 0x0A] => "O",
+        [0x55, 0x04, 0x0B] => "OU",
+        [0x09, 0x92, 0x26, 0x89, 0x93, 0xF2, 0x2C, 0x64, 0x01, 0x19] => "DC",
+        [0x09, 0x92, 0x26, 0x89, 0x93, 0xF2, 0x2C, 0x64, 0x01, 0x01] => "UID",
+        // Not defined by RFC 4514, but commonly written this way.
+        EMAIL_ADDRESS => "emailAddress",
+        _ => return oid(contents),
+    }
+    .to_string()
+}
+
+/// The object identifier of the `emailAddress` attribute of PKCS #9.
+const EMAIL_ADDRESS: &[u8] = &[0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x09, 0x01];
+
+/// Decodes a DER string of the types that certificates use.
+fn der_string(tag: u8, contents: &[u8]) -> Option<String> {
+    match tag {
+        // UTF8String, PrintableString, IA5String and VisibleString.
+        0x0C | 0x13 | 0x16 | 0x1A => String::from_utf8(contents.to_vec()).ok(),
+        // TeletexString, which is treated as Latin-1 in practice.
+        0x14 => Some(contents.iter().map(|b| char::from(*b)).collect()),
+        // BMPString.
+        0x1E => char::decode_utf16(
+            contents.chunks_exact(2).map(|pair| u16::from_be_bytes([pair[0], pair[1]])),
+        )
+        .collect::<Result<_, _>>()
+        .ok(),
+        _ => None,
+    }
+}
+
+/// Escapes an attribute value of RFC 4514.
+fn escape_attribute_value(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    let last = value.chars().count().saturating_sub(1);
+
+    for (i, c) in value.chars().enumerate() {
+        match c {
+            '"' | '+' | ',' | ';' | '<' | '>' | '\\' => escaped.push('\\'),
+            '#' | ' ' if i == 0 => escaped.push('\\'),
+            ' ' if i == last => escaped.push('\\'),
+            '\0' => {
+                escaped.push_str("\\00");
+
+                continue;
+            },
+            _ => (),
+        }
+
+        escaped.push(c);
+    }
+
+    escaped
+}
+
+/// Formats a distinguished name as an RFC 4514 string, collecting the email addresses in it.
+fn distinguished_name(mut name: &[u8], emails: &mut Vec<String>) -> Option<String> {
+    let mut rdns = Vec::new();
+
+    while !name.is_empty() {
+        let Some((0x31, mut set, next)) = read_der(name) else {
+            return None;
+        };
+
+        name = next;
+
+        let mut attributes = Vec::new();
+
+        while !set.is_empty() {
+            let Some((0x30, attribute, next)) = read_der(set) else {
+                return None;
+            };
+
+            set = next;
+
+            let Some((0x06, kind, element)) = read_der(attribute) else {
+                return None;
+            };
+
+            let (tag, contents, _) = read_der(element)?;
+
+            let value = match der_string(tag, contents) {
+                Some(value) => {
+                    if kind == EMAIL_ADDRESS {
+                        emails.push(value.clone());
+                    }
+
+                    escape_attribute_value(&value)
+                },
+                // Other types are written as the hexadecimal form of their encoding.
+                None => format!("#{}", hex(&element[..2 + contents.len()], false)),
+            };
+
+            attributes.push(format!("{}={value}", attribute_type(kind)));
+        }
+
+        rdns.push(attributes.join("+"));
+    }
+
+    // The most specific RDN comes first in the string form.
+    rdns.reverse();
+
+    Some(rdns.join(","))
+}
+
+/// Collects the email addresses in the subject alternative name extension.
+fn alternative_emails(extensions: &[u8], emails: &mut Vec<String>) -> Option<()> {
+    let Some((0x30, mut extensions, _)) = read_der(extensions) else {
+        return None;
+    };
+
+    while !extensions.is_empty() {
+        let Some((0x30, extension, next)) = read_der(extensions) else {
+            return None;
+        };
+
+        extensions = next;
+
+        let Some((0x06, kind, rest)) = read_der(extension) else {
+            return None;
+        };
+
+        if kind != [0x55, 0x1D, 0x11] {
+            continue;
+        }
+
+        let (mut tag, mut value, rest) = read_der(rest)?;
+
+        // Skip the critical flag.
+        if tag == 0x01 {
+            (tag, value, _) = read_der(rest)?;
+        }
+
+        let (0x04, Some((0x30, mut names, _))) = (tag, read_der(value)) else {
+            return None;
+        };
+
+        while !names.is_empty() {
+            let (tag, name, next) = read_der(names)?;
+
+            names = next;
+
+            // rfc822Name.
+            if tag == 0x81 {
+                emails.push(String::from_utf8(name.to_vec()).ok()?);
+            }
+        }
+    }
+
+    Some(())
+}
+
+fn parse_x509(mut data: Vec<u8>) -> Option<PublicKey> {
+    let Some((0x30, certificate, trailing)) = read_der(&data) else {
+        return None;
+    };
+
+    let Some((0x30, tbs, _)) = read_der(certificate) else {
+        return None;
+    };
+
+    let (tag, _, mut rest) = read_der(tbs)?;
+
+    // The version is optional, and the serial number follows it.
+    if tag == 0xA0 {
+        (_, _, rest) = read_der(rest)?;
+    }
+
+    // Skip the signature algorithm and the issuer.
+    let (_, _, rest) = read_der(rest)?;
+    let (_, _, rest) = read_der(rest)?;
+
+    let Some((0x30, validity, rest)) = read_der(rest) else {
+        return None;
+    };
+
+    let (tag, not_before, validity) = read_der(validity)?;
+    let created = der_time(tag, not_before)?;
+    let (tag, not_after, _) = read_der(validity)?;
+    let expires = der_time(tag, not_after)?;
+
+    let Some((0x30, subject, rest)) = read_der(rest) else {
+        return None;
+    };
+
+    let mut emails = Vec::new();
+    let subject = distinguished_name(subject, &mut emails)?;
+
+    // Skip the subject public key info.
+    let (_, _, mut rest) = read_der(rest)?;
+
+    while !rest.is_empty() {
+        let (tag, contents, next) = read_der(rest)?;
+
+        rest = next;
+
+        if tag == 0xA3 {
+            alternative_emails(contents, &mut emails)?;
+        }
+    }
+
+    let mut user_ids: Vec<String> = Vec::with_capacity(emails.len());
+
+    for email in emails {
+        if !user_ids.iter().any(|user_id| user_id.eq_ignore_ascii_case(&email)) {
+            user_ids.push(email);
+        }
+    }
+
+    let length = data.len() - trailing.len();
+
+    data.truncate(length);
+
+    Some(PublicKey {
+        kind: KeyKind::X509,
+        fingerprint: Sha256::digest(&data).to_vec(),
+        user_ids,
+        subject: Some(subject),
+        created: Some(created),
+        expires: Some(expires),
+        data,
+    })
+}
+
+fn parse_ssh(line: &str) -> Option<PublicKey> {
+    let mut fields = line.split_ascii_whitespace();
+
+    let algorithm = fields.next()?;
+    let data = BASE64.decode(fields.next()?).ok()?;
+    let comment = fields.collect::<Vec<_>>().join(" ");
+
+    // The wire encoding starts with the name of the algorithm.
+    let length = be(data.get(..4)?);
+
+    if data.get(4..4 + length)? != algorithm.as_bytes() {
+        return None;
+    }
+
+    Some(PublicKey {
+        kind: KeyKind::Ssh,
+        fingerprint: Sha256::digest(&data).to_vec(),
+        user_ids: if comment.is_empty() { Vec::new() } else { vec![comment] },
+        subject: None,
+        created: None,
+        expires: None,
+        data,
+    })
+}
+
+impl Property<TextOrUri> {
+    /// Decodes the public key embedded in this KEY property as a `data:` URI or as text.
+    ///
+    /// Keys referenced by other URIs are not fetched and give [`KeyError::NotEmbedded`].
+    pub fn public_key(&self) -> Result<PublicKey, KeyError> {
+        match &self.value {
+            TextOrUri::Text(text) => PublicKey::parse(text.as_bytes()),
+            TextOrUri::Uri(uri) => PublicKey::parse(&uri.data().ok_or(KeyError::NotEmbedded)?.1),
+        }
+    }
+
+    /// Creates a KEY property that embeds a public key.
+    ///
+    /// OpenPGP keys and X.509 certificates are embedded in their binary form as `data:` URIs with the MEDIATYPE parameter, and SSH public keys are embedded as OpenSSH text.
+    pub fn from_public_key(key: &PublicKey) -> Self {
+        let Some(mime) = key.kind.mime() else {
+            // The wire encoding starts with the name of the algorithm, which was checked by parsing.
+            let algorithm = key
+                .data
+                .get(4..4 + be(key.data.get(..4).unwrap_or_default()))
+                .and_then(|name| std::str::from_utf8(name).ok())
+                .unwrap_or_default();
+
+            let mut text = format!("{algorithm} {}", BASE64.encode(&key.data));
+
+            if let Some(comment) = key.user_ids.first() {
+                text.push(' ');
+                text.push_str(comment);
+            }
+
+            return Self::new(TextOrUri::Text(text));
+        };
+
+        let mut property = Self::new(TextOrUri::Uri(Uri::from_data(&mime, &key.data)));
+
+        property.parameters.media_type = Some(mime);
+
+        property
+    }
+
+    /// Reads a public key from a file, e.g. an exported `.asc`, `.pem`, `.der` or `.pub` file, and creates a KEY property that embeds it with [`Property::from_public_key`].
+    ///
+    /// A file that is not a public key gives an error of [`io::ErrorKind::InvalidData`].
+    pub fn from_key_file(path: impl AsRef<Path>) -> Result<Self, io::Error> {
+        let key = PublicKey::parse(&fs::read(path)?)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+
+        Ok(Self::from_public_key(&key))
+    }
+}
+
+impl VCard {
+    /// Decodes the public keys embedded in the KEY properties, the most preferred first, skipping the external and undecodable ones.
+    pub fn public_keys(&self) -> Vec<PublicKey> {
+        by_preference(&self.keys).into_iter().filter_map(|key| key.public_key().ok()).collect()
+    }
+}
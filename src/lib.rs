@@ -49,12 +49,30 @@ assert_eq!("Magic Len", vcard.formatted_names[0].value);
 ```
 */
 
+mod alternative;
+#[cfg(feature = "carddav")]
+pub mod carddav;
 mod error;
+mod filter;
 mod fold;
+mod group;
+mod icalendar;
+mod key;
+mod location;
+mod media;
+mod merge;
+mod occasion;
 mod parameters;
 mod parse;
+mod preference;
+mod projection;
 mod property;
 mod syntax;
+#[cfg(feature = "image")]
+mod thumbnail;
+mod time_zone;
+mod tracking;
+mod uid;
 pub mod values;
 mod vcard;
 
@@ -62,13 +80,23 @@ pub use base64;
 pub use chrono;
 pub use chrono_tz;
 pub use error::{InvalidValueError, ParseError, ParseErrorKind, ValidationError};
+pub use filter::{Collation, Filter, FilterTest, MatchType, ParamFilter, PropFilter, TextMatch};
 pub use fold::FoldingWriter;
+pub use icalendar::OccasionCalendar;
+#[cfg(feature = "image")]
+pub use image;
+pub use key::{KeyError, KeyKind, PublicKey};
+pub use location::BoundingBox;
+pub use media::{MediaReference, MediaResolver};
+pub use merge::{MergeConflict, MergeOutcome};
 pub use mime;
 pub use mime_guess;
+pub use occasion::{LeapDayPolicy, Occasion, OccasionKind};
 pub use oxilangtag;
 pub use parameters::{
     AnyParameter, Calscale, Parameters, Phonetic, Pid, Pref, PropId, Script, TypeValue, TzParam,
 };
+pub use projection::ProjectedVCard;
 pub use property::{
     Address, Anniversary, Birthday, CalendarAddressUri, CalendarUri, Categories, ClientPidMap,
     Created, Email, ExtensionProperty, Fburl, FormattedName, Gender, Geo, GramGender, GroupName,
@@ -76,6 +104,11 @@ pub use property::{
     Pronouns, Property, PropertyValue, Related, Rev, Role, SocialProfile, Sound, Source, Tel,
     TimeZone, Title, Uid, Url, Xml,
 };
+#[cfg(feature = "image")]
+pub use thumbnail::{ImageEncoding, ImageOptions, ImageProcessingError};
+pub use time_zone::ResolvedTimeZone;
+pub use tracking::TrackedVCard;
 pub use url;
+pub use uuid;
 pub use validators;
 pub use vcard::VCard;
@@ -0,0 +1,145 @@
+//! Locations of contacts from the GEO property and the GEO parameter of ADR, for finding contacts nearby.
+
+use crate::{
+    values::{EARTH_RADIUS, GeoUri},
+    vcard::VCard,
+};
+
+/// A latitude and longitude range in WGS 84 degrees, which can cross the antimeridian when `west` is greater than `east`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    /// The southern latitude.
+    pub south: f64,
+    /// The western longitude.
+    pub west:  f64,
+    /// The northern latitude.
+    pub north: f64,
+    /// The eastern longitude.
+    pub east:  f64,
+}
+
+impl BoundingBox {
+    /// Creates the smallest box that contains a circle around a location, where the radius is in meters.
+    ///
+    /// A circle that reaches a pole covers all the longitudes.
+    pub fn around(center: &GeoUri, radius: f64) -> Self {
+        let angle = (radius / EARTH_RADIUS).to_degrees();
+
+        let south = center.latitude() - angle;
+        let north = center.latitude() + angle;
+
+        if south <= -90.0 || north >= 90.0 {
+            return Self {
+                south: south.max(-90.0),
+                west:  -180.0,
+                north: north.min(90.0),
+                east:  180.0,
+            };
+        }
+
+        // The longitudes widen towards the poles, by the latitude where the circle is widest.
+        let spread = (angle.to_radians().sin() / center.latitude().to_radians().cos())
+            .min(1.0)
+            .asin()
+            .to_degrees();
+
+        if spread >= 180.0 {
+            return Self {
+                south,
+                west: -180.0,
+                north,
+                east: 180.0,
+            };
+        }
+
+        let wrap = |longitude: f64| {
+            if longitude < -180.0 {
+                longitude + 360.0
+            } else if longitude > 180.0 {
+                longitude - 360.0
+            } else {
+                longitude
+            }
+        };
+
+        Self {
+            south,
+            west: wrap(center.longitude() - spread),
+            north,
+            east: wrap(center.longitude() + spread),
+        }
+    }
+
+    /// Checks whether a WGS 84 location is inside this box.
+    pub fn contains(&self, location: &GeoUri) -> bool {
+        if !location.is_wgs84() || !(self.south..=self.north).contains(&location.latitude()) {
+            return false;
+        }
+
+        let longitude = location.longitude();
+
+        if self.west <= self.east {
+            (self.west..=self.east).contains(&longitude)
+        } else {
+            longitude >= self.west || longitude <= self.east
+        }
+    }
+}
+
+impl VCard {
+    /// Returns the locations of the GEO properties and of the GEO parameters of the ADR properties, skipping URIs that are not valid `geo:` URIs.
+    pub fn locations(&self) -> Vec<GeoUri> {
+        self.geos
+            .iter()
+            .map(|geo| &geo.value)
+            .chain(self.addresses.iter().filter_map(|address| address.parameters.geo.as_ref()))
+            .filter_map(|uri| GeoUri::try_from(uri).ok())
+            .collect()
+    }
+
+    /// Returns the distance in meters from a location to the nearest location of this vCard, or `None` if it has no WGS 84 location.
+    pub fn distance_to(&self, location: &GeoUri) -> Option<f64> {
+        self.locations().iter().filter_map(|own| own.distance_to(location)).min_by(f64::total_cmp)
+    }
+
+    /// Returns the vCards that have a location inside a bounding box.
+    ///
+    /// ```rust
+    /// use vcard::{BoundingBox, Geo, VCard, values::GeoUri};
+    ///
+    /// let mut nearby = VCard::new("Nearby");
+    ///
+    /// nearby.geos.push(Geo::new(GeoUri::new(25.04, 121.56).unwrap().into()));
+    ///
+    /// let mut far = VCard::new("Far");
+    ///
+    /// far.geos.push(Geo::new("geo:35.68,139.65".parse().unwrap()));
+    ///
+    /// let vcards = [nearby, far];
+    /// let me = GeoUri::new(25.03, 121.57).unwrap();
+    ///
+    /// let found = VCard::within(&vcards, &BoundingBox::around(&me, 5000.0));
+    ///
+    /// assert_eq!(1, found.len());
+    /// assert_eq!("Nearby", found[0].formatted_names[0].value);
+    /// ```
+    pub fn within<'a>(vcards: &'a [VCard], bounding_box: &BoundingBox) -> Vec<&'a VCard> {
+        vcards
+            .iter()
+            .filter(|vcard| {
+                vcard.locations().iter().any(|location| bounding_box.contains(location))
+            })
+            .collect()
+    }
+
+    /// Sorts vCards by their distance to a location, the nearest first.
+    ///
+    /// vCards without a WGS 84 location are moved to the end, and the sort is stable.
+    pub fn sort_by_distance(vcards: &mut [VCard], location: &GeoUri) {
+        vcards.sort_by_cached_key(|vcard| match vcard.distance_to(location) {
+            // Distances are never negative, so their bits order the same way as the numbers.
+            Some(distance) => (false, distance.to_bits()),
+            None => (true, 0),
+        });
+    }
+}
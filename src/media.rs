@@ -0,0 +1,323 @@
+//! Embedded media of the PHOTO, LOGO, SOUND and KEY properties, and moving them in and out of vCards.
+
+use std::{
+    fmt::Write,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use mime::Mime;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    parameters::Parameters,
+    property::Property,
+    values::{TextOrUri, Uri},
+    vcard::VCard,
+};
+
+/// Stores the media moved out of vCards and loads the media referenced by them, for [`VCard::externalize_media`] and [`VCard::inline_media`].
+///
+/// Both methods receive the name of the property, e.g. `PHOTO`, so a resolver can choose which properties it handles.
+pub trait MediaResolver {
+    /// Stores the bytes of an embedded media and returns the URI that refers to them from now on, or `None` to keep the media embedded.
+    ///
+    /// The SHA-256 hash of the bytes is given so that a content-addressed store does not need to compute it again.
+    fn store(
+        &mut self,
+        property: &str,
+        media_type: &Mime,
+        data: &[u8],
+        sha256: &[u8; 32],
+    ) -> Result<Option<Uri>, io::Error>;
+
+    /// Loads the media type and the bytes that a URI refers to, or returns `None` to keep the reference.
+    fn load(&mut self, property: &str, uri: &Uri) -> Result<Option<(Mime, Vec<u8>)>, io::Error>;
+}
+
+/// A media moved by [`VCard::externalize_media`] or [`VCard::inline_media`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MediaReference {
+    /// The name of the property, e.g. `PHOTO`.
+    pub property:   &'static str,
+    /// The external URI, which the property refers to after externalizing and referred to before inlining.
+    pub uri:        Uri,
+    /// The media type of the bytes.
+    pub media_type: Mime,
+    /// The SHA-256 hash of the bytes.
+    pub sha256:     [u8; 32],
+    /// The number of bytes.
+    pub size:       usize,
+}
+
+impl MediaReference {
+    /// Returns the SHA-256 hash in lowercase hexadecimal.
+    pub fn sha256_hex(&self) -> String {
+        self.sha256.iter().fold(String::with_capacity(64), |mut s, byte| {
+            // Writing to a `String` never fails.
+            write!(s, "{byte:02x}").unwrap();
+
+            s
+        })
+    }
+}
+
+/// Returns the usual file extension of a media type, e.g. `jpg` for `image/jpeg`.
+fn extension(mime: &Mime) -> &'static str {
+    match mime.essence_str() {
+        "image/jpeg" => "jpg",
+        "image/svg+xml" => "svg",
+        "audio/mpeg" => "mp3",
+        "audio/ogg" => "ogg",
+        "text/plain" => "txt",
+        essence => mime_guess::get_mime_extensions_str(essence)
+            .and_then(|extensions| extensions.first().copied())
+            .unwrap_or("bin"),
+    }
+}
+
+/// Turns a name into a file name stem that is safe on common file systems, e.g. `Magic_Len` for `Magic Len`.
+fn file_stem(name: &str) -> String {
+    let stem: String = name
+        .trim()
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == '-' || c == '.' { c } else { '_' })
+        .collect();
+
+    stem.trim_matches(['.', '_']).to_string()
+}
+
+impl VCard {
+    /// Returns the file name stem for files derived from this vCard, which comes from the first FN value or, if that has no usable characters, the UID.
+    fn media_stem(&self) -> String {
+        let uid = self.uid.as_ref().map(|uid| match &uid.value {
+            TextOrUri::Text(text) => file_stem(text),
+            TextOrUri::Uri(uri) => file_stem(uri.as_str().rsplit(':').next().unwrap_or_default()),
+        });
+
+        self.formatted_names
+            .first()
+            .map(|formatted_name| file_stem(&formatted_name.value))
+            .filter(|stem| !stem.is_empty())
+            .or(uid)
+            .filter(|stem| !stem.is_empty())
+            .unwrap_or_else(|| String::from("vcard"))
+    }
+
+    /// Writes the media embedded as `data:` URIs in the PHOTO, LOGO and SOUND properties into a directory, and returns the paths of the written files.
+    ///
+    /// The files are named after the FN or the UID, the property and the media type, e.g. `Magic_Len-photo.jpg`, `Magic_Len-photo-2.png` and `Magic_Len-sound.mp3`. Existing files are overwritten, and media referenced by other URIs are left alone.
+    ///
+    /// ```rust,no_run
+    /// use vcard::{Photo, VCard, mime, values::Uri};
+    ///
+    /// let mut vcard = VCard::new("Magic Len");
+    ///
+    /// vcard.photos.push(Photo::new(Uri::from_data(&mime::IMAGE_PNG, &[
+    ///     0x89, b'P', b'N', b'G',
+    /// ])));
+    ///
+    /// let paths = vcard.extract_media("/tmp").unwrap();
+    ///
+    /// assert_eq!("/tmp/Magic_Len-photo.png", paths[0].to_str().unwrap());
+    /// ```
+    pub fn extract_media<P: AsRef<Path>>(&self, directory: P) -> Result<Vec<PathBuf>, io::Error> {
+        let directory = directory.as_ref();
+        let stem = self.media_stem();
+
+        let mut paths = Vec::new();
+
+        for (label, properties) in
+            [("photo", &self.photos), ("logo", &self.logos), ("sound", &self.sounds)]
+        {
+            let mut index = 0;
+
+            for (mime, data) in properties.iter().filter_map(|property| property.value.data()) {
+                index += 1;
+
+                let name = if index == 1 {
+                    format!("{stem}-{label}.{}", extension(&mime))
+                } else {
+                    format!("{stem}-{label}-{index}.{}", extension(&mime))
+                };
+
+                let path = directory.join(name);
+
+                fs::write(&path, data)?;
+
+                paths.push(path);
+            }
+        }
+
+        Ok(paths)
+    }
+}
+
+/// Collects the URIs of the properties whose values are URIs, or can be, along with the property names and parameters.
+fn push_uris<'a>(
+    uris: &mut Vec<(&'static str, &'a mut Uri, &'a mut Parameters)>,
+    name: &'static str,
+    properties: &'a mut [Property<Uri>],
+) {
+    uris.extend(
+        properties.iter_mut().map(|property| (name, &mut property.value, &mut property.parameters)),
+    );
+}
+
+fn push_text_or_uris<'a>(
+    uris: &mut Vec<(&'static str, &'a mut Uri, &'a mut Parameters)>,
+    name: &'static str,
+    properties: &'a mut [Property<TextOrUri>],
+) {
+    uris.extend(properties.iter_mut().filter_map(|property| match &mut property.value {
+        TextOrUri::Uri(uri) => Some((name, uri, &mut property.parameters)),
+        TextOrUri::Text(_) => None,
+    }));
+}
+
+impl VCard {
+    /// Returns the URI values of all the properties except UID and TZ, whose URIs are identifiers rather than resources.
+    fn uri_values_mut(&mut self) -> Vec<(&'static str, &mut Uri, &mut Parameters)> {
+        let mut uris = Vec::new();
+
+        push_uris(&mut uris, "SOURCE", &mut self.sources);
+        push_uris(&mut uris, "PHOTO", &mut self.photos);
+        push_uris(&mut uris, "IMPP", &mut self.impps);
+        push_uris(&mut uris, "GEO", &mut self.geos);
+        push_uris(&mut uris, "LOGO", &mut self.logos);
+        push_uris(&mut uris, "MEMBER", &mut self.members);
+        push_text_or_uris(&mut uris, "RELATED", &mut self.relations);
+        push_uris(&mut uris, "SOUND", &mut self.sounds);
+        push_uris(&mut uris, "URL", &mut self.urls);
+        push_text_or_uris(&mut uris, "KEY", &mut self.keys);
+        push_uris(&mut uris, "FBURL", &mut self.fburls);
+        push_uris(&mut uris, "CALADRURI", &mut self.calendar_address_uris);
+        push_uris(&mut uris, "CALURI", &mut self.calendar_uris);
+        push_text_or_uris(&mut uris, "SOCIALPROFILE", &mut self.social_profiles);
+
+        uris
+    }
+
+    /// Moves the media embedded as `data:` URIs out of this vCard, replacing every one that the resolver stores with the URI it returns.
+    ///
+    /// The MEDIATYPE parameter of a replaced property is set, because the new URI no longer carries the media type. The moved media are returned with their SHA-256 hashes.
+    ///
+    /// ```rust
+    /// use std::{collections::HashMap, io};
+    ///
+    /// use vcard::{
+    ///     MediaResolver, Photo, VCard,
+    ///     mime::{self, Mime},
+    ///     values::Uri,
+    /// };
+    ///
+    /// #[derive(Default)]
+    /// struct Store(HashMap<String, (Mime, Vec<u8>)>);
+    ///
+    /// impl MediaResolver for Store {
+    ///     fn store(
+    ///         &mut self,
+    ///         _property: &str,
+    ///         media_type: &Mime,
+    ///         data: &[u8],
+    ///         sha256: &[u8; 32],
+    ///     ) -> Result<Option<Uri>, io::Error> {
+    ///         let uri = format!(
+    ///             "https://media.example.com/{:02x}{:02x}",
+    ///             sha256[0], sha256[1]
+    ///         );
+    ///
+    ///         self.0.insert(uri.clone(), (media_type.clone(), data.to_vec()));
+    ///
+    ///         Ok(Some(uri.parse().unwrap()))
+    ///     }
+    ///
+    ///     fn load(
+    ///         &mut self,
+    ///         _property: &str,
+    ///         uri: &Uri,
+    ///     ) -> Result<Option<(Mime, Vec<u8>)>, io::Error> {
+    ///         Ok(self.0.get(uri.as_str()).cloned())
+    ///     }
+    /// }
+    ///
+    /// let mut vcard = VCard::new("Magic Len");
+    ///
+    /// vcard.photos.push(Photo::new(Uri::from_data(&mime::IMAGE_PNG, b"\x89PNG")));
+    ///
+    /// let original = vcard.clone();
+    /// let mut store = Store::default();
+    ///
+    /// let moved = vcard.externalize_media(&mut store).unwrap();
+    ///
+    /// assert_eq!(moved[0].uri, vcard.photos[0].value);
+    /// assert_eq!(Some(mime::IMAGE_PNG), vcard.photos[0].parameters.media_type);
+    ///
+    /// vcard.inline_media(&mut store).unwrap();
+    ///
+    /// assert_eq!(original.photos[0].value, vcard.photos[0].value);
+    /// ```
+    pub fn externalize_media<R: MediaResolver + ?Sized>(
+        &mut self,
+        resolver: &mut R,
+    ) -> Result<Vec<MediaReference>, io::Error> {
+        let mut moved = Vec::new();
+
+        for (property, uri, parameters) in self.uri_values_mut() {
+            let Some((media_type, data)) = uri.data() else {
+                continue;
+            };
+
+            let sha256: [u8; 32] = Sha256::digest(&data).into();
+
+            let Some(external) = resolver.store(property, &media_type, &data, &sha256)? else {
+                continue;
+            };
+
+            *uri = external.clone();
+            parameters.media_type = Some(media_type.clone());
+
+            moved.push(MediaReference {
+                property,
+                uri: external,
+                media_type,
+                sha256,
+                size: data.len(),
+            });
+        }
+
+        Ok(moved)
+    }
+
+    /// Embeds the media referenced by URIs other than `data:` ones into this vCard as `data:` URIs, for every URI that the resolver loads.
+    ///
+    /// The inlined media are returned with their former URIs and their SHA-256 hashes, so the hashes recorded when externalizing can be checked.
+    pub fn inline_media<R: MediaResolver + ?Sized>(
+        &mut self,
+        resolver: &mut R,
+    ) -> Result<Vec<MediaReference>, io::Error> {
+        let mut inlined = Vec::new();
+
+        for (property, uri, _) in self.uri_values_mut() {
+            if uri.is_data() {
+                continue;
+            }
+
+            let Some((media_type, data)) = resolver.load(property, uri)? else {
+                continue;
+            };
+
+            let external = std::mem::replace(uri, Uri::from_data(&media_type, &data));
+
+            inlined.push(MediaReference {
+                property,
+                uri: external,
+                sha256: Sha256::digest(&data).into(),
+                media_type,
+                size: data.len(),
+            });
+        }
+
+        Ok(inlined)
+    }
+}
@@ -0,0 +1,277 @@
+//! Three-way merging of vCards, which resolves the conflicts of synchronization property by property.
+
+use crate::{
+    parameters::Parameters,
+    property::{ExtensionProperty, Property, PropertyValue, content_line},
+    vcard::{VCard, for_each_property},
+};
+
+/// A property that both sides changed in different ways, so it cannot be merged automatically.
+///
+/// Every side is given as its unfolded content line, or `None` when the property is absent on that side.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeConflict {
+    /// The uppercase name of the property, e.g. `TEL`.
+    pub name:   String,
+    /// The property in the common base version.
+    pub base:   Option<String>,
+    /// The property in our version.
+    pub ours:   Option<String>,
+    /// The property in their version.
+    pub theirs: Option<String>,
+}
+
+/// The result of [`VCard::merge3`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergeOutcome {
+    /// The merged vCard, where every conflicting property keeps our side.
+    pub merged:    VCard,
+    /// The properties that could not be merged automatically.
+    pub conflicts: Vec<MergeConflict>,
+}
+
+impl MergeOutcome {
+    /// Checks whether the merge finished without any conflict.
+    #[inline]
+    pub fn is_clean(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+}
+
+/// The behavior that merging needs from a property.
+trait MergeItem: Clone + PartialEq {
+    /// Returns the parameters, which may carry the PROP-ID and PID identifiers.
+    fn parameters(&self) -> &Parameters;
+
+    /// Checks whether both properties have the same name, which only matters for extension properties.
+    #[inline]
+    fn same_name(&self, _other: &Self) -> bool {
+        true
+    }
+
+    /// Checks whether both properties have the same value, which identifies them when there is no identifier.
+    fn same_value(&self, other: &Self) -> bool;
+
+    /// Returns the uppercase property name, where `name` is the name of the field being merged.
+    fn property_name(&self, name: &str) -> String;
+
+    /// Returns the unfolded content line, where `name` is the name of the field being merged.
+    fn content_line(&self, name: &str) -> String;
+}
+
+impl<V: PropertyValue + Clone + PartialEq> MergeItem for Property<V> {
+    #[inline]
+    fn parameters(&self) -> &Parameters {
+        &self.parameters
+    }
+
+    #[inline]
+    fn same_value(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+
+    #[inline]
+    fn property_name(&self, name: &str) -> String {
+        name.to_string()
+    }
+
+    #[inline]
+    fn content_line(&self, name: &str) -> String {
+        content_line(name, self)
+    }
+}
+
+impl MergeItem for ExtensionProperty {
+    #[inline]
+    fn parameters(&self) -> &Parameters {
+        &self.parameters
+    }
+
+    #[inline]
+    fn same_name(&self, other: &Self) -> bool {
+        self.name.as_str().eq_ignore_ascii_case(other.name.as_str())
+    }
+
+    #[inline]
+    fn same_value(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+
+    #[inline]
+    fn property_name(&self, _name: &str) -> String {
+        self.name.as_str().to_ascii_uppercase()
+    }
+
+    #[inline]
+    fn content_line(&self, _name: &str) -> String {
+        ExtensionProperty::content_line(self)
+    }
+}
+
+/// Checks whether two properties are the same instance.
+///
+/// The PROP-ID parameter is used first, then the PID parameter, and the value itself is the last resort.
+fn same_identity<T: MergeItem>(a: &T, b: &T) -> bool {
+    if !a.same_name(b) {
+        return false;
+    }
+
+    let (a_parameters, b_parameters) = (a.parameters(), b.parameters());
+
+    if a_parameters.prop_id.is_some() || b_parameters.prop_id.is_some() {
+        return a_parameters.prop_id == b_parameters.prop_id;
+    }
+
+    if !a_parameters.pids.is_empty() || !b_parameters.pids.is_empty() {
+        return a_parameters.pids == b_parameters.pids;
+    }
+
+    a.same_value(b)
+}
+
+/// Merges the three versions of one property instance, where `None` means the instance is absent.
+fn merge_one<T: MergeItem>(
+    name: &str,
+    base: Option<&T>,
+    ours: Option<&T>,
+    theirs: Option<&T>,
+    conflicts: &mut Vec<MergeConflict>,
+) -> Option<T> {
+    if ours == theirs || theirs == base {
+        return ours.cloned();
+    }
+
+    if ours == base {
+        return theirs.cloned();
+    }
+
+    // The instance exists on at least one side because the sides differ.
+    let property_name = ours.or(theirs).or(base).unwrap().property_name(name);
+
+    conflicts.push(MergeConflict {
+        name:   property_name,
+        base:   base.map(|property| property.content_line(name)),
+        ours:   ours.map(|property| property.content_line(name)),
+        theirs: theirs.map(|property| property.content_line(name)),
+    });
+
+    ours.cloned()
+}
+
+/// Merges the three versions of a property list, keeping our order and appending the instances that only they added.
+fn merge_many<T: MergeItem>(
+    name: &str,
+    base: &[T],
+    ours: &[T],
+    theirs: &[T],
+    conflicts: &mut Vec<MergeConflict>,
+) -> Vec<T> {
+    let mut merged = Vec::with_capacity(ours.len().max(theirs.len()));
+
+    let mut theirs_matched = vec![false; theirs.len()];
+
+    for our in ours {
+        let base_item = base.iter().find(|item| same_identity(our, *item));
+
+        let their_index = theirs
+            .iter()
+            .enumerate()
+            .position(|(i, item)| !theirs_matched[i] && same_identity(our, item));
+
+        if let Some(i) = their_index {
+            theirs_matched[i] = true;
+        }
+
+        merged.extend(merge_one(
+            name,
+            base_item,
+            Some(our),
+            their_index.map(|i| &theirs[i]),
+            conflicts,
+        ));
+    }
+
+    for (their, _) in theirs.iter().zip(theirs_matched).filter(|(_, matched)| !matched) {
+        let base_item = base.iter().find(|item| same_identity(their, *item));
+
+        merged.extend(merge_one(name, base_item, None, Some(their), conflicts));
+    }
+
+    merged
+}
+
+impl VCard {
+    /// Merges two versions of a vCard which were both derived from a common base version.
+    ///
+    /// Property instances are matched by their PROP-ID or PID parameters, or by their values when they have neither.
+    /// A change made by only one side is taken automatically, and changes made by both sides in different ways are reported as conflicts.
+    /// The REV property is never a conflict, because the later revision simply wins.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vcard::{Note, VCard};
+    ///
+    /// let base = VCard::new("Magic Len");
+    ///
+    /// let mut ours = base.clone();
+    /// ours.notes.push(Note::from("Added by us."));
+    ///
+    /// let mut theirs = base.clone();
+    /// theirs.formatted_names[0].value = String::from("Len");
+    ///
+    /// let outcome = VCard::merge3(&base, &ours, &theirs);
+    ///
+    /// assert!(outcome.is_clean());
+    /// assert_eq!("Len", outcome.merged.formatted_names[0].value);
+    /// assert_eq!("Added by us.", outcome.merged.notes[0].value);
+    /// ```
+    pub fn merge3(base: &VCard, ours: &VCard, theirs: &VCard) -> MergeOutcome {
+        let mut merged = VCard::default();
+        let mut conflicts = Vec::new();
+
+        macro_rules! merge_field {
+            (many, $field:ident, $name:literal) => {
+                merged.$field =
+                    merge_many($name, &base.$field, &ours.$field, &theirs.$field, &mut conflicts);
+            };
+            (one, $field:ident, $name:literal) => {
+                merged.$field = merge_one(
+                    $name,
+                    base.$field.as_ref(),
+                    ours.$field.as_ref(),
+                    theirs.$field.as_ref(),
+                    &mut conflicts,
+                );
+            };
+        }
+
+        macro_rules! merge_fields {
+            ($(($field:ident, $name:literal, $card:tt)),* $(,)?) => {
+                $(merge_field!($card, $field, $name);)*
+            };
+        }
+
+        for_each_property!(merge_fields);
+
+        merged.extensions =
+            merge_many("", &base.extensions, &ours.extensions, &theirs.extensions, &mut conflicts);
+
+        if let Some(index) = conflicts.iter().position(|conflict| conflict.name == "REV") {
+            conflicts.remove(index);
+
+            merged.revision = match (&ours.revision, &theirs.revision) {
+                (Some(our), Some(their)) if their.value.date_time() > our.value.date_time() => {
+                    Some(their.clone())
+                },
+                (Some(our), _) => Some(our.clone()),
+                (None, their) => their.clone(),
+            };
+        }
+
+        MergeOutcome {
+            merged,
+            conflicts,
+        }
+    }
+}
@@ -0,0 +1,289 @@
+//! Upcoming birthdays and anniversaries, computed from the BDAY and ANNIVERSARY properties for reminders.
+
+use chrono::{NaiveDate, TimeZone};
+
+use crate::{
+    property::Property,
+    values::{CalendarDate, CalendarSystem, DateAndOrTime, DateAndOrTimeOrText},
+    vcard::VCard,
+};
+
+/// Where a February 29 occasion falls in a year that is not a leap year.
+///
+/// The same applies to a day missing from a month in other calendars, e.g. 30 Kislev in the Hebrew calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LeapDayPolicy {
+    /// On February 28, or on the last day of the month. This is the default.
+    #[default]
+    February28,
+    /// On March 1, or on the day after the last day of the month.
+    March1,
+    /// Not at all, so the next occurrence is in the next leap year.
+    Skip,
+}
+
+/// The kind of an occasion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OccasionKind {
+    /// The BDAY property.
+    Birthday,
+    /// The ANNIVERSARY property.
+    Anniversary,
+}
+
+/// An upcoming occurrence of a birthday or an anniversary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Occasion {
+    /// Which property the occasion comes from.
+    pub kind:       OccasionKind,
+    /// The date of the occurrence, which can be the reference date itself.
+    pub date:       NaiveDate,
+    /// The number of days from the reference date to the occurrence.
+    pub days_until: u32,
+    /// The age reached or the number of years completed on the occurrence, when the original year is known.
+    pub years:      Option<u32>,
+}
+
+/// Returns the Gregorian date of a yearly occasion in a year of a calendar system, or `None` when the policy skips the missing day in this year.
+fn occurrence_in(
+    calendar: CalendarSystem,
+    year: i32,
+    month: u8,
+    day: u8,
+    policy: LeapDayPolicy,
+) -> Option<NaiveDate> {
+    let days = calendar.days_in_month(year, month, false)?;
+
+    if day <= days {
+        return CalendarDate::new(calendar, year, month, false, day).ok()?.to_gregorian();
+    }
+
+    let last_day = CalendarDate::new(calendar, year, month, false, days).ok()?.to_gregorian()?;
+
+    match policy {
+        LeapDayPolicy::February28 => Some(last_day),
+        LeapDayPolicy::March1 => last_day.succ_opt(),
+        LeapDayPolicy::Skip => None,
+    }
+}
+
+impl DateAndOrTimeOrText {
+    /// Returns the year, month and day of a yearly occasion, where the year can be unknown, e.g. `--0203`.
+    fn month_day(&self) -> Option<(Option<u16>, u8, u8)> {
+        let date = match self {
+            Self::DateAndOrTime(DateAndOrTime::Date(date)) => *date,
+            Self::DateAndOrTime(DateAndOrTime::DateTime(date_time)) => date_time.date(),
+            _ => return None,
+        };
+
+        Some((date.year(), date.month()?, date.day()?))
+    }
+
+    /// Returns the next yearly occurrence on or after a date, or `None` if this value has no month and day.
+    ///
+    /// ```rust
+    /// use vcard::{
+    ///     LeapDayPolicy,
+    ///     chrono::NaiveDate,
+    ///     values::{Date, DateAndOrTimeOrText},
+    /// };
+    ///
+    /// let birthday =
+    ///     DateAndOrTimeOrText::from(Date::from_month_day(2, 29).unwrap());
+    /// let today = NaiveDate::from_ymd_opt(2025, 2, 1).unwrap();
+    ///
+    /// assert_eq!(
+    ///     NaiveDate::from_ymd_opt(2025, 3, 1),
+    ///     birthday.next_occurrence(today, LeapDayPolicy::March1)
+    /// );
+    /// assert_eq!(
+    ///     NaiveDate::from_ymd_opt(2028, 2, 29),
+    ///     birthday.next_occurrence(today, LeapDayPolicy::Skip)
+    /// );
+    /// ```
+    #[inline]
+    pub fn next_occurrence(&self, from: NaiveDate, policy: LeapDayPolicy) -> Option<NaiveDate> {
+        self.next_occurrence_in(CalendarSystem::Gregorian, from, policy)
+    }
+
+    /// Returns the Gregorian date of the next yearly occurrence on or after a date, where this value is written in a calendar system.
+    ///
+    /// ```rust
+    /// use vcard::{
+    ///     LeapDayPolicy,
+    ///     chrono::NaiveDate,
+    ///     values::{CalendarSystem, Date, DateAndOrTimeOrText},
+    /// };
+    ///
+    /// // 1 Tishri, the Hebrew new year.
+    /// let birthday =
+    ///     DateAndOrTimeOrText::from(Date::from_month_day(1, 1).unwrap());
+    /// let today = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+    ///
+    /// assert_eq!(
+    ///     NaiveDate::from_ymd_opt(2025, 9, 23),
+    ///     birthday.next_occurrence_in(
+    ///         CalendarSystem::Hebrew,
+    ///         today,
+    ///         LeapDayPolicy::default()
+    ///     )
+    /// );
+    /// ```
+    pub fn next_occurrence_in(
+        &self,
+        calendar: CalendarSystem,
+        from: NaiveDate,
+        policy: LeapDayPolicy,
+    ) -> Option<NaiveDate> {
+        let (_, month, day) = self.month_day()?;
+        let year = CalendarDate::from_gregorian(from, calendar).ok()?.year();
+
+        // A leap day skipped by the policy comes back within eight years, e.g. 2096 to 2104, and a missing day of another calendar within a few more.
+        (year..=year + 12)
+            .filter_map(|year| occurrence_in(calendar, year, month, day, policy))
+            .find(|date| *date >= from)
+    }
+
+    /// Returns the number of complete years from this date to another date, e.g. an age, or `None` if the year is unknown.
+    ///
+    /// In years without February 29, a leap day occasion is completed on the date given by the policy, where `Skip` counts as March 1.
+    #[inline]
+    pub fn years_on(&self, date: NaiveDate, policy: LeapDayPolicy) -> Option<u32> {
+        self.years_on_in(CalendarSystem::Gregorian, date, policy)
+    }
+
+    /// Returns the number of complete years from this date, written in a calendar system, to a Gregorian date.
+    pub fn years_on_in(
+        &self,
+        calendar: CalendarSystem,
+        date: NaiveDate,
+        policy: LeapDayPolicy,
+    ) -> Option<u32> {
+        let (Some(year), month, day) = self.month_day()? else {
+            return None;
+        };
+
+        let policy = if policy == LeapDayPolicy::Skip { LeapDayPolicy::March1 } else { policy };
+
+        let current_year = CalendarDate::from_gregorian(date, calendar).ok()?.year();
+
+        let mut years = current_year - i32::from(year);
+
+        if occurrence_in(calendar, current_year, month, day, policy)
+            .is_some_and(|occurrence| date < occurrence)
+        {
+            years -= 1;
+        }
+
+        u32::try_from(years).ok()
+    }
+}
+
+/// Returns the calendar system and the value of a BDAY or ANNIVERSARY property, or `None` if the calendar system is unknown.
+fn calendar_value(
+    property: Option<&Property<DateAndOrTimeOrText>>,
+) -> Option<(CalendarSystem, &DateAndOrTimeOrText)> {
+    let property = property?;
+
+    Some((CalendarSystem::from_calscale(property.parameters.calscale.as_ref())?, &property.value))
+}
+
+impl VCard {
+    fn occasion_value(&self, kind: OccasionKind) -> Option<(CalendarSystem, &DateAndOrTimeOrText)> {
+        match kind {
+            OccasionKind::Birthday => calendar_value(self.birthday.as_ref()),
+            OccasionKind::Anniversary => calendar_value(self.anniversary.as_ref()),
+        }
+    }
+
+    /// Returns the next occurrence of the birthday or the anniversary, on or after the date of `now` in its time zone.
+    ///
+    /// Values in the calendar systems of [`CalendarSystem`] follow their CALSCALE parameter, and values in free-form text, without a month and a day, or in an unknown calendar system have no occurrence.
+    ///
+    /// ```rust
+    /// use vcard::{
+    ///     Birthday, LeapDayPolicy, OccasionKind, VCard,
+    ///     chrono::{NaiveDate, TimeZone},
+    ///     chrono_tz::Asia::Taipei,
+    ///     values::Date,
+    /// };
+    ///
+    /// let mut vcard = VCard::new("Magic Len");
+    ///
+    /// vcard.birthday = Some(Birthday::new(
+    ///     Date::from_year_month_day(1990, 4, 12).unwrap().into(),
+    /// ));
+    ///
+    /// // It is still April 11 in UTC, but already April 12 in Taipei.
+    /// let now = Taipei.with_ymd_and_hms(2025, 4, 12, 1, 0, 0).unwrap();
+    ///
+    /// let birthday = vcard
+    ///     .next_occasion(OccasionKind::Birthday, &now, LeapDayPolicy::default())
+    ///     .unwrap();
+    ///
+    /// assert_eq!(NaiveDate::from_ymd_opt(2025, 4, 12).unwrap(), birthday.date);
+    /// assert_eq!(0, birthday.days_until);
+    /// assert_eq!(Some(35), birthday.years);
+    /// ```
+    pub fn next_occasion<Tz: TimeZone>(
+        &self,
+        kind: OccasionKind,
+        now: &chrono::DateTime<Tz>,
+        policy: LeapDayPolicy,
+    ) -> Option<Occasion> {
+        let (calendar, value) = self.occasion_value(kind)?;
+        let today = now.date_naive();
+        let date = value.next_occurrence_in(calendar, today, policy)?;
+
+        Some(Occasion {
+            kind,
+            date,
+            days_until: (date - today).num_days() as u32,
+            years: value.years_on_in(calendar, date, policy),
+        })
+    }
+
+    /// Returns the upcoming birthday and anniversary, the soonest first.
+    pub fn upcoming_occasions<Tz: TimeZone>(
+        &self,
+        now: &chrono::DateTime<Tz>,
+        policy: LeapDayPolicy,
+    ) -> Vec<Occasion> {
+        let mut occasions: Vec<Occasion> = [OccasionKind::Birthday, OccasionKind::Anniversary]
+            .into_iter()
+            .filter_map(|kind| self.next_occasion(kind, now, policy))
+            .collect();
+
+        occasions.sort_by_key(|occasion| occasion.days_until);
+
+        occasions
+    }
+
+    /// Returns the age on the date of `now` in its time zone, or `None` if the birth year is unknown.
+    #[inline]
+    pub fn age<Tz: TimeZone>(
+        &self,
+        now: &chrono::DateTime<Tz>,
+        policy: LeapDayPolicy,
+    ) -> Option<u32> {
+        let (calendar, value) = self.occasion_value(OccasionKind::Birthday)?;
+
+        value.years_on_in(calendar, now.date_naive(), policy)
+    }
+
+    /// Sorts vCards by their next birthday or anniversary, the soonest first.
+    ///
+    /// vCards without an occurrence are moved to the end, and the sort is stable.
+    pub fn sort_by_upcoming<Tz: TimeZone>(
+        vcards: &mut [VCard],
+        kind: OccasionKind,
+        now: &chrono::DateTime<Tz>,
+        policy: LeapDayPolicy,
+    ) {
+        // `None` would sort first, so vCards without an occurrence are keyed behind all the others.
+        vcards.sort_by_cached_key(|vcard| match vcard.next_occasion(kind, now, policy) {
+            Some(occasion) => (false, occasion.days_until),
+            None => (true, 0),
+        });
+    }
+}
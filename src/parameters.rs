@@ -700,7 +700,7 @@ fn single_value(raw: &str) -> String {
 ///
 /// When `split_inside_quotes` is enabled, commas inside a quoted item also separate values.
 /// That accepts the legacy `TYPE="voice,cell"` form which older versions of this crate produced.
-fn list_values(raw: &str, split_inside_quotes: bool) -> Vec<String> {
+pub(crate) fn list_values(raw: &str, split_inside_quotes: bool) -> Vec<String> {
     let mut items = Vec::new();
 
     let bytes = raw.as_bytes();
@@ -1,6 +1,6 @@
 //! The vCard text parser.
 
-mod content_line;
+pub(crate) mod content_line;
 mod lines;
 
 use std::str::FromStr;
@@ -11,6 +11,7 @@ use lines::LogicalLines;
 use crate::{
     error::{ParseError, ParseErrorKind},
     parameters::{AnyParameter, Parameters},
+    projection::is_projected,
     property::{ExtensionProperty, GroupName, Property, PropertyValue},
     values::Token,
     vcard::{VCard, for_each_property},
@@ -20,43 +21,60 @@ impl FromStr for VCard {
     type Err = ParseError;
 
     /// Parses text that contains exactly one vCard.
+    #[inline]
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut lines = LogicalLines::new(s);
-
-        let Some(vcard) = parse_card(&mut lines)? else {
-            return Err(ParseError {
-                line: lines.current_line_number(),
-                kind: ParseErrorKind::ExpectedBegin,
-            });
-        };
-
-        if let Some((number, _)) = lines.next() {
-            return Err(ParseError {
-                line: number, kind: ParseErrorKind::TrailingData
-            });
-        }
-
-        Ok(vcard)
+        parse_one(s, None)
     }
 }
 
 impl VCard {
     /// Parses text that contains any number of vCards, e.g. the content of a `.vcf` file with several contacts.
+    #[inline]
     pub fn parse_multiple(s: &str) -> Result<Vec<Self>, ParseError> {
-        let mut lines = LogicalLines::new(s);
+        parse_all(s, None)
+    }
+}
 
-        let mut vcards = Vec::new();
+/// Parses text that contains exactly one vCard, keeping only the properties in the projection when one is given.
+pub(crate) fn parse_one(s: &str, projection: Option<&[&str]>) -> Result<VCard, ParseError> {
+    let mut lines = LogicalLines::new(s);
 
-        while let Some(vcard) = parse_card(&mut lines)? {
-            vcards.push(vcard);
-        }
+    let Some(vcard) = parse_card(&mut lines, projection)? else {
+        return Err(ParseError {
+            line: lines.current_line_number(),
+            kind: ParseErrorKind::ExpectedBegin,
+        });
+    };
+
+    if let Some((number, _)) = lines.next() {
+        return Err(ParseError {
+            line: number, kind: ParseErrorKind::TrailingData
+        });
+    }
 
-        Ok(vcards)
+    Ok(vcard)
+}
+
+/// Parses text that contains any number of vCards, keeping only the properties in the projection when one is given.
+pub(crate) fn parse_all(s: &str, projection: Option<&[&str]>) -> Result<Vec<VCard>, ParseError> {
+    let mut lines = LogicalLines::new(s);
+
+    let mut vcards = Vec::new();
+
+    while let Some(vcard) = parse_card(&mut lines, projection)? {
+        vcards.push(vcard);
     }
+
+    Ok(vcards)
 }
 
 /// Parses one vCard from the line iterator, returning `None` when no lines are left.
-fn parse_card(lines: &mut LogicalLines) -> Result<Option<VCard>, ParseError> {
+///
+/// With a projection, the properties that are not in it are skipped without parsing their parameters and values.
+fn parse_card(
+    lines: &mut LogicalLines,
+    projection: Option<&[&str]>,
+) -> Result<Option<VCard>, ParseError> {
     // The first line must be BEGIN:VCARD without a group or parameters.
     let Some((number, line)) = lines.next() else {
         return Ok(None);
@@ -142,6 +160,10 @@ fn parse_card(lines: &mut LogicalLines) -> Result<Option<VCard>, ParseError> {
             });
         }
 
+        if projection.is_some_and(|names| !is_projected(names, content.name)) {
+            continue;
+        }
+
         add_property(&mut vcard, number, content)?;
     }
 }
@@ -0,0 +1,136 @@
+//! Access to multi-valued properties in the order of their PREF parameters, e.g. the primary email address.
+
+use crate::{
+    parameters::{Pref, TypeValue},
+    property::{Email, Property, Tel, by_preference},
+    vcard::VCard,
+};
+
+impl<V> Property<V> {
+    /// Checks whether this property has all of the given TYPE values, which is always true for an empty list.
+    #[inline]
+    pub fn has_types(&self, types: &[TypeValue]) -> bool {
+        types.iter().all(|t| self.parameters.types.contains(t))
+    }
+}
+
+impl VCard {
+    /// Returns the properties that have all of the given TYPE values, the most preferred first, where properties with the same PREF or without PREF keep their order.
+    ///
+    /// ```rust
+    /// use vcard::{Tel, TypeValue, VCard, values::TelValue};
+    ///
+    /// let mut vcard = VCard::new("Magic Len");
+    ///
+    /// for (number, types) in [
+    ///     ("+886 2 1234 5678", vec![TypeValue::Work, TypeValue::Voice]),
+    ///     ("+886 912 345 678", vec![TypeValue::Cell, TypeValue::Work]),
+    ///     ("+886 987 654 321", vec![TypeValue::Cell, TypeValue::Home]),
+    /// ] {
+    ///     let mut tel = Tel::new(TelValue::Text(String::from(number)));
+    ///
+    ///     tel.parameters.types = types;
+    ///     vcard.telephones.push(tel);
+    /// }
+    ///
+    /// let cells = VCard::by_preference(&vcard.telephones, &[
+    ///     TypeValue::Cell,
+    ///     TypeValue::Work,
+    /// ]);
+    ///
+    /// assert_eq!(1, cells.len());
+    /// assert_eq!(
+    ///     TelValue::Text(String::from("+886 912 345 678")),
+    ///     cells[0].value
+    /// );
+    /// ```
+    pub fn by_preference<'a, V>(
+        properties: &'a [Property<V>],
+        types: &[TypeValue],
+    ) -> Vec<&'a Property<V>> {
+        let mut properties = by_preference(properties);
+
+        properties.retain(|property| property.has_types(types));
+
+        properties
+    }
+
+    /// Returns the most preferred property that has all of the given TYPE values.
+    #[inline]
+    pub fn preferred<'a, V>(
+        properties: &'a [Property<V>],
+        types: &[TypeValue],
+    ) -> Option<&'a Property<V>> {
+        Self::by_preference(properties, types).into_iter().next()
+    }
+
+    /// Returns the most preferred email address that has all of the given TYPE values.
+    #[inline]
+    pub fn preferred_email(&self, types: &[TypeValue]) -> Option<&Email> {
+        Self::preferred(&self.emails, types)
+    }
+
+    /// Returns the most preferred telephone number that has all of the given TYPE values.
+    #[inline]
+    pub fn preferred_telephone(&self, types: &[TypeValue]) -> Option<&Tel> {
+        Self::preferred(&self.telephones, types)
+    }
+
+    /// Returns the email addresses that have all of the given TYPE values, the most preferred first.
+    #[inline]
+    pub fn emails_by_preference(&self, types: &[TypeValue]) -> Vec<&Email> {
+        Self::by_preference(&self.emails, types)
+    }
+
+    /// Returns the telephone numbers that have all of the given TYPE values, the most preferred first.
+    #[inline]
+    pub fn telephones_by_preference(&self, types: &[TypeValue]) -> Vec<&Tel> {
+        Self::by_preference(&self.telephones, types)
+    }
+
+    /// Makes the property at `index` the most preferred one with `PREF=1`, and renumbers the other properties that have PREF from 2 in their previous order.
+    ///
+    /// The properties without PREF are left alone, and the numbers stop at 100, the least preferred value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// ```rust
+    /// use vcard::{Email, Pref, VCard};
+    ///
+    /// let mut vcard = VCard::new("Magic Len");
+    ///
+    /// for (address, pref) in [("len@example.com", 1), ("magic@example.org", 2)] {
+    ///     let mut email = Email::new(address.parse().unwrap());
+    ///
+    ///     email.parameters.pref = Some(Pref::new(pref).unwrap());
+    ///     vcard.emails.push(email);
+    /// }
+    ///
+    /// VCard::set_preferred(&mut vcard.emails, 1);
+    ///
+    /// assert_eq!(
+    ///     "magic@example.org",
+    ///     vcard.preferred_email(&[]).unwrap().value.as_str()
+    /// );
+    /// assert_eq!(Some(2), vcard.emails[0].parameters.pref.map(|pref| pref.get()));
+    /// ```
+    pub fn set_preferred<V>(properties: &mut [Property<V>], index: usize) {
+        let mut others: Vec<usize> = (0..properties.len())
+            .filter(|&i| i != index && properties[i].parameters.pref.is_some())
+            .collect();
+
+        // The sort is stable, so properties with the same PREF keep their order.
+        others.sort_by_key(|&i| properties[i].parameters.pref.map(|pref| pref.get()));
+
+        properties[index].parameters.pref = Some(Pref::new(1).unwrap());
+
+        for (n, i) in others.into_iter().enumerate() {
+            let pref = (n + 2).min(100) as u8;
+
+            // The number is between 2 and 100.
+            properties[i].parameters.pref = Some(Pref::new(pref).unwrap());
+        }
+    }
+}
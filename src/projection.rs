@@ -0,0 +1,111 @@
+//! Partial retrieval of vCards, which keeps only some properties like the `address-data` element of CardDAV does.
+
+use std::fmt::{self, Display, Formatter};
+
+use crate::{
+    error::ParseError,
+    parse::{parse_all, parse_one},
+    vcard::{VCard, for_each_property},
+};
+
+/// Checks whether a property is kept by a projection. FN is always kept because a vCard cannot exist without it.
+#[inline]
+pub(crate) fn is_projected(names: &[&str], name: &str) -> bool {
+    name.eq_ignore_ascii_case("FN") || names.iter().any(|n| n.eq_ignore_ascii_case(name))
+}
+
+/// A vCard that serializes only the properties of a projection, which is created by [`VCard::projected`].
+#[derive(Debug, Clone, Copy)]
+pub struct ProjectedVCard<'a> {
+    vcard: &'a VCard,
+    names: &'a [&'a str],
+}
+
+impl Display for ProjectedVCard<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.vcard.write(f, Some(self.names))
+    }
+}
+
+impl VCard {
+    /// Returns a copy of this vCard with only the given properties, plus FN which is always kept.
+    ///
+    /// Property names are compared case-insensitively and can also name extension properties.
+    ///
+    /// ```rust
+    /// use vcard::{Note, VCard};
+    ///
+    /// let mut vcard = VCard::new("Magic Len");
+    ///
+    /// vcard.notes.push(Note::new(String::from("Hello")));
+    ///
+    /// let projected = vcard.project(&["TEL", "PHOTO"]);
+    ///
+    /// assert_eq!(
+    ///     "BEGIN:VCARD\r\nVERSION:4.0\r\nFN:Magic Len\r\nEND:VCARD\r\n",
+    ///     projected.to_string()
+    /// );
+    /// ```
+    pub fn project(&self, names: &[&str]) -> VCard {
+        let mut projected = VCard::default();
+
+        macro_rules! project_fields {
+            ($(($field:ident, $name:literal, $card:tt)),* $(,)?) => {
+                $(
+                    if is_projected(names, $name) {
+                        projected.$field = self.$field.clone();
+                    }
+                )*
+            };
+        }
+
+        for_each_property!(project_fields);
+
+        projected.extensions = self
+            .extensions
+            .iter()
+            .filter(|extension| is_projected(names, extension.name.as_str()))
+            .cloned()
+            .collect();
+
+        projected
+    }
+
+    /// Serializes only the given properties, plus FN which is always written, without copying the vCard.
+    ///
+    /// ```rust
+    /// use vcard::{Note, VCard};
+    ///
+    /// let mut vcard = VCard::new("Magic Len");
+    ///
+    /// vcard.notes.push(Note::new(String::from("Hello")));
+    ///
+    /// assert_eq!(
+    ///     "BEGIN:VCARD\r\nVERSION:4.0\r\nFN:Magic \
+    ///      Len\r\nNOTE:Hello\r\nEND:VCARD\r\n",
+    ///     vcard.projected(&["note"]).to_string()
+    /// );
+    /// ```
+    #[inline]
+    pub fn projected<'a>(&'a self, names: &'a [&'a str]) -> ProjectedVCard<'a> {
+        ProjectedVCard {
+            vcard: self,
+            names,
+        }
+    }
+
+    /// Parses text that contains exactly one vCard, skipping the properties that are not in the projection.
+    ///
+    /// Skipped properties are not parsed at all, so an invalid value in one of them is not reported.
+    #[inline]
+    pub fn parse_projected(s: &str, names: &[&str]) -> Result<Self, ParseError> {
+        parse_one(s, Some(names))
+    }
+
+    /// Parses text that contains any number of vCards, skipping the properties that are not in the projection.
+    #[inline]
+    pub fn parse_multiple_projected(s: &str, names: &[&str]) -> Result<Vec<Self>, ParseError> {
+        parse_all(s, Some(names))
+    }
+}
@@ -96,6 +96,15 @@ impl<V> From<V> for Property<V> {
     }
 }
 
+/// Returns the properties sorted by their PREF parameters, the most preferred first and the others in their order.
+pub(crate) fn by_preference<T>(properties: &[Property<T>]) -> Vec<&Property<T>> {
+    let mut properties: Vec<&Property<T>> = properties.iter().collect();
+
+    properties.sort_by_key(|property| property.parameters.pref.map_or(u8::MAX, |pref| pref.get()));
+
+    properties
+}
+
 impl From<&str> for Property<String> {
     #[inline]
     fn from(value: &str) -> Self {
@@ -128,6 +137,18 @@ pub(crate) fn write_property<V: PropertyValue>(
     w.end_line()
 }
 
+/// Builds the unfolded content line of a property, without the ending CRLF.
+pub(crate) fn content_line<V: PropertyValue>(name: &str, property: &Property<V>) -> String {
+    let mut line = String::new();
+
+    // Writing into a string never fails.
+    write_property(&mut FoldingWriter::unfolded(&mut line), name, property).unwrap();
+
+    line.truncate(line.len() - 2);
+
+    line
+}
+
 /// A property that this crate has no dedicated field for, which is an x-name or IANA extension property.
 #[derive(Debug, Clone, PartialEq)]
 pub struct ExtensionProperty {
@@ -179,6 +200,18 @@ impl ExtensionProperty {
 
         w.end_line()
     }
+
+    /// Builds the unfolded content line of this property, without the ending CRLF.
+    pub(crate) fn content_line(&self) -> String {
+        let mut line = String::new();
+
+        // Writing into a string never fails.
+        self.write(&mut FoldingWriter::unfolded(&mut line)).unwrap();
+
+        line.truncate(line.len() - 2);
+
+        line
+    }
 }
 
 impl PropertyValue for String {
@@ -2,6 +2,17 @@
 
 use std::fmt::{self, Write};
 
+use base64::{
+    alphabet,
+    engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig},
+};
+
+/// The standard base64 engine, decoding with or without padding because data URIs and armored keys are often edited by hand.
+pub(crate) const BASE64: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::STANDARD,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
 /// Checks whether the string is a non-empty token made of ASCII letters, digits and hyphens.
 /// This matches both the `iana-token` and the `group` rules of RFC 6350.
 #[inline]
@@ -0,0 +1,284 @@
+//! Downscaling and recompression of embedded PHOTO and LOGO images, enabled by the `image` feature.
+
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    io::Cursor,
+};
+
+use image::{
+    DynamicImage, ImageDecoder, ImageReader, RgbImage,
+    codecs::{jpeg::JpegEncoder, webp::WebPEncoder},
+    imageops::FilterType,
+};
+use mime::Mime;
+
+use crate::{property::Property, values::Uri, vcard::VCard};
+
+/// The smallest dimension that a budget can shrink an image to before giving up.
+const MIN_DIMENSION: u32 = 16;
+
+/// The lowest JPEG quality that a budget can reduce to before the image is shrunk instead.
+const MIN_QUALITY: u8 = 40;
+
+/// The encoding of a recompressed image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ImageEncoding {
+    /// Lossy JPEG, where transparent pixels are put on a white background. This is the default.
+    #[default]
+    Jpeg,
+    /// Lossless WebP, which keeps transparency and can only meet a budget by shrinking.
+    WebP,
+}
+
+impl ImageEncoding {
+    /// Returns the media type of this encoding.
+    #[inline]
+    pub fn mime(self) -> Mime {
+        match self {
+            Self::Jpeg => mime::IMAGE_JPEG,
+            // The `mime` crate has no constant for WebP, and this literal is always valid.
+            Self::WebP => "image/webp".parse().unwrap(),
+        }
+    }
+}
+
+/// How [`Property::shrink_image`] downscales and recompresses an image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImageOptions {
+    max_dimension: u32,
+    max_bytes:     Option<usize>,
+    encoding:      ImageEncoding,
+    quality:       u8,
+}
+
+impl ImageOptions {
+    /// Creates options that fit an image into a square of `max_dimension` pixels, encoded as JPEG with quality 85 and without a byte budget.
+    #[inline]
+    pub const fn new(max_dimension: u32) -> Self {
+        Self {
+            max_dimension,
+            max_bytes: None,
+            encoding: ImageEncoding::Jpeg,
+            quality: 85,
+        }
+    }
+
+    /// Sets the largest encoded size in bytes, which is reached by lowering the JPEG quality down to 40 and then by shrinking the image.
+    #[inline]
+    pub const fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = Some(max_bytes);
+
+        self
+    }
+
+    /// Sets the encoding.
+    #[inline]
+    pub const fn with_encoding(mut self, encoding: ImageEncoding) -> Self {
+        self.encoding = encoding;
+
+        self
+    }
+
+    /// Sets the starting JPEG quality within `1..=100`.
+    #[inline]
+    pub const fn with_quality(mut self, quality: u8) -> Self {
+        self.quality = if quality == 0 {
+            1
+        } else if quality > 100 {
+            100
+        } else {
+            quality
+        };
+
+        self
+    }
+}
+
+impl Default for ImageOptions {
+    /// Fits an image into 512 by 512 pixels.
+    #[inline]
+    fn default() -> Self {
+        Self::new(512)
+    }
+}
+
+/// The error type of [`Property::shrink_image`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ImageProcessingError {
+    /// The value is not a `data:` URI, so there is no embedded image.
+    NotEmbedded,
+    /// The embedded data cannot be decoded as an image.
+    Decode(image::ImageError),
+    /// The image cannot be encoded.
+    Encode(image::ImageError),
+    /// The image cannot fit the byte budget even at the smallest dimension.
+    OverBudget,
+}
+
+impl Display for ImageProcessingError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::NotEmbedded => f.write_str("the value is not an embedded data URI"),
+            Self::Decode(error) => write!(f, "the embedded image cannot be decoded: {error}"),
+            Self::Encode(error) => write!(f, "the image cannot be encoded: {error}"),
+            Self::OverBudget => f.write_str("the image cannot fit the byte budget"),
+        }
+    }
+}
+
+impl Error for ImageProcessingError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Decode(error) | Self::Encode(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Decodes an image and turns it upright by its EXIF orientation, which is dropped together with the rest of the metadata.
+fn decode(data: &[u8]) -> Result<DynamicImage, image::ImageError> {
+    let mut decoder = ImageReader::new(Cursor::new(data)).with_guessed_format()?.into_decoder()?;
+
+    let orientation = decoder.orientation()?;
+
+    let mut image = DynamicImage::from_decoder(decoder)?;
+
+    image.apply_orientation(orientation);
+
+    Ok(image)
+}
+
+/// Puts an image on a white background, because JPEG has no transparency.
+fn flatten(image: &DynamicImage) -> RgbImage {
+    if !image.color().has_alpha() {
+        return image.to_rgb8();
+    }
+
+    let rgba = image.to_rgba8();
+
+    RgbImage::from_fn(rgba.width(), rgba.height(), |x, y| {
+        let [r, g, b, a] = rgba.get_pixel(x, y).0;
+
+        let blend =
+            |c: u8| ((u16::from(c) * u16::from(a) + 255 * (255 - u16::from(a))) / 255) as u8;
+
+        image::Rgb([blend(r), blend(g), blend(b)])
+    })
+}
+
+fn encode(
+    image: &DynamicImage,
+    encoding: ImageEncoding,
+    quality: u8,
+) -> Result<Vec<u8>, image::ImageError> {
+    let mut bytes = Vec::new();
+
+    match encoding {
+        ImageEncoding::Jpeg => DynamicImage::ImageRgb8(flatten(image))
+            .write_with_encoder(JpegEncoder::new_with_quality(&mut bytes, quality))?,
+        ImageEncoding::WebP => DynamicImage::ImageRgba8(image.to_rgba8())
+            .write_with_encoder(WebPEncoder::new_lossless(&mut bytes))?,
+    }
+
+    Ok(bytes)
+}
+
+/// Downscales and encodes an image until it fits the options.
+fn recompress(
+    image: DynamicImage,
+    options: &ImageOptions,
+) -> Result<Vec<u8>, ImageProcessingError> {
+    let mut dimension = options.max_dimension.max(1);
+
+    loop {
+        let resized = if image.width().max(image.height()) > dimension {
+            image.resize(dimension, dimension, FilterType::Lanczos3)
+        } else {
+            image.clone()
+        };
+
+        let mut quality = options.quality;
+
+        loop {
+            let bytes = encode(&resized, options.encoding, quality)
+                .map_err(ImageProcessingError::Encode)?;
+
+            match options.max_bytes {
+                Some(max_bytes) if bytes.len() > max_bytes => (),
+                _ => return Ok(bytes),
+            }
+
+            if options.encoding != ImageEncoding::Jpeg || quality <= MIN_QUALITY {
+                break;
+            }
+
+            quality = quality.saturating_sub(10).max(MIN_QUALITY);
+        }
+
+        let largest = resized.width().max(resized.height());
+
+        if largest <= MIN_DIMENSION {
+            return Err(ImageProcessingError::OverBudget);
+        }
+
+        dimension = (largest * 3 / 4).max(MIN_DIMENSION);
+    }
+}
+
+impl Property<Uri> {
+    /// Decodes the image embedded in this PHOTO or LOGO property, downscales it to fit the options, and encodes it again without its EXIF metadata.
+    ///
+    /// The value is replaced by a new `data:` URI, and the MEDIATYPE parameter is updated when it exists. Images referenced by other URIs are not fetched and give [`ImageProcessingError::NotEmbedded`].
+    ///
+    /// ```rust
+    /// use vcard::{ImageOptions, Photo, image, mime, values::Uri};
+    ///
+    /// let mut png = Vec::new();
+    ///
+    /// image::RgbImage::new(2000, 1000)
+    ///     .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+    ///     .unwrap();
+    ///
+    /// let mut photo = Photo::new(Uri::from_data(&mime::IMAGE_PNG, &png));
+    ///
+    /// photo.shrink_image(&ImageOptions::new(200).with_max_bytes(4096)).unwrap();
+    ///
+    /// let (mime, jpeg) = photo.value.data().unwrap();
+    ///
+    /// assert_eq!(mime::IMAGE_JPEG, mime);
+    /// assert!(jpeg.len() <= 4096);
+    /// ```
+    pub fn shrink_image(&mut self, options: &ImageOptions) -> Result<(), ImageProcessingError> {
+        let (_, data) = self.value.data().ok_or(ImageProcessingError::NotEmbedded)?;
+
+        let image = decode(&data).map_err(ImageProcessingError::Decode)?;
+
+        let bytes = recompress(image, options)?;
+
+        let mime = options.encoding.mime();
+
+        self.value = Uri::from_data(&mime, &bytes);
+
+        if self.parameters.media_type.is_some() {
+            self.parameters.media_type = Some(mime);
+        }
+
+        Ok(())
+    }
+}
+
+impl VCard {
+    /// Shrinks every embedded image of the PHOTO and LOGO properties with [`Property::shrink_image`], leaving the referenced ones alone.
+    pub fn shrink_images(&mut self, options: &ImageOptions) -> Result<(), ImageProcessingError> {
+        for property in self.photos.iter_mut().chain(self.logos.iter_mut()) {
+            if property.value.is_data() {
+                property.shrink_image(options)?;
+            }
+        }
+
+        Ok(())
+    }
+}
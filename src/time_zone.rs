@@ -0,0 +1,401 @@
+//! Resolution of the TZ property and the TZ parameter to real time zones.
+
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use chrono::{FixedOffset, Offset, TimeZone as _, Utc};
+use chrono_tz::{TZ_VARIANTS, Tz};
+
+use crate::{
+    error::InvalidValueError,
+    parameters::TzParam,
+    property::by_preference,
+    values::{TzValue, Uri},
+    vcard::VCard,
+};
+
+/// The Windows time zone IDs and their IANA zones for the world territory of the CLDR `windowsZones` table.
+static WINDOWS_ZONES: &[(&str, &str)] = &[
+    ("Dateline Standard Time", "Etc/GMT+12"),
+    ("UTC-11", "Etc/GMT+11"),
+    ("Aleutian Standard Time", "America/Adak"),
+    ("Hawaiian Standard Time", "Pacific/Honolulu"),
+    ("Marquesas Standard Time", "Pacific/Marquesas"),
+    ("Alaskan Standard Time", "America/Anchorage"),
+    ("UTC-09", "Etc/GMT+9"),
+    ("Pacific Standard Time (Mexico)", "America/Tijuana"),
+    ("UTC-08", "Etc/GMT+8"),
+    ("Pacific Standard Time", "America/Los_Angeles"),
+    ("US Mountain Standard Time", "America/Phoenix"),
+    ("Mountain Standard Time (Mexico)", "America/Mazatlan"),
+    ("Mountain Standard Time", "America/Denver"),
+    ("Yukon Standard Time", "America/Whitehorse"),
+    ("Central America Standard Time", "America/Guatemala"),
+    ("Central Standard Time", "America/Chicago"),
+    ("Easter Island Standard Time", "Pacific/Easter"),
+    ("Central Standard Time (Mexico)", "America/Mexico_City"),
+    ("Canada Central Standard Time", "America/Regina"),
+    ("SA Pacific Standard Time", "America/Bogota"),
+    ("Eastern Standard Time (Mexico)", "America/Cancun"),
+    ("Eastern Standard Time", "America/New_York"),
+    ("Haiti Standard Time", "America/Port-au-Prince"),
+    ("Cuba Standard Time", "America/Havana"),
+    ("US Eastern Standard Time", "America/Indiana/Indianapolis"),
+    ("Turks And Caicos Standard Time", "America/Grand_Turk"),
+    ("Paraguay Standard Time", "America/Asuncion"),
+    ("Atlantic Standard Time", "America/Halifax"),
+    ("Venezuela Standard Time", "America/Caracas"),
+    ("Central Brazilian Standard Time", "America/Cuiaba"),
+    ("SA Western Standard Time", "America/La_Paz"),
+    ("Pacific SA Standard Time", "America/Santiago"),
+    ("Newfoundland Standard Time", "America/St_Johns"),
+    ("Tocantins Standard Time", "America/Araguaina"),
+    ("E. South America Standard Time", "America/Sao_Paulo"),
+    ("SA Eastern Standard Time", "America/Cayenne"),
+    ("Argentina Standard Time", "America/Argentina/Buenos_Aires"),
+    ("Greenland Standard Time", "America/Nuuk"),
+    ("Montevideo Standard Time", "America/Montevideo"),
+    ("Magallanes Standard Time", "America/Punta_Arenas"),
+    ("Saint Pierre Standard Time", "America/Miquelon"),
+    ("Bahia Standard Time", "America/Bahia"),
+    ("UTC-02", "Etc/GMT+2"),
+    ("Azores Standard Time", "Atlantic/Azores"),
+    ("Cape Verde Standard Time", "Atlantic/Cape_Verde"),
+    ("UTC", "Etc/UTC"),
+    ("GMT Standard Time", "Europe/London"),
+    ("Greenwich Standard Time", "Atlantic/Reykjavik"),
+    ("Sao Tome Standard Time", "Africa/Sao_Tome"),
+    ("Morocco Standard Time", "Africa/Casablanca"),
+    ("W. Europe Standard Time", "Europe/Berlin"),
+    ("Central Europe Standard Time", "Europe/Budapest"),
+    ("Romance Standard Time", "Europe/Paris"),
+    ("Central European Standard Time", "Europe/Warsaw"),
+    ("W. Central Africa Standard Time", "Africa/Lagos"),
+    ("Jordan Standard Time", "Asia/Amman"),
+    ("GTB Standard Time", "Europe/Bucharest"),
+    ("Middle East Standard Time", "Asia/Beirut"),
+    ("Egypt Standard Time", "Africa/Cairo"),
+    ("E. Europe Standard Time", "Europe/Chisinau"),
+    ("Syria Standard Time", "Asia/Damascus"),
+    ("West Bank Standard Time", "Asia/Hebron"),
+    ("South Africa Standard Time", "Africa/Johannesburg"),
+    ("FLE Standard Time", "Europe/Kyiv"),
+    ("Israel Standard Time", "Asia/Jerusalem"),
+    ("South Sudan Standard Time", "Africa/Juba"),
+    ("Kaliningrad Standard Time", "Europe/Kaliningrad"),
+    ("Sudan Standard Time", "Africa/Khartoum"),
+    ("Libya Standard Time", "Africa/Tripoli"),
+    ("Namibia Standard Time", "Africa/Windhoek"),
+    ("Arabic Standard Time", "Asia/Baghdad"),
+    ("Turkey Standard Time", "Europe/Istanbul"),
+    ("Arab Standard Time", "Asia/Riyadh"),
+    ("Belarus Standard Time", "Europe/Minsk"),
+    ("Russian Standard Time", "Europe/Moscow"),
+    ("E. Africa Standard Time", "Africa/Nairobi"),
+    ("Volgograd Standard Time", "Europe/Volgograd"),
+    ("Iran Standard Time", "Asia/Tehran"),
+    ("Arabian Standard Time", "Asia/Dubai"),
+    ("Astrakhan Standard Time", "Europe/Astrakhan"),
+    ("Azerbaijan Standard Time", "Asia/Baku"),
+    ("Russia Time Zone 3", "Europe/Samara"),
+    ("Mauritius Standard Time", "Indian/Mauritius"),
+    ("Saratov Standard Time", "Europe/Saratov"),
+    ("Georgian Standard Time", "Asia/Tbilisi"),
+    ("Caucasus Standard Time", "Asia/Yerevan"),
+    ("Afghanistan Standard Time", "Asia/Kabul"),
+    ("West Asia Standard Time", "Asia/Tashkent"),
+    ("Ekaterinburg Standard Time", "Asia/Yekaterinburg"),
+    ("Pakistan Standard Time", "Asia/Karachi"),
+    ("Qyzylorda Standard Time", "Asia/Qyzylorda"),
+    ("India Standard Time", "Asia/Kolkata"),
+    ("Sri Lanka Standard Time", "Asia/Colombo"),
+    ("Nepal Standard Time", "Asia/Kathmandu"),
+    ("Central Asia Standard Time", "Asia/Bishkek"),
+    ("Bangladesh Standard Time", "Asia/Dhaka"),
+    ("Omsk Standard Time", "Asia/Omsk"),
+    ("Myanmar Standard Time", "Asia/Yangon"),
+    ("SE Asia Standard Time", "Asia/Bangkok"),
+    ("Altai Standard Time", "Asia/Barnaul"),
+    ("W. Mongolia Standard Time", "Asia/Hovd"),
+    ("North Asia Standard Time", "Asia/Krasnoyarsk"),
+    ("N. Central Asia Standard Time", "Asia/Novosibirsk"),
+    ("Tomsk Standard Time", "Asia/Tomsk"),
+    ("China Standard Time", "Asia/Shanghai"),
+    ("North Asia East Standard Time", "Asia/Irkutsk"),
+    ("Singapore Standard Time", "Asia/Singapore"),
+    ("W. Australia Standard Time", "Australia/Perth"),
+    ("Taipei Standard Time", "Asia/Taipei"),
+    ("Ulaanbaatar Standard Time", "Asia/Ulaanbaatar"),
+    ("Aus Central W. Standard Time", "Australia/Eucla"),
+    ("Transbaikal Standard Time", "Asia/Chita"),
+    ("Tokyo Standard Time", "Asia/Tokyo"),
+    ("North Korea Standard Time", "Asia/Pyongyang"),
+    ("Korea Standard Time", "Asia/Seoul"),
+    ("Yakutsk Standard Time", "Asia/Yakutsk"),
+    ("Cen. Australia Standard Time", "Australia/Adelaide"),
+    ("AUS Central Standard Time", "Australia/Darwin"),
+    ("E. Australia Standard Time", "Australia/Brisbane"),
+    ("AUS Eastern Standard Time", "Australia/Sydney"),
+    ("West Pacific Standard Time", "Pacific/Port_Moresby"),
+    ("Tasmania Standard Time", "Australia/Hobart"),
+    ("Vladivostok Standard Time", "Asia/Vladivostok"),
+    ("Lord Howe Standard Time", "Australia/Lord_Howe"),
+    ("Bougainville Standard Time", "Pacific/Bougainville"),
+    ("Russia Time Zone 10", "Asia/Srednekolymsk"),
+    ("Magadan Standard Time", "Asia/Magadan"),
+    ("Norfolk Standard Time", "Pacific/Norfolk"),
+    ("Sakhalin Standard Time", "Asia/Sakhalin"),
+    ("Central Pacific Standard Time", "Pacific/Guadalcanal"),
+    ("Russia Time Zone 11", "Asia/Kamchatka"),
+    ("New Zealand Standard Time", "Pacific/Auckland"),
+    ("UTC+12", "Etc/GMT-12"),
+    ("Fiji Standard Time", "Pacific/Fiji"),
+    ("Chatham Islands Standard Time", "Pacific/Chatham"),
+    ("UTC+13", "Etc/GMT-13"),
+    ("Tonga Standard Time", "Pacific/Tongatapu"),
+    ("Samoa Standard Time", "Pacific/Apia"),
+    ("Line Islands Standard Time", "Pacific/Kiritimati"),
+];
+
+/// Common abbreviations that are not zone names of the IANA database but name a single region without doubt.
+///
+/// The IANA database already keeps its own legacy names, e.g. `US/Eastern` and `Asia/Calcutta`, as links.
+static ABBREVIATIONS: &[(&str, &str)] = &[
+    ("PST", "America/Los_Angeles"),
+    ("PDT", "America/Los_Angeles"),
+    ("MDT", "America/Denver"),
+    ("CDT", "America/Chicago"),
+    ("EDT", "America/New_York"),
+    ("AKST", "America/Anchorage"),
+    ("AKDT", "America/Anchorage"),
+    ("CEST", "Europe/Paris"),
+    ("JST", "Asia/Tokyo"),
+    ("KST", "Asia/Seoul"),
+    ("HKT", "Asia/Hong_Kong"),
+    ("SGT", "Asia/Singapore"),
+    ("AEST", "Australia/Sydney"),
+    ("AEDT", "Australia/Sydney"),
+    ("NZST", "Pacific/Auckland"),
+    ("NZDT", "Pacific/Auckland"),
+];
+
+const TIME_ZONE_ERROR: InvalidValueError = InvalidValueError::new("time zone");
+
+/// A time zone resolved from a TZ property or a TZ parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResolvedTimeZone {
+    /// A zone of the IANA time zone database, which follows daylight saving time.
+    Named(Tz),
+    /// A fixed offset from UTC.
+    Fixed(FixedOffset),
+}
+
+impl ResolvedTimeZone {
+    /// Returns the offset from UTC at an instant.
+    #[inline]
+    pub fn offset_at<Z: chrono::TimeZone>(&self, instant: &chrono::DateTime<Z>) -> FixedOffset {
+        match self {
+            Self::Named(tz) => tz.offset_from_utc_datetime(&instant.naive_utc()).fix(),
+            Self::Fixed(offset) => *offset,
+        }
+    }
+
+    /// Converts an instant into the local time of this time zone.
+    #[inline]
+    pub fn to_local<Z: chrono::TimeZone>(
+        &self,
+        instant: &chrono::DateTime<Z>,
+    ) -> chrono::DateTime<FixedOffset> {
+        instant.with_timezone(&self.offset_at(instant))
+    }
+
+    /// Returns the current local time of this time zone.
+    #[inline]
+    pub fn now(&self) -> chrono::DateTime<FixedOffset> {
+        self.to_local(&Utc::now())
+    }
+}
+
+impl From<Tz> for ResolvedTimeZone {
+    #[inline]
+    fn from(tz: Tz) -> Self {
+        Self::Named(tz)
+    }
+}
+
+impl From<FixedOffset> for ResolvedTimeZone {
+    #[inline]
+    fn from(offset: FixedOffset) -> Self {
+        Self::Fixed(offset)
+    }
+}
+
+impl Display for ResolvedTimeZone {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Named(tz) => f.write_str(tz.name()),
+            Self::Fixed(offset) => Display::fmt(offset, f),
+        }
+    }
+}
+
+impl FromStr for ResolvedTimeZone {
+    type Err = InvalidValueError;
+
+    /// Resolves a time zone text, which can be an IANA zone name in any case, a Windows time zone ID, a common abbreviation like `PST`, or a UTC offset like `+08:00`, `UTC-5` or `GMT+0530`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if let Some(tz) = iana_zone(s) {
+            return Ok(Self::Named(tz));
+        }
+
+        let alias = WINDOWS_ZONES
+            .iter()
+            .chain(ABBREVIATIONS)
+            .find(|(name, _)| name.eq_ignore_ascii_case(s))
+            .and_then(|(_, zone)| Tz::from_str(zone).ok());
+
+        if let Some(tz) = alias {
+            return Ok(Self::Named(tz));
+        }
+
+        parse_offset(s).map(Self::Fixed).ok_or(TIME_ZONE_ERROR)
+    }
+}
+
+/// Finds an IANA zone by its name, ignoring the case.
+fn iana_zone(name: &str) -> Option<Tz> {
+    Tz::from_str(name)
+        .ok()
+        .or_else(|| TZ_VARIANTS.iter().copied().find(|tz| tz.name().eq_ignore_ascii_case(name)))
+}
+
+/// Parses a UTC offset with an optional `UTC` or `GMT` prefix, where the hours can have one or two digits and the minutes can follow a colon.
+fn parse_offset(s: &str) -> Option<FixedOffset> {
+    let rest = match s.get(..3) {
+        Some(prefix)
+            if prefix.eq_ignore_ascii_case("UTC") || prefix.eq_ignore_ascii_case("GMT") =>
+        {
+            &s[3..]
+        },
+        _ => s,
+    };
+
+    if s.is_empty() {
+        return None;
+    }
+
+    if rest.is_empty() || rest.eq_ignore_ascii_case("Z") {
+        return FixedOffset::east_opt(0);
+    }
+
+    let (negative, digits) = match rest.as_bytes()[0] {
+        b'+' => (false, &rest[1..]),
+        b'-' => (true, &rest[1..]),
+        _ => return None,
+    };
+
+    // Splitting by bytes below needs ASCII, so anything else is rejected first.
+    if !digits.bytes().all(|b| b.is_ascii_digit() || b == b':') {
+        return None;
+    }
+
+    let (hour, minute) = match digits.split_once(':') {
+        Some((hour, minute)) if minute.len() == 2 => (hour, minute),
+        Some(_) => return None,
+        None if digits.len() > 2 => digits.split_at(digits.len() - 2),
+        None => (digits, "00"),
+    };
+
+    if hour.is_empty() || hour.len() > 2 || minute.contains(':') {
+        return None;
+    }
+
+    let (hour, minute) = (hour.parse::<i32>().ok()?, minute.parse::<i32>().ok()?);
+
+    if hour > 14 || minute > 59 {
+        return None;
+    }
+
+    let seconds = hour * 3600 + minute * 60;
+
+    FixedOffset::east_opt(if negative { -seconds } else { seconds })
+}
+
+/// Resolves a time zone URI by the IANA zone name at the end of its path, e.g. `http://tzurl.org/zoneinfo/Europe/London`.
+fn resolve_uri(uri: &Uri) -> Option<ResolvedTimeZone> {
+    let url = url::Url::parse(uri.as_str()).ok()?;
+
+    let path = url.path();
+    let segments: Vec<&str> =
+        path.split(['/', ':']).filter(|segment| !segment.is_empty()).collect();
+
+    // IANA names have up to three parts, e.g. `America/Argentina/Buenos_Aires`.
+    (1..=segments.len().min(3))
+        .rev()
+        .find_map(|count| iana_zone(&segments[segments.len() - count..].join("/")))
+        .map(ResolvedTimeZone::Named)
+}
+
+impl TzValue {
+    /// Resolves this value to a time zone, see [`ResolvedTimeZone::from_str`] for the accepted texts.
+    ///
+    /// ```rust
+    /// use vcard::{
+    ///     ResolvedTimeZone, chrono_tz::America::New_York, values::TzValue,
+    /// };
+    ///
+    /// let tz = TzValue::Text(String::from("Eastern Standard Time"));
+    ///
+    /// assert_eq!(Some(ResolvedTimeZone::Named(New_York)), tz.resolve());
+    /// ```
+    pub fn resolve(&self) -> Option<ResolvedTimeZone> {
+        match self {
+            Self::Text(text) => text.parse().ok(),
+            Self::Uri(uri) => resolve_uri(uri),
+            Self::UtcOffset(offset) => Some(ResolvedTimeZone::Fixed(offset.to_fixed_offset())),
+        }
+    }
+}
+
+impl TzParam {
+    /// Resolves this parameter to a time zone in the same way as [`TzValue::resolve`].
+    pub fn resolve(&self) -> Option<ResolvedTimeZone> {
+        match self {
+            Self::Text(text) => text.parse().ok(),
+            Self::Uri(uri) => resolve_uri(uri),
+        }
+    }
+}
+
+impl VCard {
+    /// Resolves the time zone of the contact from the most preferred TZ property that can be resolved, falling back to the TZ parameters of the ADR properties.
+    ///
+    /// ```rust
+    /// use vcard::{TimeZone, VCard, values::TzValue};
+    ///
+    /// let mut vcard = VCard::new("Magic Len");
+    ///
+    /// vcard
+    ///     .time_zones
+    ///     .push(TimeZone::new(TzValue::Text(String::from("Asia/Taipei"))));
+    ///
+    /// let local_now = vcard.time_zone().unwrap().now();
+    ///
+    /// assert_eq!(8 * 3600, local_now.offset().local_minus_utc());
+    /// ```
+    pub fn time_zone(&self) -> Option<ResolvedTimeZone> {
+        by_preference(&self.time_zones)
+            .into_iter()
+            .find_map(|time_zone| time_zone.value.resolve())
+            .or_else(|| {
+                by_preference(&self.addresses)
+                    .into_iter()
+                    .find_map(|address| address.parameters.tz.as_ref()?.resolve())
+            })
+    }
+}
@@ -0,0 +1,254 @@
+//! Opt-in change tracking, which maintains the REV and CREATED properties and the RFC 9554 CREATED, AUTHOR and AUTHOR-NAME parameters.
+
+use std::{
+    fs, io,
+    ops::{Deref, DerefMut},
+    path::Path,
+};
+
+use crate::{
+    parameters::Parameters,
+    property::{Created, ExtensionProperty, Property, Rev},
+    values::{Timestamp, Uri},
+    vcard::{VCard, for_each_property},
+};
+
+/// A vCard wrapper that records when it changes.
+///
+/// The vCard is edited through `Deref` and `DerefMut`, and the changes are compared with the last saved state by [`TrackedVCard::save`]. A save with changes sets REV, and the first save of a new vCard also sets CREATED if it is missing.
+///
+/// ```rust
+/// use vcard::{Note, TrackedVCard, VCard};
+///
+/// let mut tracked = TrackedVCard::new(VCard::new("Magic Len"));
+///
+/// assert!(tracked.save());
+/// assert!(tracked.created.is_some());
+/// assert!(!tracked.save());
+///
+/// tracked.notes.push(Note::new(String::from("Hello")));
+///
+/// assert!(tracked.is_modified());
+/// assert!(tracked.save());
+/// assert!(tracked.revision.is_some());
+/// ```
+#[derive(Debug, Clone)]
+pub struct TrackedVCard {
+    vcard:            VCard,
+    saved:            Option<VCard>,
+    stamp_properties: bool,
+    author:           Option<Uri>,
+    author_name:      Option<String>,
+}
+
+impl TrackedVCard {
+    /// Tracks a new vCard, which gets the CREATED property on its first save if it has none.
+    #[inline]
+    pub fn new(vcard: VCard) -> Self {
+        Self {
+            vcard,
+            saved: None,
+            stamp_properties: false,
+            author: None,
+            author_name: None,
+        }
+    }
+
+    /// Tracks a vCard that has already been saved, e.g. one loaded from storage, so its current state is not a change.
+    #[inline]
+    pub fn from_saved(vcard: VCard) -> Self {
+        Self {
+            saved: Some(vcard.clone()),
+            ..Self::new(vcard)
+        }
+    }
+
+    /// Enables stamping the CREATED parameter on the properties that are added or modified, where an existing CREATED parameter is kept.
+    #[inline]
+    pub const fn with_property_stamps(mut self) -> Self {
+        self.stamp_properties = true;
+
+        self
+    }
+
+    /// Sets the AUTHOR parameter that is stamped on the properties that are added or modified, which also enables stamping.
+    #[inline]
+    pub fn with_author(mut self, author: Uri) -> Self {
+        self.author = Some(author);
+        self.stamp_properties = true;
+
+        self
+    }
+
+    /// Sets the AUTHOR-NAME parameter that is stamped on the properties that are added or modified, which also enables stamping.
+    #[inline]
+    pub fn with_author_name<S: Into<String>>(mut self, author_name: S) -> Self {
+        self.author_name = Some(author_name.into());
+        self.stamp_properties = true;
+
+        self
+    }
+
+    /// Checks whether the vCard differs from its last saved state, or has never been saved.
+    #[inline]
+    pub fn is_modified(&self) -> bool {
+        self.saved.as_ref() != Some(&self.vcard)
+    }
+
+    /// Records the changes since the last save with the current time, and returns whether there were any.
+    #[inline]
+    pub fn save(&mut self) -> bool {
+        self.save_at(Timestamp::now())
+    }
+
+    /// Records the changes since the last save with the given time, and returns whether there were any.
+    pub fn save_at(&mut self, now: Timestamp) -> bool {
+        if !self.is_modified() {
+            return false;
+        }
+
+        if self.saved.is_none() && self.vcard.created.is_none() {
+            self.vcard.created = Some(Created::new(now));
+        }
+
+        if self.stamp_properties {
+            self.stamp(now);
+        }
+
+        self.vcard.revision = Some(Rev::new(now));
+        self.saved = Some(self.vcard.clone());
+
+        true
+    }
+
+    /// Saves the changes with [`TrackedVCard::save`] and writes the vCard to a file.
+    #[inline]
+    pub fn save_to_file<P: AsRef<Path>>(&mut self, path: P) -> Result<(), io::Error> {
+        self.save();
+
+        fs::write(path, self.vcard.to_string())
+    }
+
+    /// Returns the last saved state, or `None` if the vCard has never been saved.
+    #[inline]
+    pub fn saved(&self) -> Option<&VCard> {
+        self.saved.as_ref()
+    }
+
+    /// Discards the changes since the last save, which does nothing if the vCard has never been saved.
+    #[inline]
+    pub fn revert(&mut self) {
+        if let Some(saved) = &self.saved {
+            self.vcard = saved.clone();
+        }
+    }
+
+    /// Returns the vCard, dropping the unsaved state of the tracking.
+    #[inline]
+    pub fn into_inner(self) -> VCard {
+        self.vcard
+    }
+
+    /// Stamps the parameters of the properties that have no equal in the last saved state.
+    fn stamp(&mut self, now: Timestamp) {
+        let empty = VCard::default();
+        let saved = self.saved.as_ref().unwrap_or(&empty);
+        let vcard = &mut self.vcard;
+
+        let stamp = |parameters: &mut Parameters| {
+            if parameters.created.is_none() {
+                parameters.created = Some(now);
+            }
+
+            if let Some(author) = &self.author {
+                parameters.author = Some(author.clone());
+            }
+
+            if let Some(author_name) = &self.author_name {
+                parameters.author_name = Some(author_name.clone());
+            }
+        };
+
+        macro_rules! stamp_field {
+            (many, $field:ident) => {
+                stamp_changed(&mut vcard.$field, &saved.$field, property_parameters, &stamp)
+            };
+            (one, $field:ident) => {
+                stamp_changed(
+                    vcard.$field.as_mut_slice(),
+                    saved.$field.as_slice(),
+                    property_parameters,
+                    &stamp,
+                )
+            };
+        }
+
+        macro_rules! stamp_fields {
+            ($(($field:ident, $name:literal, $card:tt)),* $(,)?) => {
+                $(
+                    // REV and CREATED are maintained as whole properties.
+                    if $name != "REV" && $name != "CREATED" {
+                        stamp_field!($card, $field);
+                    }
+                )*
+            };
+        }
+
+        for_each_property!(stamp_fields);
+
+        stamp_changed(&mut vcard.extensions, &saved.extensions, extension_parameters, &stamp);
+    }
+}
+
+#[inline]
+fn property_parameters<V>(property: &mut Property<V>) -> &mut Parameters {
+    &mut property.parameters
+}
+
+#[inline]
+fn extension_parameters(property: &mut ExtensionProperty) -> &mut Parameters {
+    &mut property.parameters
+}
+
+/// Stamps the properties that are not matched by an equal property in the saved list, where each saved property can match once.
+fn stamp_changed<T: PartialEq>(
+    current: &mut [T],
+    saved: &[T],
+    parameters: fn(&mut T) -> &mut Parameters,
+    stamp: &impl Fn(&mut Parameters),
+) {
+    let mut matched = vec![false; saved.len()];
+
+    for property in current {
+        let found =
+            saved.iter().enumerate().position(|(i, saved)| !matched[i] && saved == property);
+
+        match found {
+            Some(i) => matched[i] = true,
+            None => stamp(parameters(property)),
+        }
+    }
+}
+
+impl Deref for TrackedVCard {
+    type Target = VCard;
+
+    #[inline]
+    fn deref(&self) -> &VCard {
+        &self.vcard
+    }
+}
+
+impl DerefMut for TrackedVCard {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut VCard {
+        &mut self.vcard
+    }
+}
+
+impl From<VCard> for TrackedVCard {
+    #[inline]
+    fn from(vcard: VCard) -> Self {
+        Self::new(vcard)
+    }
+}
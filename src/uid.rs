@@ -0,0 +1,199 @@
+//! Generation and normalization of UID values, which are preferably `urn:uuid:` URIs as RFC 6350 recommends.
+
+use std::str::FromStr;
+
+use uuid::Uuid;
+
+use crate::{
+    property::Uid,
+    values::{TelValue, TextOrUri, Uri},
+    vcard::VCard,
+};
+
+impl TextOrUri {
+    /// Creates a `urn:uuid:` URI value from a UUID.
+    #[inline]
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        // A URN built this way is always a valid URI, so parsing never fails.
+        Self::Uri(Uri::from_str(uuid.urn().encode_lower(&mut Uuid::encode_buffer())).unwrap())
+    }
+
+    /// Creates a `urn:uuid:` URI value from a random version 4 UUID.
+    #[inline]
+    pub fn new_uuid_v4() -> Self {
+        Self::from_uuid(Uuid::new_v4())
+    }
+
+    /// Creates a `urn:uuid:` URI value from a version 7 UUID, which is ordered by the current time.
+    #[inline]
+    pub fn new_uuid_v7() -> Self {
+        Self::from_uuid(Uuid::now_v7())
+    }
+
+    /// Creates a `urn:uuid:` URI value from a version 5 UUID, which is the same for the same namespace and name.
+    #[inline]
+    pub fn new_uuid_v5(namespace: &Uuid, name: &[u8]) -> Self {
+        Self::from_uuid(Uuid::new_v5(namespace, name))
+    }
+
+    /// Returns the UUID of a `urn:uuid:` URI or of a text value that is a UUID in any common format, e.g. hyphenated, simple or in braces.
+    pub fn uuid(&self) -> Option<Uuid> {
+        let s = match self {
+            Self::Uri(uri) => uri.as_str(),
+            Self::Text(text) => text.trim(),
+        };
+
+        // The namespace identifier of a URN is case-insensitive.
+        let s = match s.get(..9) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("urn:uuid:") => &s[9..],
+            _ if matches!(self, Self::Uri(_)) => return None,
+            _ => s,
+        };
+
+        Uuid::parse_str(s).ok()
+    }
+
+    /// Returns the normalized form of this value as a UID.
+    ///
+    /// UUIDs in either form become lowercase `urn:uuid:` URIs, text that is a URI becomes a URI value, and other text is trimmed.
+    ///
+    /// ```rust
+    /// use vcard::values::TextOrUri;
+    ///
+    /// let uid =
+    ///     TextOrUri::Text(String::from("{F81D4FAE-7DEC-11D0-A765-00A0C91E6BF6}"));
+    ///
+    /// assert_eq!(
+    ///     TextOrUri::Uri(
+    ///         "urn:uuid:f81d4fae-7dec-11d0-a765-00a0c91e6bf6".parse().unwrap()
+    ///     ),
+    ///     uid.normalized_uid()
+    /// );
+    /// ```
+    pub fn normalized_uid(&self) -> Self {
+        if let Some(uuid) = self.uuid() {
+            return Self::from_uuid(uuid);
+        }
+
+        match self {
+            Self::Uri(uri) => Self::Uri(uri.clone()),
+            Self::Text(text) => {
+                let text = text.trim();
+
+                // Only text with a scheme and without whitespace is taken as a URI, so that plain words stay text.
+                match text.split_once(':') {
+                    Some((scheme, rest))
+                        if !scheme.is_empty()
+                            && !rest.is_empty()
+                            && !text.contains(char::is_whitespace) =>
+                    {
+                        match Uri::from_str(text) {
+                            Ok(uri) => Self::Uri(uri),
+                            Err(_) => Self::Text(text.to_string()),
+                        }
+                    },
+                    _ => Self::Text(text.to_string()),
+                }
+            },
+        }
+    }
+}
+
+impl VCard {
+    /// Sets a UID with a random version 4 UUID if there is none, and returns the UID.
+    pub fn ensure_uid(&mut self) -> &Uid {
+        self.uid.get_or_insert_with(|| Uid::new(TextOrUri::new_uuid_v4()))
+    }
+
+    /// Normalizes the UID with [`TextOrUri::normalized_uid`] if it exists.
+    pub fn normalize_uid(&mut self) {
+        if let Some(uid) = &mut self.uid {
+            uid.value = uid.value.normalized_uid();
+        }
+    }
+
+    /// Derives a UID with a version 5 UUID from the names, email addresses and telephone numbers, so that importing the same contact again gives the same UID.
+    ///
+    /// The namespace should be fixed for each source of imports, and an existing UID is ignored.
+    ///
+    /// ```rust
+    /// use vcard::{Email, VCard, uuid::Uuid};
+    ///
+    /// let mut a = VCard::new("Magic Len");
+    ///
+    /// a.emails.push(Email::new("Len@Example.com".parse().unwrap()));
+    ///
+    /// let mut b = VCard::new(" Magic Len ");
+    ///
+    /// b.emails.push(Email::new("Len@example.COM".parse().unwrap()));
+    ///
+    /// assert_eq!(
+    ///     a.derive_uid(&Uuid::NAMESPACE_URL),
+    ///     b.derive_uid(&Uuid::NAMESPACE_URL)
+    /// );
+    /// ```
+    pub fn derive_uid(&self, namespace: &Uuid) -> Uid {
+        let mut fields: Vec<String> = Vec::new();
+
+        for formatted_name in &self.formatted_names {
+            fields.push(format!("FN:{}", formatted_name.value.trim()));
+        }
+
+        for name in &self.names {
+            let components = [
+                &name.value.family_names,
+                &name.value.given_names,
+                &name.value.additional_names,
+                &name.value.honorific_prefixes,
+                &name.value.honorific_suffixes,
+            ];
+
+            fields.push(format!(
+                "N:{}",
+                components
+                    .iter()
+                    .map(|component| component
+                        .iter()
+                        .map(|s| s.trim())
+                        .collect::<Vec<_>>()
+                        .join(","))
+                    .collect::<Vec<_>>()
+                    .join(";")
+            ));
+        }
+
+        let mut emails: Vec<String> = self
+            .emails
+            .iter()
+            .map(|email| format!("EMAIL:{}", email.value.canonicalized().as_str().to_lowercase()))
+            .collect();
+
+        // Only the digits and the plus sign are kept, so that the formatting of the numbers does not matter.
+        let mut telephones: Vec<String> = self
+            .telephones
+            .iter()
+            .map(|telephone| {
+                let s = match &telephone.value {
+                    TelValue::Uri(uri) => uri.as_str(),
+                    TelValue::Text(text) => text.as_str(),
+                };
+
+                format!(
+                    "TEL:{}",
+                    s.chars().filter(|c| c.is_ascii_digit() || *c == '+').collect::<String>()
+                )
+            })
+            .collect();
+
+        // The order of email addresses and telephone numbers is not meaningful.
+        emails.sort_unstable();
+        emails.dedup();
+        telephones.sort_unstable();
+        telephones.dedup();
+
+        fields.extend(emails);
+        fields.extend(telephones);
+
+        Uid::new(TextOrUri::new_uuid_v5(namespace, fields.join("\n").as_bytes()))
+    }
+}
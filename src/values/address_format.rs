@@ -0,0 +1,328 @@
+//! Postal label formatting of structured addresses, driven by per-country templates.
+//!
+//! The templates follow the format strings of Google's libaddressinput, where `%A` is the street address lines, `%D` the dependent locality, `%C` the city, `%S` the administrative area, `%Z` the postal code and `%n` a line break.
+//! The `%N` (name), `%O` (organization), `%X` (sorting code) and other unknown fields are left out because an ADR value does not carry them.
+
+use super::AddressValue;
+use crate::property::Address;
+
+/// The label format of a country.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AddressFormat {
+    code:                &'static str,
+    name:                &'static str,
+    aliases:             &'static [&'static str],
+    template:            &'static str,
+    upper:               &'static str,
+    street_number_first: bool,
+}
+
+/// The format used when the country is unknown.
+const DEFAULT_FORMAT: AddressFormat = AddressFormat {
+    code:                "ZZ",
+    name:                "",
+    aliases:             &[],
+    template:            "%N%n%O%n%A%n%C %S %Z",
+    upper:               "",
+    street_number_first: true,
+};
+
+macro_rules! country {
+    ($code:literal, $name:literal, [$($alias:literal),*], $template:literal, $upper:literal, $number_first:literal) => {
+        AddressFormat {
+            code:                $code,
+            name:                $name,
+            aliases:             &[$($alias),*],
+            template:            $template,
+            upper:               $upper,
+            street_number_first: $number_first,
+        }
+    };
+}
+
+/// The bundled country formats, sorted by the ISO 3166-1 alpha-2 code.
+static COUNTRIES: &[AddressFormat] = &[
+    country!("AR", "ARGENTINA", [], "%N%n%O%n%A%n%Z %C%n%S", "ACZ", false),
+    country!("AT", "AUSTRIA", ["ÖSTERREICH"], "%O%n%N%n%A%n%Z %C", "", false),
+    country!("AU", "AUSTRALIA", [], "%O%n%N%n%A%n%C %S %Z", "CS", true),
+    country!("BE", "BELGIUM", ["BELGIË", "BELGIQUE"], "%O%n%N%n%A%n%Z %C", "", false),
+    country!("BR", "BRAZIL", ["BRASIL"], "%O%n%N%n%A%n%D%n%C-%S%n%Z", "CS", false),
+    country!("CA", "CANADA", [], "%N%n%O%n%A%n%C %S %Z", "ACNOSZ", true),
+    country!(
+        "CH",
+        "SWITZERLAND",
+        ["SCHWEIZ", "SUISSE", "SVIZZERA"],
+        "%O%n%N%n%A%nCH-%Z %C",
+        "",
+        false
+    ),
+    country!("CN", "CHINA", ["中国", "中國"], "%Z%n%S%C%D%n%A%n%O%n%N", "", false),
+    country!("DE", "GERMANY", ["DEUTSCHLAND"], "%N%n%O%n%A%n%Z %C", "", false),
+    country!("DK", "DENMARK", ["DANMARK"], "%N%n%O%n%A%n%Z %C", "", false),
+    country!("ES", "SPAIN", ["ESPAÑA"], "%N%n%O%n%A%n%Z %C %S", "CS", false),
+    country!("FI", "FINLAND", ["SUOMI"], "%O%n%N%n%A%nFI-%Z %C", "", false),
+    country!("FR", "FRANCE", [], "%O%n%N%n%A%n%Z %C", "CX", true),
+    country!(
+        "GB",
+        "UNITED KINGDOM",
+        ["UK", "GREAT BRITAIN", "ENGLAND", "SCOTLAND", "WALES"],
+        "%N%n%O%n%A%n%C%n%Z",
+        "CZ",
+        true
+    ),
+    country!("HK", "HONG KONG", ["香港"], "%S%n%C%n%A%n%O%n%N", "S", false),
+    country!("HU", "HUNGARY", ["MAGYARORSZÁG"], "%N%n%O%n%C%n%A%n%Z", "AC", false),
+    country!("IE", "IRELAND", ["ÉIRE"], "%N%n%O%n%A%n%D%n%C%n%S%n%Z", "", true),
+    country!("IN", "INDIA", [], "%N%n%O%n%A%n%D%n%C %Z%n%S", "", true),
+    country!("IT", "ITALY", ["ITALIA"], "%N%n%O%n%A%n%Z %C %S", "CS", false),
+    country!("JP", "JAPAN", ["日本"], "〒%Z%n%S%C%n%A%n%O%n%N", "", false),
+    country!(
+        "KR",
+        "SOUTH KOREA",
+        ["KOREA", "REPUBLIC OF KOREA", "대한민국"],
+        "%S %C%D%n%A%n%O%n%N%n%Z",
+        "",
+        false
+    ),
+    country!("MX", "MEXICO", ["MÉXICO"], "%N%n%O%n%A%n%D%n%Z %C, %S", "CSZ", false),
+    country!("NL", "NETHERLANDS", ["NEDERLAND", "THE NETHERLANDS"], "%O%n%N%n%A%n%Z %C", "", false),
+    country!("NO", "NORWAY", ["NORGE"], "%N%n%O%n%A%n%Z %C", "", false),
+    country!("NZ", "NEW ZEALAND", [], "%N%n%O%n%A%n%D%n%C %Z", "", true),
+    country!("PL", "POLAND", ["POLSKA"], "%N%n%O%n%A%n%Z %C", "", false),
+    country!("PT", "PORTUGAL", [], "%N%n%O%n%A%n%Z %C", "", false),
+    country!(
+        "RU",
+        "RUSSIA",
+        ["RUSSIAN FEDERATION", "РОССИЯ"],
+        "%N%n%O%n%A%n%C%n%S%n%Z",
+        "AC",
+        false
+    ),
+    country!("SE", "SWEDEN", ["SVERIGE"], "%O%n%N%n%A%nSE-%Z %C", "", false),
+    country!("SG", "SINGAPORE", [], "%N%n%O%n%A%nSINGAPORE %Z", "", true),
+    country!("TW", "TAIWAN", ["台灣", "臺灣"], "%Z%n%S%C%n%A%n%O%n%N", "", false),
+    country!(
+        "US",
+        "UNITED STATES",
+        ["USA", "U.S.A.", "UNITED STATES OF AMERICA", "AMERICA"],
+        "%N%n%O%n%A%n%C, %S %Z",
+        "CS",
+        true
+    ),
+    country!("ZA", "SOUTH AFRICA", [], "%N%n%O%n%A%n%D%n%C%n%Z", "", true),
+];
+
+impl AddressFormat {
+    /// Finds the format of a country by its ISO 3166-1 alpha-2 code or its name, both compared case-insensitively.
+    pub fn for_country(country: &str) -> Option<&'static AddressFormat> {
+        let country = country.trim();
+
+        COUNTRIES.iter().find(|format| {
+            format.code.eq_ignore_ascii_case(country)
+                || format.name.eq_ignore_ascii_case(country)
+                || format.aliases.iter().any(|alias| alias.to_uppercase() == country.to_uppercase())
+        })
+    }
+
+    /// Returns the generic format used for unknown countries.
+    #[inline]
+    pub fn generic() -> &'static AddressFormat {
+        &DEFAULT_FORMAT
+    }
+
+    /// Returns the ISO 3166-1 alpha-2 code, which is `ZZ` for the generic format.
+    #[inline]
+    pub const fn code(&self) -> &'static str {
+        self.code
+    }
+
+    /// Returns the English country name in uppercase, as it is written on international mail.
+    #[inline]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the libaddressinput-style template.
+    #[inline]
+    pub const fn template(&self) -> &'static str {
+        self.template
+    }
+
+    /// Builds the street address lines, preferring the RFC 6350 components and falling back to the RFC 9554 ones.
+    fn street_lines(&self, address: &AddressValue) -> Vec<String> {
+        let mut lines: Vec<String> = address
+            .post_office_boxes
+            .iter()
+            .chain(&address.extended_addresses)
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect();
+
+        if address.street_addresses.iter().any(|s| !s.trim().is_empty()) {
+            lines.extend(
+                address
+                    .street_addresses
+                    .iter()
+                    .flat_map(|s| s.lines())
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty()),
+            );
+
+            return lines;
+        }
+
+        let (first, second) = if self.street_number_first {
+            (&address.street_numbers, &address.street_names)
+        } else {
+            (&address.street_names, &address.street_numbers)
+        };
+
+        let street = join_words([first, second, &address.directions]);
+
+        let details = [
+            &address.buildings,
+            &address.blocks,
+            &address.floors,
+            &address.apartments,
+            &address.rooms,
+            &address.landmarks,
+        ]
+        .into_iter()
+        .flatten()
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+        lines.extend([street, details].into_iter().filter(|s| !s.is_empty()));
+
+        lines
+    }
+
+    /// Formats an address as a postal label whose lines are separated by `\n`.
+    ///
+    /// When `country` is given, it is written as the last line, which is what international mail needs.
+    pub fn format(&self, address: &AddressValue, country: Option<&str>) -> String {
+        let street_lines = self.street_lines(address);
+
+        let mut lines = Vec::new();
+
+        for line_template in self.template.split("%n") {
+            let mut line = String::new();
+            let mut literal = String::new();
+            let mut leading = true;
+
+            let mut chars = line_template.chars();
+
+            while let Some(c) = chars.next() {
+                if c != '%' {
+                    literal.push(c);
+                    continue;
+                }
+
+                let Some(field) = chars.next() else {
+                    break;
+                };
+
+                let mut value = match field {
+                    'A' => street_lines.join("\n"),
+                    'D' => join_words([&address.subdistricts, &address.districts]),
+                    'C' => join_words([&address.localities]),
+                    'S' => join_words([&address.regions]),
+                    'Z' => join_words([&address.postal_codes]),
+                    _ => String::new(),
+                };
+
+                if self.upper.contains(field) {
+                    value = value.to_uppercase();
+                }
+
+                // A separator is only kept between two present fields, and a prefix like 〒 only with its field.
+                if !value.is_empty() {
+                    if !line.is_empty() || leading {
+                        line.push_str(&literal);
+                    }
+
+                    line.push_str(&value);
+                }
+
+                literal.clear();
+                leading = false;
+            }
+
+            if !line.is_empty() {
+                line.push_str(&literal);
+            } else if leading && !literal.trim().is_empty() {
+                // A line without any field is constant text.
+                line = literal;
+            }
+
+            if !line.trim().is_empty() {
+                lines.push(line);
+            }
+        }
+
+        if let Some(country) = country.map(str::trim).filter(|s| !s.is_empty()) {
+            lines.push(country.to_uppercase());
+        }
+
+        lines.join("\n")
+    }
+}
+
+/// Joins the non-empty items of several components with spaces.
+fn join_words<'a>(components: impl IntoIterator<Item = &'a Vec<String>>) -> String {
+    components
+        .into_iter()
+        .flatten()
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+impl AddressValue {
+    /// Formats this address as a postal label, picking the format from the first country and writing the country as the last line.
+    ///
+    /// ```rust
+    /// use vcard::values::AddressValue;
+    ///
+    /// let address = AddressValue {
+    ///     street_numbers: vec![String::from("1600")],
+    ///     street_names: vec![String::from("Amphitheatre Parkway")],
+    ///     localities: vec![String::from("Mountain View")],
+    ///     regions: vec![String::from("CA")],
+    ///     postal_codes: vec![String::from("94043")],
+    ///     countries: vec![String::from("US")],
+    ///     ..AddressValue::default()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     "1600 Amphitheatre Parkway\nMOUNTAIN VIEW, CA 94043\nUNITED STATES",
+    ///     address.format_label()
+    /// );
+    /// ```
+    pub fn format_label(&self) -> String {
+        let country = self.countries.iter().map(|s| s.trim()).find(|s| !s.is_empty());
+
+        match country {
+            Some(country) => match AddressFormat::for_country(country) {
+                // A known country code is written as the full name.
+                Some(format) if format.code.eq_ignore_ascii_case(country) => {
+                    format.format(self, Some(format.name))
+                },
+                Some(format) => format.format(self, Some(country)),
+                None => AddressFormat::generic().format(self, Some(country)),
+            },
+            None => AddressFormat::generic().format(self, None),
+        }
+    }
+}
+
+impl Address {
+    /// Sets the LABEL parameter to the postal label formatted from the structured components.
+    #[inline]
+    pub fn generate_label(&mut self) {
+        self.parameters.label = Some(self.value.format_label());
+    }
+}
@@ -0,0 +1,485 @@
+//! Best-effort parsing of free-form address text into structured components.
+
+use super::{AddressFormat, AddressValue};
+
+/// The result of [`AddressValue::parse_free_text`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedAddress {
+    /// The structured address.
+    pub address:    AddressValue,
+    /// How much of the text was recognized, from `0.0` (nothing) to `1.0` (every part was matched by a country rule).
+    pub confidence: f32,
+}
+
+/// How the postal code and the locality are written in a country.
+struct CountryRules {
+    code:          &'static str,
+    /// Postal code patterns where `9` is a digit, `A` is an ASCII letter and anything else is literal.
+    postal_codes:  &'static [&'static str],
+    /// Whether the postal code comes before the locality, e.g. `10117 Berlin`.
+    postal_first:  bool,
+    /// Whether an abbreviated region can follow the locality or the postal code, e.g. `CA 95014` or `Roma RM`.
+    region_abbrev: bool,
+}
+
+macro_rules! rules {
+    ($code:literal, [$($pattern:literal),*], $postal_first:literal, $region_abbrev:literal) => {
+        CountryRules {
+            code:          $code,
+            postal_codes:  &[$($pattern),*],
+            postal_first:  $postal_first,
+            region_abbrev: $region_abbrev,
+        }
+    };
+}
+
+/// The per-country rules, where countries whose postal codes are easy to tell apart come first so that guessing prefers them.
+static RULES: &[CountryRules] = &[
+    rules!("CA", ["A9A 9A9", "A9A9A9"], false, true),
+    rules!("GB", [], false, false),
+    rules!("US", ["99999-9999", "99999"], false, true),
+    rules!("NL", ["9999 AA", "9999AA"], true, false),
+    rules!("PL", ["99-999"], true, false),
+    rules!("PT", ["9999-999"], true, false),
+    rules!("BR", ["99999-999"], true, false),
+    rules!("JP", ["〒999-9999", "999-9999"], true, false),
+    rules!("SE", ["999 99"], true, false),
+    rules!("DE", ["99999"], true, false),
+    rules!("FR", ["99999"], true, false),
+    rules!("ES", ["99999"], true, true),
+    rules!("IT", ["99999"], true, true),
+    rules!("MX", ["99999"], true, false),
+    rules!("FI", ["99999"], true, false),
+    rules!("AT", ["9999"], true, false),
+    rules!("CH", ["9999"], true, false),
+    rules!("BE", ["9999"], true, false),
+    rules!("DK", ["9999"], true, false),
+    rules!("NO", ["9999"], true, false),
+    rules!("AU", ["9999"], false, true),
+    rules!("NZ", ["9999"], false, false),
+    rules!("TW", ["999-99", "99999", "999"], true, false),
+];
+
+/// The abbreviated regions of the countries whose addresses write them, e.g. `CA 95014` or `Toronto, ON`.
+static REGIONS: &[(&str, &[&str])] = &[
+    ("US", &[
+        "AL", "AK", "AZ", "AR", "CA", "CO", "CT", "DE", "FL", "GA", "HI", "ID", "IL", "IN", "IA",
+        "KS", "KY", "LA", "ME", "MD", "MA", "MI", "MN", "MS", "MO", "MT", "NE", "NV", "NH", "NJ",
+        "NM", "NY", "NC", "ND", "OH", "OK", "OR", "PA", "RI", "SC", "SD", "TN", "TX", "UT", "VT",
+        "VA", "WA", "WV", "WI", "WY", "DC", "AS", "GU", "MP", "PR", "VI",
+    ]),
+    ("CA", &["AB", "BC", "MB", "NB", "NL", "NS", "NT", "NU", "ON", "PE", "QC", "SK", "YT"]),
+    ("AU", &["ACT", "NSW", "NT", "QLD", "SA", "TAS", "VIC", "WA"]),
+];
+
+/// Checks whether a word is an abbreviated region of a country.
+fn is_region_of(code: &str, region: &str) -> bool {
+    REGIONS
+        .iter()
+        .any(|(country, regions)| country.eq_ignore_ascii_case(code) && regions.contains(&region))
+}
+
+/// Checks whether the addresses of a country write abbreviated regions.
+fn uses_region_abbreviations(code: &str) -> bool {
+    RULES.iter().any(|rules| rules.code == code && rules.region_abbrev)
+}
+
+/// The words that start the unit part of an address, mapped to the component they belong to.
+const UNIT_WORDS: &[(&str, Unit)] = &[
+    ("apt", Unit::Apartment),
+    ("apartment", Unit::Apartment),
+    ("unit", Unit::Apartment),
+    ("flat", Unit::Apartment),
+    ("#", Unit::Apartment),
+    ("wohnung", Unit::Apartment),
+    ("whg", Unit::Apartment),
+    ("suite", Unit::Room),
+    ("ste", Unit::Room),
+    ("room", Unit::Room),
+    ("rm", Unit::Room),
+    ("floor", Unit::Floor),
+    ("fl", Unit::Floor),
+    ("étage", Unit::Floor),
+    ("piso", Unit::Floor),
+    ("stock", Unit::Floor),
+    ("og", Unit::Floor),
+];
+
+#[derive(Clone, Copy)]
+enum Unit {
+    Apartment,
+    Room,
+    Floor,
+}
+
+fn matches_pattern(token: &str, pattern: &str) -> bool {
+    token.chars().count() == pattern.chars().count()
+        && token.chars().zip(pattern.chars()).all(|(c, p)| match p {
+            '9' => c.is_ascii_digit(),
+            'A' => c.is_ascii_alphabetic(),
+            _ => c == p,
+        })
+}
+
+/// Checks the UK postcode format, e.g. `SW1A 2AA` or `M1 1AE`.
+fn is_uk_postcode(token: &str) -> bool {
+    let Some((outward, inward)) = token.split_once(' ') else {
+        return false;
+    };
+
+    ["A9", "A99", "AA9", "AA99", "A9A", "AA9A"]
+        .iter()
+        .any(|pattern| matches_pattern(outward, pattern))
+        && matches_pattern(inward, "9AA")
+}
+
+impl CountryRules {
+    /// Finds a postal code in the words of a segment, returning the range of words it covers.
+    ///
+    /// With `distinctive_only`, patterns made only of digits are skipped because they could be street numbers.
+    fn find_postal_code(&self, words: &[&str], distinctive_only: bool) -> Option<(usize, usize)> {
+        if distinctive_only
+            && self.code != "GB"
+            && self.postal_codes.iter().all(|pattern| pattern.chars().all(|c| c == '9'))
+        {
+            return None;
+        }
+
+        let is_postal_code = |token: &str| {
+            if self.code == "GB" {
+                is_uk_postcode(token)
+            } else {
+                self.postal_codes.iter().any(|pattern| {
+                    (!distinctive_only || pattern.chars().any(|c| c != '9'))
+                        && matches_pattern(token, pattern)
+                })
+            }
+        };
+
+        // Patterns with a space cover two words, so pairs are tried first.
+        for i in (0..words.len()).rev() {
+            if i + 1 < words.len() && is_postal_code(&format!("{} {}", words[i], words[i + 1])) {
+                return Some((i, i + 2));
+            }
+
+            if is_postal_code(words[i]) {
+                return Some((i, i + 1));
+            }
+        }
+
+        None
+    }
+}
+
+/// Checks whether a word is an abbreviated region, e.g. `CA`, `NSW` or `RM`.
+fn is_region_abbreviation(word: &str) -> bool {
+    (2..=3).contains(&word.len()) && word.chars().all(|c| c.is_ascii_uppercase())
+}
+
+/// Finds the unit keyword that starts the unit part of a segment.
+fn unit_start(words: &[&str]) -> Option<(usize, Unit)> {
+    words.iter().enumerate().find_map(|(i, word)| {
+        let word = word.trim_end_matches('.').to_lowercase();
+
+        // `#12` has the keyword glued to the number.
+        let word = if word.starts_with('#') { "#" } else { word.as_str() };
+
+        UNIT_WORDS.iter().find(|(keyword, _)| *keyword == word).map(|(_, unit)| (i, *unit))
+    })
+}
+
+fn push_unit(address: &mut AddressValue, unit: Unit, text: String) {
+    match unit {
+        Unit::Apartment => address.apartments.push(text),
+        Unit::Room => address.rooms.push(text),
+        Unit::Floor => address.floors.push(text),
+    }
+}
+
+/// Splits a street line into the street number and the street name, accepting the number on either side.
+fn split_street(words: &[&str]) -> Option<(String, String)> {
+    let starts_with_digit = |word: &&str| word.chars().next().is_some_and(|c| c.is_ascii_digit());
+
+    match words {
+        [first, rest @ ..] if !rest.is_empty() && starts_with_digit(first) => {
+            Some((first.trim_end_matches(',').to_string(), rest.join(" ")))
+        },
+        [rest @ .., last] if !rest.is_empty() && starts_with_digit(last) => {
+            Some((last.to_string(), rest.join(" ").trim_end_matches(',').to_string()))
+        },
+        _ => None,
+    }
+}
+
+impl AddressValue {
+    /// Splits a free-form address into components, guessing the country from the text when it is not written.
+    ///
+    /// The street line is kept in `street_addresses` for compatibility and also split into `street_numbers` and `street_names`.
+    ///
+    /// ```rust
+    /// use vcard::values::AddressValue;
+    ///
+    /// let parsed = AddressValue::parse_free_text(
+    ///     "1 Infinite Loop, Cupertino, CA 95014, USA",
+    /// );
+    ///
+    /// assert_eq!(vec!["1"], parsed.address.street_numbers);
+    /// assert_eq!(vec!["Infinite Loop"], parsed.address.street_names);
+    /// assert_eq!(vec!["Cupertino"], parsed.address.localities);
+    /// assert_eq!(vec!["CA"], parsed.address.regions);
+    /// assert_eq!(vec!["95014"], parsed.address.postal_codes);
+    /// assert_eq!(vec!["USA"], parsed.address.countries);
+    /// assert!(parsed.confidence > 0.9);
+    /// ```
+    #[inline]
+    pub fn parse_free_text(text: &str) -> ParsedAddress {
+        parse(text, None)
+    }
+
+    /// Splits a free-form address into components, using the rules of the given country when the text does not name one.
+    ///
+    /// The country can be an ISO 3166-1 alpha-2 code or a name.
+    #[inline]
+    pub fn parse_free_text_in(text: &str, country: &str) -> ParsedAddress {
+        parse(text, AddressFormat::for_country(country).map(AddressFormat::code))
+    }
+}
+
+/// Returns the rules of a country, or of every country when it is unknown.
+fn candidate_rules(code: Option<&str>) -> Vec<&'static CountryRules> {
+    match code {
+        Some(code) => RULES.iter().filter(|rules| rules.code == code).collect(),
+        None => RULES.iter().collect(),
+    }
+}
+
+/// Finds the postal code of the segments and the rules it matches, looking from the end because it is usually near the end.
+fn find_postal<'r>(
+    words: &[Vec<&str>],
+    candidates: &[&'r CountryRules],
+) -> Option<(usize, (usize, usize), &'r CountryRules)> {
+    for (index, segment_words) in words.iter().enumerate().rev() {
+        // The first of several segments is usually the street, whose number looks like a postal code.
+        let distinctive_only = index == 0 && words.len() > 1;
+
+        let mut matches = candidates.iter().filter_map(|rules| {
+            rules
+                .find_postal_code(segment_words, distinctive_only)
+                .map(|range| (index, range, *rules))
+        });
+
+        let Some(first) = matches.next() else {
+            continue;
+        };
+
+        // Countries sharing a pattern are told apart by whether the postal code comes before or after the locality.
+        let fits = |(_, (start, end), rules): &(usize, (usize, usize), &CountryRules)| {
+            if rules.postal_first { *start == 0 } else { *end == segment_words.len() }
+        };
+
+        return Some(if fits(&first) { first } else { matches.find(fits).unwrap_or(first) });
+    }
+
+    None
+}
+
+/// Checks whether an abbreviated region can follow the locality or be the last segment, which needs the segments before it to fit a country with that region.
+///
+/// A segment after one of its own regions, like `CA` after `ON` in `Toronto, ON, CA`, is the country instead.
+fn can_be_region(last: &str, before: &[&str], code: Option<&str>) -> bool {
+    if before.is_empty() || !is_region_abbreviation(last) {
+        return false;
+    }
+
+    if before.last().is_some_and(|previous| is_region_of(last, previous)) {
+        return false;
+    }
+
+    let words: Vec<Vec<&str>> =
+        before.iter().map(|segment| segment.split_whitespace().collect()).collect();
+
+    let candidates = candidate_rules(code);
+
+    match find_postal(&words, &candidates) {
+        Some((.., rules)) => {
+            !rules.code.eq_ignore_ascii_case(last) && is_region_of(rules.code, last)
+        },
+        None => candidates.iter().any(|rules| is_region_of(rules.code, last)),
+    }
+}
+
+fn parse(text: &str, default_country: Option<&str>) -> ParsedAddress {
+    // Lines and commas both separate the parts of an address.
+    let mut segments: Vec<&str> = text
+        .lines()
+        .flat_map(|line| line.split(','))
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .collect();
+
+    let mut address = AddressValue::default();
+    let mut score = 0.0;
+
+    if segments.is_empty() {
+        return ParsedAddress {
+            address,
+            confidence: 0.0,
+        };
+    }
+
+    let mut code = default_country;
+
+    if let Some(&last) = segments.last()
+        && let Some(format) = AddressFormat::for_country(last)
+        // `CA` in `Cupertino, CA` is California rather than Canada, because a Canadian address would have its province.
+        && !(uses_region_abbreviations(format.code())
+            && can_be_region(last, &segments[..segments.len() - 1], code))
+    {
+        address.countries.push(last.to_string());
+        code = Some(format.code());
+        score += 0.15;
+
+        segments.pop();
+    }
+
+    let mut region_segment = false;
+
+    if let Some(&last) = segments.last()
+        && can_be_region(last, &segments[..segments.len() - 1], code)
+    {
+        address.regions.push(last.to_string());
+        region_segment = true;
+
+        segments.pop();
+    }
+
+    let words: Vec<Vec<&str>> =
+        segments.iter().map(|segment| segment.split_whitespace().collect()).collect();
+
+    let candidates = candidate_rules(code);
+
+    let postal = find_postal(&words, &candidates);
+
+    let mut consumed = vec![false; segments.len()];
+
+    if region_segment {
+        score += 0.05;
+
+        // Without a postal code, the locality is the segment right before the region.
+        if postal.is_none() && !segments.is_empty() {
+            let index = segments.len() - 1;
+
+            address.localities.push(segments[index].to_string());
+            consumed[index] = true;
+            score += 0.25;
+        }
+    } else if postal.is_none()
+        && !address.countries.is_empty()
+        && words.len() == 1
+        && split_street(&words[0]).is_none()
+    {
+        // A lone segment without a street number before the country is the locality, e.g. `Berlin, DE`.
+        address.localities.push(segments[0].to_string());
+        consumed[0] = true;
+        score += 0.25;
+    }
+
+    if let Some((index, (start, end), rules)) = postal {
+        let segment_words = &words[index];
+
+        consumed[index] = true;
+
+        address
+            .postal_codes
+            .push(segment_words[start..end].join(" ").trim_start_matches('〒').to_string());
+
+        // A guessed country is less certain than a written or a given one.
+        score += if code.is_some() { 0.3 } else { 0.2 };
+
+        let mut before = segment_words[..start].to_vec();
+        let mut after = segment_words[end..].to_vec();
+
+        if rules.region_abbrev {
+            if rules.postal_first {
+                if after.len() > 1 && after.last().is_some_and(|word| is_region_abbreviation(word))
+                {
+                    address.regions.push(after.pop().unwrap_or_default().to_string());
+                }
+            } else if before.last().is_some_and(|word| is_region_abbreviation(word)) {
+                address.regions.push(before.pop().unwrap_or_default().to_string());
+            }
+        }
+
+        let locality = if rules.postal_first { after } else { before };
+
+        if !locality.is_empty() {
+            address.localities.push(locality.join(" "));
+        } else if !rules.postal_first && index > 0 {
+            // The locality is on its own line or between its own commas, e.g. `Cupertino, CA 95014`.
+            address.localities.push(segments[index - 1].to_string());
+            consumed[index - 1] = true;
+        }
+
+        if !address.localities.is_empty() {
+            score += 0.25;
+        }
+
+        if !address.regions.is_empty() {
+            score += 0.05;
+        }
+    }
+
+    let remaining: Vec<&Vec<&str>> =
+        words.iter().zip(&consumed).filter(|(_, consumed)| !**consumed).map(|(w, _)| w).collect();
+
+    let mut unknown = 0;
+    let mut street_found = false;
+
+    for segment_words in &remaining {
+        let (street_words, unit) = match unit_start(segment_words) {
+            Some((0, unit)) => {
+                push_unit(&mut address, unit, segment_words.join(" "));
+                continue;
+            },
+            Some((i, unit)) => (&segment_words[..i], Some((i, unit))),
+            None => (&segment_words[..], None),
+        };
+
+        if let Some((i, unit)) = unit {
+            push_unit(&mut address, unit, segment_words[i..].join(" "));
+        }
+
+        match split_street(street_words) {
+            Some((number, name)) if !street_found => {
+                address.street_addresses.push(street_words.join(" "));
+                address.street_numbers.push(number);
+                address.street_names.push(name);
+
+                street_found = true;
+            },
+            _ if !street_found && remaining.len() == 1 => {
+                // A lone street line without a separate number is still a street.
+                address.street_addresses.push(street_words.join(" "));
+                address.street_names.push(street_words.join(" "));
+
+                street_found = true;
+            },
+            _ => {
+                address.extended_addresses.push(street_words.join(" "));
+                unknown += 1;
+            },
+        }
+    }
+
+    if street_found {
+        score += 0.25;
+    }
+
+    // Whatever could not be placed makes the result less certain.
+    let confidence = (score - 0.1 * unknown as f32).clamp(0.0, 1.0);
+
+    ParsedAddress {
+        address,
+        confidence,
+    }
+}
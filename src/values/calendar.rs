@@ -0,0 +1,897 @@
+//! Conversions between the Gregorian calendar and the other calendar systems that the CALSCALE parameter can name.
+//!
+//! The Hebrew, tabular Islamic and Persian calendars are arithmetic. The Chinese calendar is computed from new moons and solar terms in China time, following *Calendrical Calculations* by Reingold and Dershowitz with the lunar and solar formulas of Meeus.
+
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use chrono::{Datelike, NaiveDate};
+
+use super::{Date, DateAndOrTime, DateTime, Token};
+use crate::{error::InvalidValueError, parameters::Calscale};
+
+/// A calendar system, named by the CLDR identifiers that RFC 7529 uses for RSCALE.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CalendarSystem {
+    /// The Gregorian calendar, which is the default.
+    #[default]
+    Gregorian,
+    /// The Chinese lunisolar calendar, whose years are numbered by the Gregorian year in which they start.
+    Chinese,
+    /// The Hebrew calendar, whose leap month Adar I is month `5L` and whose Adar (Adar II in leap years) is month 6.
+    Hebrew,
+    /// The tabular Islamic calendar with the civil epoch of July 16, 622.
+    IslamicCivil,
+    /// The Persian solar calendar.
+    Persian,
+}
+
+impl CalendarSystem {
+    /// Returns the calendar system of a CALSCALE parameter, where no parameter means Gregorian, or `None` for an unknown system.
+    pub fn from_calscale(calscale: Option<&Calscale>) -> Option<Self> {
+        match calscale {
+            None | Some(Calscale::Gregorian) => Some(Self::Gregorian),
+            Some(Calscale::Extension(token)) => Self::from_str(token.as_str()).ok(),
+        }
+    }
+
+    /// Returns the CALSCALE parameter value of this calendar system.
+    #[inline]
+    pub fn to_calscale(self) -> Calscale {
+        match self {
+            Self::Gregorian => Calscale::Gregorian,
+            // The CLDR identifiers are valid tokens.
+            _ => Calscale::Extension(Token::from_str(&self.to_string()).unwrap()),
+        }
+    }
+
+    /// Returns the uppercase RSCALE name of RFC 7529, e.g. `HEBREW`.
+    #[inline]
+    pub const fn rscale(self) -> &'static str {
+        match self {
+            Self::Gregorian => "GREGORIAN",
+            Self::Chinese => "CHINESE",
+            Self::Hebrew => "HEBREW",
+            Self::IslamicCivil => "ISLAMIC-CIVIL",
+            Self::Persian => "PERSIAN",
+        }
+    }
+
+    /// Returns the number of days in a month, or `None` if the month does not exist in the year.
+    pub fn days_in_month(self, year: i32, month: u8, leap_month: bool) -> Option<u8> {
+        let (year, month) = (i64::from(year), i64::from(month));
+
+        let days = match self {
+            Self::Gregorian if !leap_month && (1..=12).contains(&month) => match month {
+                4 | 6 | 9 | 11 => 30,
+                2 if NaiveDate::from_ymd_opt(year as i32, 2, 29).is_some() => 29,
+                2 => 28,
+                _ => 31,
+            },
+            Self::IslamicCivil if !leap_month && (1..=12).contains(&month) => {
+                islamic_month_days(year, month)
+            },
+            Self::Persian if !leap_month && (1..=12).contains(&month) => match month {
+                1..=6 => 31,
+                7..=11 => 30,
+                _ if persian_year(year)?.0 => 30,
+                _ => 29,
+            },
+            Self::Hebrew => {
+                hebrew_month_days(hebrew_month_from_cldr(year, month, leap_month)?, year)
+            },
+            Self::Chinese if (1..=12).contains(&month) => {
+                let start = fixed_from_chinese(year, month, leap_month, 1);
+
+                if chinese_from_fixed(start) != (year, month, leap_month, 1) {
+                    return None;
+                }
+
+                chinese_new_moon_on_or_after(start + 1) - start
+            },
+            _ => return None,
+        };
+
+        Some(days as u8)
+    }
+}
+
+impl Display for CalendarSystem {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::Gregorian => "gregorian",
+            Self::Chinese => "chinese",
+            Self::Hebrew => "hebrew",
+            Self::IslamicCivil => "islamic-civil",
+            Self::Persian => "persian",
+        })
+    }
+}
+
+impl FromStr for CalendarSystem {
+    type Err = InvalidValueError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "gregorian" | "gregory" => Ok(Self::Gregorian),
+            "chinese" => Ok(Self::Chinese),
+            "hebrew" => Ok(Self::Hebrew),
+            "islamic-civil" | "islamicc" => Ok(Self::IslamicCivil),
+            "persian" => Ok(Self::Persian),
+            _ => Err(InvalidValueError::new("calendar system")),
+        }
+    }
+}
+
+/// A complete date in some calendar system, which can be in a leap month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CalendarDate {
+    calendar:   CalendarSystem,
+    year:       i32,
+    month:      u8,
+    leap_month: bool,
+    day:        u8,
+}
+
+const CALENDAR_DATE_ERROR: InvalidValueError = InvalidValueError::new("calendar date");
+
+impl CalendarDate {
+    /// Creates a date, validated against the calendar system.
+    ///
+    /// ```rust
+    /// use vcard::{
+    ///     chrono::NaiveDate,
+    ///     values::{CalendarDate, CalendarSystem},
+    /// };
+    ///
+    /// // The first day of Chinese year 2025.
+    /// let date =
+    ///     CalendarDate::new(CalendarSystem::Chinese, 2025, 1, false, 1).unwrap();
+    ///
+    /// assert_eq!(NaiveDate::from_ymd_opt(2025, 1, 29), date.to_gregorian());
+    /// ```
+    pub fn new(
+        calendar: CalendarSystem,
+        year: i32,
+        month: u8,
+        leap_month: bool,
+        day: u8,
+    ) -> Result<Self, InvalidValueError> {
+        let days = calendar.days_in_month(year, month, leap_month).ok_or(CALENDAR_DATE_ERROR)?;
+
+        if day < 1 || day > days {
+            return Err(CALENDAR_DATE_ERROR);
+        }
+
+        Ok(Self {
+            calendar,
+            year,
+            month,
+            leap_month,
+            day,
+        })
+    }
+
+    /// Converts a Gregorian date into a calendar system, failing when the date is out of the range of the system.
+    pub fn from_gregorian(
+        date: NaiveDate,
+        calendar: CalendarSystem,
+    ) -> Result<Self, InvalidValueError> {
+        let rd = fixed(date);
+
+        let (year, month, leap_month, day) = match calendar {
+            CalendarSystem::Gregorian => {
+                (i64::from(date.year()), i64::from(date.month()), false, i64::from(date.day()))
+            },
+            CalendarSystem::Chinese => chinese_from_fixed(rd),
+            CalendarSystem::Hebrew => {
+                let (year, month, day) = hebrew_from_fixed(rd);
+                let (month, leap_month) = hebrew_month_to_cldr(year, month);
+
+                (year, month, leap_month, day)
+            },
+            CalendarSystem::IslamicCivil => {
+                let (year, month, day) = islamic_from_fixed(rd);
+
+                (year, month, false, day)
+            },
+            CalendarSystem::Persian => {
+                let (year, month, day) = persian_from_fixed(rd).ok_or(CALENDAR_DATE_ERROR)?;
+
+                (year, month, false, day)
+            },
+        };
+
+        Ok(Self {
+            calendar,
+            year: i32::try_from(year).map_err(|_| CALENDAR_DATE_ERROR)?,
+            month: month as u8,
+            leap_month,
+            day: day as u8,
+        })
+    }
+
+    /// Converts this date into the Gregorian calendar, or `None` if it is out of the range of chrono.
+    pub fn to_gregorian(&self) -> Option<NaiveDate> {
+        let (year, month, day) = (i64::from(self.year), i64::from(self.month), i64::from(self.day));
+
+        let rd = match self.calendar {
+            CalendarSystem::Gregorian => {
+                return NaiveDate::from_ymd_opt(self.year, month as u32, day as u32);
+            },
+            CalendarSystem::Chinese => fixed_from_chinese(year, month, self.leap_month, day),
+            CalendarSystem::Hebrew => {
+                fixed_from_hebrew(year, hebrew_month_from_cldr(year, month, self.leap_month)?, day)
+            },
+            CalendarSystem::IslamicCivil => fixed_from_islamic(year, month, day),
+            CalendarSystem::Persian => fixed_from_persian(year, month, day)?,
+        };
+
+        from_fixed(rd)
+    }
+
+    /// Returns the calendar system.
+    #[inline]
+    pub const fn calendar(&self) -> CalendarSystem {
+        self.calendar
+    }
+
+    /// Returns the year.
+    #[inline]
+    pub const fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month, which is shared by a leap month and the month it follows.
+    #[inline]
+    pub const fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Checks whether the month is a leap month, e.g. `5L` in RFC 7529.
+    #[inline]
+    pub const fn is_leap_month(&self) -> bool {
+        self.leap_month
+    }
+
+    /// Returns the day of the month.
+    #[inline]
+    pub const fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+impl Date {
+    /// Converts a complete date written in a calendar system into the Gregorian calendar.
+    ///
+    /// A DATE value cannot mark a leap month, so its month is always read as a regular month.
+    ///
+    /// ```rust
+    /// use vcard::{
+    ///     chrono::NaiveDate,
+    ///     values::{CalendarSystem, Date},
+    /// };
+    ///
+    /// // 1 Tishri 5786 in the Hebrew calendar.
+    /// let date = Date::from_year_month_day(5786, 1, 1).unwrap();
+    ///
+    /// assert_eq!(
+    ///     NaiveDate::from_ymd_opt(2025, 9, 23),
+    ///     date.to_gregorian(CalendarSystem::Hebrew)
+    /// );
+    /// ```
+    pub fn to_gregorian(&self, calendar: CalendarSystem) -> Option<NaiveDate> {
+        let (year, month, day) = (self.year()?, self.month()?, self.day()?);
+
+        CalendarDate::new(calendar, i32::from(year), month, false, day).ok()?.to_gregorian()
+    }
+
+    /// Converts a Gregorian date into a complete date of a calendar system, failing when it falls in a leap month or out of the `0..=9999` range.
+    pub fn from_gregorian(
+        date: NaiveDate,
+        calendar: CalendarSystem,
+    ) -> Result<Self, InvalidValueError> {
+        let date = CalendarDate::from_gregorian(date, calendar)?;
+
+        if date.leap_month {
+            return Err(CALENDAR_DATE_ERROR);
+        }
+
+        let year = u16::try_from(date.year).map_err(|_| CALENDAR_DATE_ERROR)?;
+
+        if year > 9999 {
+            return Err(CALENDAR_DATE_ERROR);
+        }
+
+        Self::from_year_month_day(year, date.month, date.day)
+    }
+}
+
+impl DateAndOrTime {
+    /// Converts the date part of a value written in a calendar system into the Gregorian calendar, keeping the time part.
+    ///
+    /// Values without a complete date are returned unchanged only for the Gregorian calendar, and `None` is returned otherwise.
+    pub fn to_gregorian(&self, calendar: CalendarSystem) -> Option<Self> {
+        if calendar == CalendarSystem::Gregorian {
+            return Some(*self);
+        }
+
+        let convert = |date: &Date| Date::try_from(date.to_gregorian(calendar)?).ok();
+
+        match self {
+            Self::Date(date) => convert(date).map(Self::Date),
+            Self::DateTime(date_time) => {
+                DateTime::new(convert(&date_time.date())?, date_time.time())
+                    .ok()
+                    .map(Self::DateTime)
+            },
+            Self::Time(_) => None,
+        }
+    }
+}
+
+/// Returns the fixed day number (Rata Die) of a date, where January 1 of year 1 is day 1.
+#[inline]
+fn fixed(date: NaiveDate) -> i64 {
+    i64::from(date.num_days_from_ce())
+}
+
+#[inline]
+fn from_fixed(rd: i64) -> Option<NaiveDate> {
+    NaiveDate::from_num_days_from_ce_opt(i32::try_from(rd).ok()?)
+}
+
+#[inline]
+fn fixed_from_gregorian(year: i64, month: u32, day: u32) -> i64 {
+    NaiveDate::from_ymd_opt(year as i32, month, day).map_or(0, fixed)
+}
+
+#[inline]
+fn gregorian_year(rd: i64) -> i64 {
+    from_fixed(rd).map_or(0, |date| i64::from(date.year()))
+}
+
+// Tabular Islamic calendar
+
+const ISLAMIC_EPOCH: i64 = 227_015;
+
+fn fixed_from_islamic(year: i64, month: i64, day: i64) -> i64 {
+    day + 29 * (month - 1)
+        + (6 * month - 1).div_euclid(11)
+        + (year - 1) * 354
+        + (3 + 11 * year).div_euclid(30)
+        + ISLAMIC_EPOCH
+        - 1
+}
+
+fn islamic_from_fixed(rd: i64) -> (i64, i64, i64) {
+    let year = (30 * (rd - ISLAMIC_EPOCH) + 10_646).div_euclid(10_631);
+    let prior_days = rd - fixed_from_islamic(year, 1, 1);
+    let month = (11 * prior_days + 330).div_euclid(325);
+    let day = rd - fixed_from_islamic(year, month, 1) + 1;
+
+    (year, month, day)
+}
+
+fn islamic_month_days(year: i64, month: i64) -> i64 {
+    // Odd months are long, and the last month is long in the 11 leap years of every 30 years.
+    if month % 2 == 1 || (month == 12 && (14 + 11 * year).rem_euclid(30) < 11) { 30 } else { 29 }
+}
+
+// Hebrew calendar, with months numbered from Nisan internally and Adar II as month 13
+
+const HEBREW_EPOCH: i64 = -1_373_427;
+
+#[inline]
+fn hebrew_leap_year(year: i64) -> bool {
+    (7 * year + 1).rem_euclid(19) < 7
+}
+
+fn hebrew_elapsed_days(year: i64) -> i64 {
+    let months_elapsed = (235 * year - 234).div_euclid(19);
+    let parts_elapsed = 12_084 + 13_753 * months_elapsed;
+    let days = 29 * months_elapsed + parts_elapsed.div_euclid(25_920);
+
+    if (3 * (days + 1)).rem_euclid(7) < 3 { days + 1 } else { days }
+}
+
+fn hebrew_new_year(year: i64) -> i64 {
+    let (previous, current, next) =
+        (hebrew_elapsed_days(year - 1), hebrew_elapsed_days(year), hebrew_elapsed_days(year + 1));
+
+    let correction = if next - current == 356 {
+        2
+    } else if current - previous == 382 {
+        1
+    } else {
+        0
+    };
+
+    HEBREW_EPOCH + current + correction
+}
+
+fn hebrew_month_days(month: i64, year: i64) -> i64 {
+    let days_in_year = hebrew_new_year(year + 1) - hebrew_new_year(year);
+
+    match month {
+        2 | 4 | 6 | 10 | 13 => 29,
+        12 if !hebrew_leap_year(year) => 29,
+        // Marheshvan is long only in complete years, and Kislev is short only in deficient years.
+        8 if days_in_year % 10 != 5 => 29,
+        9 if days_in_year % 10 == 3 => 29,
+        _ => 30,
+    }
+}
+
+fn fixed_from_hebrew(year: i64, month: i64, day: i64) -> i64 {
+    let last_month = if hebrew_leap_year(year) { 13 } else { 12 };
+    let days = |months: std::ops::Range<i64>| -> i64 {
+        months.map(|month| hebrew_month_days(month, year)).sum()
+    };
+
+    // The year starts with Tishri, the seventh month.
+    let before = if month < 7 { days(7..last_month + 1) + days(1..month) } else { days(7..month) };
+
+    hebrew_new_year(year) + before + day - 1
+}
+
+fn hebrew_from_fixed(rd: i64) -> (i64, i64, i64) {
+    let approx = ((rd - HEBREW_EPOCH) * 98_496).div_euclid(35_975_351) + 1;
+    let mut year = approx - 1;
+
+    while hebrew_new_year(year + 1) <= rd {
+        year += 1;
+    }
+    let start = if rd < fixed_from_hebrew(year, 1, 1) { 7 } else { 1 };
+
+    let month = (start..=13)
+        .find(|&month| rd <= fixed_from_hebrew(year, month, hebrew_month_days(month, year)))
+        .unwrap_or(start);
+
+    (year, month, rd - fixed_from_hebrew(year, month, 1) + 1)
+}
+
+/// Converts a month numbered from Tishri, where Adar I is `5L`, into a month numbered from Nisan.
+fn hebrew_month_from_cldr(year: i64, month: i64, leap_month: bool) -> Option<i64> {
+    let leap_year = hebrew_leap_year(year);
+
+    match (month, leap_month) {
+        (1..=5, false) => Some(month + 6),
+        (5, true) if leap_year => Some(12),
+        (6, false) if leap_year => Some(13),
+        (6, false) => Some(12),
+        (7..=12, false) => Some(month - 6),
+        _ => None,
+    }
+}
+
+fn hebrew_month_to_cldr(year: i64, month: i64) -> (i64, bool) {
+    match month {
+        7..=11 => (month - 6, false),
+        12 if hebrew_leap_year(year) => (5, true),
+        12 | 13 => (6, false),
+        _ => (month + 6, false),
+    }
+}
+
+// Persian calendar, following the break years of the jalaali algorithm by Borkowski
+
+const PERSIAN_BREAKS: [i64; 20] = [
+    -61, 9, 38, 199, 426, 686, 756, 818, 1111, 1181, 1210, 1635, 2060, 2097, 2192, 2262, 2324,
+    2394, 2456, 3178,
+];
+
+/// Returns whether a Persian year is a leap year, the Gregorian year in which it starts and the day of March of its first day.
+fn persian_year(year: i64) -> Option<(bool, i64, i64)> {
+    if year < PERSIAN_BREAKS[0] || year >= PERSIAN_BREAKS[PERSIAN_BREAKS.len() - 1] {
+        return None;
+    }
+
+    let gregorian_year = year + 621;
+    let mut leap_years = -14;
+    let mut previous_break = PERSIAN_BREAKS[0];
+    let mut jump = 0;
+
+    for &next_break in &PERSIAN_BREAKS[1..] {
+        jump = next_break - previous_break;
+
+        if year < next_break {
+            break;
+        }
+
+        leap_years += jump / 33 * 8 + jump % 33 / 4;
+        previous_break = next_break;
+    }
+
+    let mut n = year - previous_break;
+
+    leap_years += n / 33 * 8 + (n % 33 + 3) / 4;
+
+    if jump % 33 == 4 && jump - n == 4 {
+        leap_years += 1;
+    }
+
+    let gregorian_leap_years = gregorian_year / 4 - (gregorian_year / 100 + 1) * 3 / 4 - 150;
+    let march = 20 + leap_years - gregorian_leap_years;
+
+    if jump - n < 6 {
+        n = n - jump + (jump + 4) / 33 * 33;
+    }
+
+    let mut years_since_leap = ((n + 1) % 33 - 1) % 4;
+
+    if years_since_leap == -1 {
+        years_since_leap = 4;
+    }
+
+    Some((years_since_leap == 0, gregorian_year, march))
+}
+
+fn fixed_from_persian(year: i64, month: i64, day: i64) -> Option<i64> {
+    let (_, gregorian_year, march) = persian_year(year)?;
+
+    // The first six months have 31 days and the others 30.
+    Some(
+        fixed_from_gregorian(gregorian_year, 3, march as u32) + (month - 1) * 31
+            - month / 7 * (month - 7)
+            + day
+            - 1,
+    )
+}
+
+fn persian_from_fixed(rd: i64) -> Option<(i64, i64, i64)> {
+    let mut year = gregorian_year(rd) - 621;
+    let (_, gregorian_year, march) = persian_year(year)?;
+    let mut k = rd - fixed_from_gregorian(gregorian_year, 3, march as u32);
+
+    if k >= 0 {
+        if k <= 185 {
+            return Some((year, 1 + k / 31, k % 31 + 1));
+        }
+
+        k -= 186;
+    } else {
+        year -= 1;
+        k += 179;
+
+        if persian_year(year)?.0 {
+            k += 1;
+        }
+    }
+
+    Some((year, 7 + k / 30, k % 30 + 1))
+}
+
+// Chinese calendar
+
+const MEAN_SYNODIC_MONTH: f64 = 29.530_588_861;
+const MEAN_TROPICAL_YEAR: f64 = 365.242_189;
+const J2000: f64 = 2_451_545.0;
+/// The Julian day of the start of fixed day 0.
+const FIXED_EPOCH_JD: f64 = 1_721_424.5;
+/// The fixed day of January 1, 1929, when China switched from Beijing mean time to UTC+8.
+const CHINA_STANDARD_TIME_SINCE: f64 = 704_188.0;
+
+/// Returns the offset of China time from universal time in days.
+#[inline]
+fn china_offset(fixed_moment: f64) -> f64 {
+    if fixed_moment < CHINA_STANDARD_TIME_SINCE { 1397.0 / 180.0 / 24.0 } else { 8.0 / 24.0 }
+}
+
+/// Returns the fixed day in China of a moment given as a Julian day in universal time.
+#[inline]
+fn china_day(jd: f64) -> i64 {
+    let moment = jd - FIXED_EPOCH_JD;
+
+    (moment + china_offset(moment)).floor() as i64
+}
+
+/// Returns the moment of the midnight in China that starts a fixed day, as a Julian day in universal time.
+#[inline]
+fn china_midnight(rd: i64) -> f64 {
+    rd as f64 + FIXED_EPOCH_JD - china_offset(rd as f64)
+}
+
+/// Returns the difference between terrestrial time and universal time in days, using the polynomials of Espenak and Meeus.
+fn delta_t(jd: f64) -> f64 {
+    let year = 2000.0 + (jd - J2000) / 365.25;
+
+    let seconds = match year {
+        y if !(1900.0..2150.0).contains(&y) => {
+            let u = (y - 1820.0) / 100.0;
+
+            -20.0 + 32.0 * u * u
+        },
+        y if y < 1920.0 => {
+            let t = y - 1900.0;
+
+            -2.79 + 1.494_119 * t - 0.059_893_9 * t.powi(2) + 0.006_196_6 * t.powi(3)
+                - 0.000_197 * t.powi(4)
+        },
+        y if y < 1941.0 => {
+            let t = y - 1920.0;
+
+            21.20 + 0.844_93 * t - 0.076_100 * t.powi(2) + 0.002_093_6 * t.powi(3)
+        },
+        y if y < 1961.0 => {
+            let t = y - 1950.0;
+
+            29.07 + 0.407 * t - t.powi(2) / 233.0 + t.powi(3) / 2547.0
+        },
+        y if y < 1986.0 => {
+            let t = y - 1975.0;
+
+            45.45 + 1.067 * t - t.powi(2) / 260.0 - t.powi(3) / 718.0
+        },
+        y if y < 2005.0 => {
+            let t = y - 2000.0;
+
+            63.86 + 0.3345 * t - 0.060_374 * t.powi(2)
+                + 0.001_727_5 * t.powi(3)
+                + 0.000_651_814 * t.powi(4)
+                + 0.000_023_735_99 * t.powi(5)
+        },
+        y if y < 2050.0 => {
+            let t = y - 2000.0;
+
+            62.92 + 0.322_17 * t + 0.005_589 * t.powi(2)
+        },
+        y => {
+            let u = (y - 1820.0) / 100.0;
+
+            -20.0 + 32.0 * u * u - 0.5628 * (2150.0 - y)
+        },
+    };
+
+    seconds / 86_400.0
+}
+
+/// Returns the moment of the `k`-th new moon after January 6, 2000 as a Julian day in universal time, by Meeus chapter 49.
+fn new_moon(k: f64) -> f64 {
+    let t = k / 1236.85;
+    let (t2, t3, t4) = (t * t, t * t * t, t * t * t * t);
+
+    let jde = 2_451_550.097_66 + MEAN_SYNODIC_MONTH * k + 0.000_154_37 * t2 - 0.000_000_150 * t3
+        + 0.000_000_000_73 * t4;
+
+    let e = 1.0 - 0.002_516 * t - 0.000_007_4 * t2;
+    let m = (2.5534 + 29.105_356_70 * k - 0.000_001_4 * t2 - 0.000_000_11 * t3).to_radians();
+    let mp = (201.5643 + 385.816_935_28 * k + 0.010_758_2 * t2 + 0.000_012_38 * t3
+        - 0.000_000_058 * t4)
+        .to_radians();
+    let f = (160.7108 + 390.670_502_84 * k - 0.001_611_8 * t2 - 0.000_002_27 * t3
+        + 0.000_000_011 * t4)
+        .to_radians();
+    let omega = (124.7746 - 1.563_755_88 * k + 0.002_067_2 * t2 + 0.000_002_15 * t3).to_radians();
+
+    let correction = -0.407_20 * mp.sin()
+        + 0.172_41 * e * m.sin()
+        + 0.016_08 * (2.0 * mp).sin()
+        + 0.010_39 * (2.0 * f).sin()
+        + 0.007_39 * e * (mp - m).sin()
+        - 0.005_14 * e * (mp + m).sin()
+        + 0.002_08 * e * e * (2.0 * m).sin()
+        - 0.001_11 * (mp - 2.0 * f).sin()
+        - 0.000_57 * (mp + 2.0 * f).sin()
+        + 0.000_56 * e * (2.0 * mp + m).sin()
+        - 0.000_42 * (3.0 * mp).sin()
+        + 0.000_42 * e * (m + 2.0 * f).sin()
+        + 0.000_38 * e * (m - 2.0 * f).sin()
+        - 0.000_24 * e * (2.0 * mp - m).sin()
+        - 0.000_17 * omega.sin()
+        - 0.000_07 * (mp + 2.0 * m).sin()
+        + 0.000_04 * (2.0 * mp - 2.0 * f).sin()
+        + 0.000_04 * (3.0 * m).sin()
+        + 0.000_03 * (mp + m - 2.0 * f).sin()
+        + 0.000_03 * (2.0 * mp + 2.0 * f).sin()
+        - 0.000_03 * (mp + m + 2.0 * f).sin()
+        + 0.000_03 * (mp - m + 2.0 * f).sin()
+        - 0.000_02 * (mp - m - 2.0 * f).sin()
+        - 0.000_02 * (3.0 * mp + m).sin()
+        + 0.000_02 * (4.0 * mp).sin();
+
+    const PLANETARY: [(f64, f64, f64, f64); 14] = [
+        (0.000_325, 299.77, 0.107_408, -0.009_173),
+        (0.000_165, 251.88, 0.016_321, 0.0),
+        (0.000_164, 251.83, 26.651_886, 0.0),
+        (0.000_126, 349.42, 36.412_478, 0.0),
+        (0.000_110, 84.66, 18.206_239, 0.0),
+        (0.000_062, 141.74, 53.303_771, 0.0),
+        (0.000_060, 207.14, 2.453_732, 0.0),
+        (0.000_056, 154.84, 7.306_860, 0.0),
+        (0.000_047, 34.52, 27.261_239, 0.0),
+        (0.000_042, 207.19, 0.121_824, 0.0),
+        (0.000_040, 291.34, 1.844_379, 0.0),
+        (0.000_037, 161.72, 24.198_154, 0.0),
+        (0.000_035, 239.56, 25.513_099, 0.0),
+        (0.000_023, 331.55, 3.592_518, 0.0),
+    ];
+
+    let planetary: f64 = PLANETARY
+        .iter()
+        .map(|(coefficient, a, b, c)| coefficient * (a + b * k + c * t2).to_radians().sin())
+        .sum();
+
+    let jde = jde + correction + planetary;
+
+    jde - delta_t(jde)
+}
+
+/// Returns the index of the mean new moon near a moment.
+#[inline]
+fn lunation(jd: f64) -> f64 {
+    ((jd - 2_451_550.097_66) / MEAN_SYNODIC_MONTH).round()
+}
+
+fn new_moon_at_or_after(jd: f64) -> f64 {
+    let mut k = lunation(jd) - 1.0;
+
+    loop {
+        let moment = new_moon(k);
+
+        if moment >= jd {
+            return moment;
+        }
+
+        k += 1.0;
+    }
+}
+
+fn new_moon_before(jd: f64) -> f64 {
+    let mut k = lunation(jd) + 1.0;
+
+    loop {
+        let moment = new_moon(k);
+
+        if moment < jd {
+            return moment;
+        }
+
+        k -= 1.0;
+    }
+}
+
+/// Returns the apparent solar longitude in degrees at a moment in universal time, by Meeus chapter 25.
+fn solar_longitude(jd: f64) -> f64 {
+    let t = (jd + delta_t(jd) - J2000) / 36_525.0;
+
+    let l0 = 280.466_46 + 36_000.769_83 * t + 0.000_303_2 * t * t;
+    let m = (357.529_11 + 35_999.050_29 * t - 0.000_153_7 * t * t).to_radians();
+    let c = (1.914_602 - 0.004_817 * t - 0.000_014 * t * t) * m.sin()
+        + (0.019_993 - 0.000_101 * t) * (2.0 * m).sin()
+        + 0.000_289 * (3.0 * m).sin();
+    let omega = (125.04 - 1934.136 * t).to_radians();
+
+    (l0 + c - 0.005_69 - 0.004_78 * omega.sin()).rem_euclid(360.0)
+}
+
+/// Returns the first moment after `jd` when the solar longitude reaches `longitude`.
+fn solar_longitude_after(longitude: f64, jd: f64) -> f64 {
+    let rate = MEAN_TROPICAL_YEAR / 360.0;
+    let mut moment = jd + rate * (longitude - solar_longitude(jd)).rem_euclid(360.0);
+
+    for _ in 0..10 {
+        let difference = (longitude - solar_longitude(moment) + 180.0).rem_euclid(360.0) - 180.0;
+
+        moment += difference * rate;
+
+        if difference.abs() < 1e-7 {
+            break;
+        }
+    }
+
+    moment
+}
+
+#[inline]
+fn chinese_new_moon_on_or_after(rd: i64) -> i64 {
+    china_day(new_moon_at_or_after(china_midnight(rd)))
+}
+
+#[inline]
+fn chinese_new_moon_before(rd: i64) -> i64 {
+    china_day(new_moon_before(china_midnight(rd)))
+}
+
+/// Returns the major solar term (zhongqi) in effect at the start of a day, from 1 to 12.
+#[inline]
+fn major_solar_term(rd: i64) -> i64 {
+    (1 + (solar_longitude(china_midnight(rd)) / 30.0).floor() as i64).rem_euclid(12) + 1
+}
+
+/// Checks whether the month starting on a day has no major solar term, which makes it a leap month in a leap year.
+#[inline]
+fn no_major_solar_term(rd: i64) -> bool {
+    major_solar_term(rd) == major_solar_term(chinese_new_moon_on_or_after(rd + 1))
+}
+
+fn winter_solstice_on_or_before(rd: i64) -> i64 {
+    let year = gregorian_year(rd);
+
+    let solstice = |year: i64| {
+        china_day(solar_longitude_after(270.0, china_midnight(fixed_from_gregorian(year, 1, 1))))
+    };
+
+    let day = solstice(year);
+
+    if day <= rd { day } else { solstice(year - 1) }
+}
+
+/// Checks whether there is a leap month from the month starting on `start` to the month starting on `month`.
+fn prior_leap_month(start: i64, mut month: i64) -> bool {
+    while month >= start {
+        if no_major_solar_term(month) {
+            return true;
+        }
+
+        month = chinese_new_moon_before(month);
+    }
+
+    false
+}
+
+fn chinese_new_year_in_sui(rd: i64) -> i64 {
+    let s1 = winter_solstice_on_or_before(rd);
+    let s2 = winter_solstice_on_or_before(s1 + 370);
+    let m12 = chinese_new_moon_on_or_after(s1 + 1);
+    let m13 = chinese_new_moon_on_or_after(m12 + 1);
+    let next_m11 = chinese_new_moon_before(s2 + 1);
+
+    // A leap month 11 or 12 pushes the new year one month later.
+    if ((next_m11 - m12) as f64 / MEAN_SYNODIC_MONTH).round() == 12.0
+        && (no_major_solar_term(m12) || no_major_solar_term(m13))
+    {
+        chinese_new_moon_on_or_after(m13 + 1)
+    } else {
+        m13
+    }
+}
+
+fn chinese_new_year_on_or_before(rd: i64) -> i64 {
+    let new_year = chinese_new_year_in_sui(rd);
+
+    if rd >= new_year { new_year } else { chinese_new_year_in_sui(rd - 180) }
+}
+
+/// Returns the Gregorian-related year, the month, whether it is a leap month and the day.
+fn chinese_from_fixed(rd: i64) -> (i64, i64, bool, i64) {
+    let s1 = winter_solstice_on_or_before(rd);
+    let s2 = winter_solstice_on_or_before(s1 + 370);
+    let m12 = chinese_new_moon_on_or_after(s1 + 1);
+    let next_m11 = chinese_new_moon_before(s2 + 1);
+    let start = chinese_new_moon_before(rd + 1);
+    let leap_year = ((next_m11 - m12) as f64 / MEAN_SYNODIC_MONTH).round() == 12.0;
+
+    let mut months = ((start - m12) as f64 / MEAN_SYNODIC_MONTH).round() as i64;
+
+    if leap_year && prior_leap_month(m12, start) {
+        months -= 1;
+    }
+
+    let month = (months - 1).rem_euclid(12) + 1;
+    let leap_month = leap_year
+        && no_major_solar_term(start)
+        && !prior_leap_month(m12, chinese_new_moon_before(start));
+
+    let year = gregorian_year(chinese_new_year_on_or_before(rd));
+
+    (year, month, leap_month, rd - start + 1)
+}
+
+fn fixed_from_chinese(year: i64, month: i64, leap_month: bool, day: i64) -> i64 {
+    let new_year = chinese_new_year_on_or_before(fixed_from_gregorian(year, 7, 1));
+    let guess = chinese_new_moon_on_or_after(new_year + (month - 1) * 29);
+    let (_, guess_month, guess_leap_month, _) = chinese_from_fixed(guess);
+
+    let start = if guess_month == month && guess_leap_month == leap_month {
+        guess
+    } else {
+        chinese_new_moon_on_or_after(guess + 1)
+    };
+
+    start + day - 1
+}
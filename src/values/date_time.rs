@@ -3,11 +3,12 @@
 //! vCard allows truncated date and time forms like `--0412` which cannot be represented by chrono types, so this module defines its own types for them.
 
 use std::{
+    cmp::Ordering,
     fmt::{self, Display, Formatter},
     str::FromStr,
 };
 
-use chrono::{Datelike, FixedOffset, NaiveDate, NaiveDateTime, TimeZone as _, Timelike, Utc};
+use chrono::{Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc};
 
 use crate::error::InvalidValueError;
 
@@ -26,6 +27,19 @@ fn parse_digits(s: &[u8]) -> Option<u16> {
     Some(n)
 }
 
+/// Compares the parts of two partial values from the most significant one, where a part that only one of them has makes them incomparable.
+fn compare_parts(parts: impl IntoIterator<Item = (Option<u16>, Option<u16>)>) -> Option<Ordering> {
+    for part in parts {
+        match part {
+            (Some(a), Some(b)) if a != b => return Some(a.cmp(&b)),
+            (Some(_), None) | (None, Some(_)) => return None,
+            _ => (),
+        }
+    }
+
+    Some(Ordering::Equal)
+}
+
 /// A UTC offset value, e.g. `+0800` or `-0530`.
 ///
 /// This is used by the `utc-offset` value type and by time zone information inside time values.
@@ -284,6 +298,52 @@ impl TryFrom<NaiveDate> for Date {
     }
 }
 
+impl Date {
+    /// Converts this date into a chrono `NaiveDate`, returning `None` when it is not complete.
+    #[inline]
+    pub fn to_naive_date(&self) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(i32::from(self.year?), u32::from(self.month?), u32::from(self.day?))
+    }
+
+    /// Converts this date into a chrono `NaiveDate`, taking the missing parts from a default date.
+    ///
+    /// `None` is returned when the combined date does not exist, e.g. `--0229` in a year that is not a leap year.
+    ///
+    /// ```rust
+    /// use vcard::{chrono::NaiveDate, values::Date};
+    ///
+    /// let today = NaiveDate::from_ymd_opt(2025, 6, 15).unwrap();
+    ///
+    /// assert_eq!(
+    ///     NaiveDate::from_ymd_opt(2025, 4, 12),
+    ///     "--0412".parse::<Date>().unwrap().to_naive_date_or(today)
+    /// );
+    /// assert_eq!(
+    ///     NaiveDate::from_ymd_opt(1985, 6, 15),
+    ///     "1985".parse::<Date>().unwrap().to_naive_date_or(today)
+    /// );
+    /// ```
+    pub fn to_naive_date_or(&self, default: NaiveDate) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(
+            self.year.map_or(default.year(), i32::from),
+            self.month.map_or(default.month(), u32::from),
+            self.day.map_or(default.day(), u32::from),
+        )
+    }
+}
+
+impl PartialOrd for Date {
+    /// Compares the year, the month and the day in turn. Dates are incomparable when the first part where they differ is missing from one of them, e.g. `1985` and `1985-04`, or `--0412` and `19850412`.
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        compare_parts([
+            (self.year, other.year),
+            (self.month.map(u16::from), other.month.map(u16::from)),
+            (self.day.map(u16::from), other.day.map(u16::from)),
+        ])
+    }
+}
+
 impl Display for Date {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match (self.year, self.month, self.day) {
@@ -457,6 +517,63 @@ impl Time {
     pub const fn zone(&self) -> Option<Zone> {
         self.zone
     }
+
+    /// Converts this time into a chrono `NaiveTime` without its zone, returning `None` when it is not complete.
+    ///
+    /// A leap second `60` becomes the leap second representation of chrono.
+    #[inline]
+    pub fn to_naive_time(&self) -> Option<NaiveTime> {
+        naive_time(self.hour?, self.minute?, self.second?)
+    }
+
+    /// Converts this time into a chrono `NaiveTime` without its zone, taking the missing parts from a default time.
+    ///
+    /// Pass `NaiveTime::MIN` to read a reduced time like `10` as `10:00:00`.
+    pub fn to_naive_time_or(&self, default: NaiveTime) -> Option<NaiveTime> {
+        naive_time(
+            self.hour.unwrap_or(default.hour() as u8),
+            self.minute.unwrap_or(default.minute() as u8),
+            self.second.unwrap_or(default.second() as u8),
+        )
+    }
+}
+
+/// Creates a chrono `NaiveTime`, where a second of `60` is a leap second.
+fn naive_time(hour: u8, minute: u8, second: u8) -> Option<NaiveTime> {
+    let (hour, minute, second) = (u32::from(hour), u32::from(minute), u32::from(second));
+
+    if second == 60 {
+        NaiveTime::from_hms_nano_opt(hour, minute, 59, 1_000_000_000)
+    } else {
+        NaiveTime::from_hms_opt(hour, minute, second)
+    }
+}
+
+impl From<NaiveTime> for Time {
+    /// Converts a chrono `NaiveTime` into a complete `Time`, dropping the sub-second part.
+    #[inline]
+    fn from(time: NaiveTime) -> Self {
+        // chrono keeps a leap second in the nanoseconds of second 59.
+        let second = if time.nanosecond() >= 1_000_000_000 { 60 } else { time.second() };
+
+        Self::from_hour_minute_second(time.hour() as u8, time.minute() as u8, second as u8).unwrap()
+    }
+}
+
+impl PartialOrd for Time {
+    /// Compares the hour, the minute and the second in turn like [`Date`] does. Times are only comparable when they have the same zone, because a truncated time cannot be moved into another zone.
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.zone != other.zone {
+            return None;
+        }
+
+        compare_parts([
+            (self.hour.map(u16::from), other.hour.map(u16::from)),
+            (self.minute.map(u16::from), other.minute.map(u16::from)),
+            (self.second.map(u16::from), other.second.map(u16::from)),
+        ])
+    }
 }
 
 impl Display for Time {
@@ -574,6 +691,111 @@ impl DateTime {
     pub const fn time(&self) -> Time {
         self.time
     }
+
+    /// Converts this date-time into a chrono `NaiveDateTime` without its zone, returning `None` when it is not complete.
+    #[inline]
+    pub fn to_naive_date_time(&self) -> Option<NaiveDateTime> {
+        Some(NaiveDateTime::new(self.date.to_naive_date()?, self.time.to_naive_time()?))
+    }
+
+    /// Converts this date-time into a chrono `NaiveDateTime` without its zone, taking the missing parts from a default date-time.
+    #[inline]
+    pub fn to_naive_date_time_or(&self, default: NaiveDateTime) -> Option<NaiveDateTime> {
+        Some(NaiveDateTime::new(
+            self.date.to_naive_date_or(default.date())?,
+            self.time.to_naive_time_or(default.time())?,
+        ))
+    }
+
+    /// Converts a complete date-time with a zone into a chrono `DateTime`, returning `None` when it is not complete or has no zone.
+    #[inline]
+    pub fn to_fixed_offset(&self) -> Option<chrono::DateTime<FixedOffset>> {
+        self.time.zone.and_then(|zone| {
+            zone.to_fixed_offset().from_local_datetime(&self.to_naive_date_time()?).single()
+        })
+    }
+
+    /// Converts a complete date-time into a chrono `DateTime` in a time zone, returning `None` when it is not complete.
+    ///
+    /// A date-time with a zone is moved into the time zone. One without a zone is read as a local time there, where an ambiguous time takes the earlier instant and a skipped time gives `None`.
+    ///
+    /// ```rust
+    /// use vcard::{chrono_tz::Asia::Taipei, values::DateTime};
+    ///
+    /// let date_time: DateTime = "20250412T020000Z".parse().unwrap();
+    ///
+    /// assert_eq!(
+    ///     "2025-04-12 10:00:00 CST",
+    ///     date_time.to_date_time(&Taipei).unwrap().to_string()
+    /// );
+    /// ```
+    pub fn to_date_time<Tz: TimeZone>(&self, tz: &Tz) -> Option<chrono::DateTime<Tz>> {
+        match self.time.zone {
+            Some(_) => Some(self.to_fixed_offset()?.with_timezone(tz)),
+            None => tz.from_local_datetime(&self.to_naive_date_time()?).earliest(),
+        }
+    }
+}
+
+impl TryFrom<NaiveDateTime> for DateTime {
+    type Error = InvalidValueError;
+
+    /// Converts a chrono `NaiveDateTime` into a complete `DateTime` without a zone, failing when the year is out of the `0..=9999` range.
+    #[inline]
+    fn try_from(date_time: NaiveDateTime) -> Result<Self, Self::Error> {
+        Self::new(
+            Date::try_from(date_time.date()).map_err(|_| DATE_TIME_ERROR)?,
+            Time::from(date_time.time()),
+        )
+    }
+}
+
+impl TryFrom<chrono::DateTime<Utc>> for DateTime {
+    type Error = InvalidValueError;
+
+    /// Converts a chrono `DateTime` in UTC into a complete `DateTime` with the `Z` zone.
+    #[inline]
+    fn try_from(date_time: chrono::DateTime<Utc>) -> Result<Self, Self::Error> {
+        let date_time = Self::try_from(date_time.naive_utc())?;
+
+        Self::new(date_time.date, date_time.time.with_zone(Zone::Utc))
+    }
+}
+
+impl TryFrom<chrono::DateTime<FixedOffset>> for DateTime {
+    type Error = InvalidValueError;
+
+    /// Converts a chrono `DateTime` with a fixed offset into a complete `DateTime` with that offset.
+    #[inline]
+    fn try_from(date_time: chrono::DateTime<FixedOffset>) -> Result<Self, Self::Error> {
+        let zone = Zone::Offset((*date_time.offset()).into());
+        let date_time = Self::try_from(date_time.naive_local())?;
+
+        Self::new(date_time.date, date_time.time.with_zone(zone))
+    }
+}
+
+impl PartialOrd for DateTime {
+    /// Compares complete date-times with zones by their instants, and the others by their dates and then their times. The two kinds are incomparable with each other, which keeps the ordering transitive.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self.to_fixed_offset(), other.to_fixed_offset()) {
+            (Some(a), Some(b)) => {
+                let ordering = a.cmp(&b);
+
+                // Equal instants written differently are not equal values.
+                if self.time.zone == other.time.zone || ordering.is_ne() {
+                    Some(ordering)
+                } else {
+                    None
+                }
+            },
+            (None, None) => match self.date.partial_cmp(&other.date)? {
+                Ordering::Equal => self.time.partial_cmp(&other.time),
+                ordering => Some(ordering),
+            },
+            _ => None,
+        }
+    }
 }
 
 impl Display for DateTime {
@@ -628,6 +850,40 @@ impl From<Time> for DateAndOrTime {
     }
 }
 
+impl DateAndOrTime {
+    /// Converts this value into a chrono `NaiveDateTime` without its zone, taking the missing parts, including a missing date or time, from a default date-time.
+    pub fn to_naive_date_time_or(&self, default: NaiveDateTime) -> Option<NaiveDateTime> {
+        match self {
+            Self::DateTime(date_time) => date_time.to_naive_date_time_or(default),
+            Self::Date(date) => {
+                Some(NaiveDateTime::new(date.to_naive_date_or(default.date())?, default.time()))
+            },
+            Self::Time(time) => {
+                Some(NaiveDateTime::new(default.date(), time.to_naive_time_or(default.time())?))
+            },
+        }
+    }
+}
+
+impl PartialOrd for DateAndOrTime {
+    /// Compares values of the same kind like their types do. A date and a date-time are compared by their dates and are incomparable on the same date, or when the date-time is an instant, while a standalone time is incomparable with the others.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Self::DateTime(a), Self::DateTime(b)) => a.partial_cmp(b),
+            (Self::Date(a), Self::Date(b)) => a.partial_cmp(b),
+            (Self::Time(a), Self::Time(b)) => a.partial_cmp(b),
+            // An instant is only comparable with other instants, like `DateTime` does.
+            (Self::DateTime(a), Self::Date(b)) if a.to_fixed_offset().is_none() => {
+                a.date.partial_cmp(b).filter(|ordering| ordering.is_ne())
+            },
+            (Self::Date(a), Self::DateTime(b)) if b.to_fixed_offset().is_none() => {
+                a.partial_cmp(&b.date).filter(|ordering| ordering.is_ne())
+            },
+            _ => None,
+        }
+    }
+}
+
 impl Display for DateAndOrTime {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
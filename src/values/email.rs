@@ -0,0 +1,150 @@
+//! Normalization and comparison of email addresses, including internationalized ones of RFC 6531.
+
+use unicode_normalization::UnicodeNormalization;
+
+use super::EmailValue;
+
+/// How a mail provider treats the local parts of its addresses.
+struct Provider {
+    domains:       &'static [&'static str],
+    /// The domain that all the domains of the provider are canonicalized to, if they are not kept.
+    canonical:     Option<&'static str>,
+    /// Whether dots in the local part are ignored, e.g. `j.doe` and `jdoe` are the same mailbox.
+    ignores_dots:  bool,
+    /// The character that starts a sub-address tag, e.g. `+` in `jdoe+news`, if the provider has tags.
+    tag_separator: Option<char>,
+}
+
+/// The providers whose canonicalization rules are known. All of them treat local parts case-insensitively.
+static PROVIDERS: &[Provider] = &[
+    Provider {
+        domains:       &["gmail.com", "googlemail.com"],
+        canonical:     Some("gmail.com"),
+        ignores_dots:  true,
+        tag_separator: Some('+'),
+    },
+    Provider {
+        domains:       &["outlook.com", "hotmail.com", "live.com"],
+        canonical:     None,
+        ignores_dots:  false,
+        tag_separator: Some('+'),
+    },
+    Provider {
+        domains:       &["icloud.com", "me.com", "mac.com"],
+        canonical:     None,
+        ignores_dots:  false,
+        tag_separator: Some('+'),
+    },
+    Provider {
+        domains:       &["fastmail.com", "proton.me", "protonmail.com"],
+        canonical:     None,
+        ignores_dots:  false,
+        tag_separator: Some('+'),
+    },
+    Provider {
+        // The `basename-keyword` addresses of Yahoo use a separately chosen base name, so they are not tags of the mailbox.
+        domains:       &["yahoo.com"],
+        canonical:     None,
+        ignores_dots:  false,
+        tag_separator: None,
+    },
+];
+
+impl EmailValue {
+    /// Returns the local part, which is everything before the last `@`.
+    #[inline]
+    pub fn local_part(&self) -> &str {
+        self.0.rsplit_once('@').map_or(self.0.as_str(), |(local_part, _)| local_part)
+    }
+
+    /// Returns the domain as written, which is everything after the last `@`.
+    #[inline]
+    pub fn domain(&self) -> &str {
+        self.0.rsplit_once('@').map_or("", |(_, domain)| domain)
+    }
+
+    /// Returns the domain converted to lowercase ASCII by IDNA, e.g. `xn--bcher-kva.example` for `Bücher.example`.
+    ///
+    /// A domain that IDNA rejects, such as an IP literal, is only lowercased.
+    pub fn ascii_domain(&self) -> String {
+        let domain = self.domain();
+
+        idna::domain_to_ascii(domain).unwrap_or_else(|_| domain.to_lowercase())
+    }
+
+    /// Returns the domain converted to lowercase Unicode by IDNA, e.g. `bücher.example` for `xn--bcher-kva.example`.
+    pub fn unicode_domain(&self) -> String {
+        let domain = self.domain();
+
+        match idna::domain_to_unicode(domain) {
+            (unicode, Ok(())) => unicode,
+            (_, Err(_)) => domain.to_lowercase(),
+        }
+    }
+
+    /// Checks whether the address has non-ASCII characters, which need the SMTPUTF8 extension of RFC 6531 when they are in the local part.
+    #[inline]
+    pub fn is_internationalized(&self) -> bool {
+        !self.0.is_ascii()
+    }
+
+    /// Returns the address with the local part in Unicode NFC and the domain in lowercase ASCII.
+    ///
+    /// The local part keeps its case because RFC 5321 lets the receiving server decide whether it matters.
+    ///
+    /// ```rust
+    /// use vcard::values::EmailValue;
+    ///
+    /// let email: EmailValue = "Len@Bücher.Example".parse().unwrap();
+    ///
+    /// assert_eq!("Len@xn--bcher-kva.example", email.normalized().as_str());
+    /// ```
+    pub fn normalized(&self) -> Self {
+        let local_part: String = self.local_part().nfc().collect();
+
+        Self(format!("{local_part}@{}", self.ascii_domain()))
+    }
+
+    /// Compares two addresses after normalization, so the domains are compared case-insensitively and across their IDNA forms.
+    #[inline]
+    pub fn eq_normalized(&self, other: &Self) -> bool {
+        self.normalized() == other.normalized()
+    }
+
+    /// Returns the address that reaches the same mailbox at a known provider, removing sub-address tags and the dots that the provider ignores.
+    ///
+    /// Addresses at other providers are only normalized, because their local parts cannot be assumed to be case-insensitive.
+    ///
+    /// ```rust
+    /// use vcard::values::EmailValue;
+    ///
+    /// let email: EmailValue = "Magic.Len+news@GoogleMail.com".parse().unwrap();
+    ///
+    /// assert_eq!("magiclen@gmail.com", email.canonicalized().as_str());
+    /// ```
+    pub fn canonicalized(&self) -> Self {
+        let normalized = self.normalized();
+        let domain = normalized.domain();
+
+        let Some(provider) = PROVIDERS.iter().find(|provider| provider.domains.contains(&domain))
+        else {
+            return normalized;
+        };
+
+        let local_part = normalized.local_part().to_lowercase();
+
+        let mut local_part = match provider.tag_separator.and_then(|c| local_part.split_once(c)) {
+            Some((local_part, _)) => local_part.to_string(),
+            None => local_part,
+        };
+
+        if provider.ignores_dots {
+            local_part.retain(|c| c != '.');
+        }
+
+        let domain = provider.canonical.unwrap_or(domain);
+
+        // Removing the tag and the dots can leave an invalid address like `@gmail.com`, which keeps the normalized one.
+        format!("{local_part}@{domain}").parse().unwrap_or(normalized)
+    }
+}
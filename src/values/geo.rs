@@ -0,0 +1,271 @@
+//! The `geo` URI scheme of RFC 5870, used by the GEO property and the GEO parameter.
+
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use super::Uri;
+use crate::error::InvalidValueError;
+
+/// The mean radius of the Earth in meters, which the haversine formula uses.
+pub(crate) const EARTH_RADIUS: f64 = 6_371_008.8;
+
+const GEO_ERROR: InvalidValueError = InvalidValueError::new("geo URI");
+
+/// A `geo:` URI of RFC 5870, e.g. `geo:25.0330,121.5654;u=10`.
+///
+/// The coordinates are in the WGS 84 reference system unless the `crs` parameter names another one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeoUri {
+    latitude:    f64,
+    longitude:   f64,
+    altitude:    Option<f64>,
+    uncertainty: Option<f64>,
+    crs:         Option<String>,
+    /// Other parameters as lowercase names with their raw values.
+    parameters:  Vec<(String, Option<String>)>,
+}
+
+impl GeoUri {
+    /// Creates a WGS 84 location, where the latitude must be within `-90..=90` and the longitude within `-180..=180` degrees.
+    pub fn new(latitude: f64, longitude: f64) -> Result<Self, InvalidValueError> {
+        if !(-90.0..=90.0).contains(&latitude) || !(-180.0..=180.0).contains(&longitude) {
+            return Err(GEO_ERROR);
+        }
+
+        Ok(Self {
+            latitude,
+            longitude,
+            altitude: None,
+            uncertainty: None,
+            crs: None,
+            parameters: Vec::new(),
+        })
+    }
+
+    /// Sets the altitude in meters, which must be finite.
+    #[inline]
+    pub fn with_altitude(mut self, altitude: f64) -> Result<Self, InvalidValueError> {
+        if !altitude.is_finite() {
+            return Err(GEO_ERROR);
+        }
+
+        self.altitude = Some(altitude);
+
+        Ok(self)
+    }
+
+    /// Sets the uncertainty in meters, which is written as the `u` parameter and must be finite and not negative.
+    #[inline]
+    pub fn with_uncertainty(mut self, uncertainty: f64) -> Result<Self, InvalidValueError> {
+        if !uncertainty.is_finite() || uncertainty < 0.0 {
+            return Err(GEO_ERROR);
+        }
+
+        self.uncertainty = Some(uncertainty);
+
+        Ok(self)
+    }
+
+    /// Returns the latitude in degrees.
+    #[inline]
+    pub const fn latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    /// Returns the longitude in degrees.
+    #[inline]
+    pub const fn longitude(&self) -> f64 {
+        self.longitude
+    }
+
+    /// Returns the altitude in meters if it exists.
+    #[inline]
+    pub const fn altitude(&self) -> Option<f64> {
+        self.altitude
+    }
+
+    /// Returns the uncertainty in meters if it exists.
+    #[inline]
+    pub const fn uncertainty(&self) -> Option<f64> {
+        self.uncertainty
+    }
+
+    /// Returns the lowercase name of the coordinate reference system, which is `wgs84` by default.
+    #[inline]
+    pub fn crs(&self) -> &str {
+        self.crs.as_deref().unwrap_or("wgs84")
+    }
+
+    /// Checks whether the coordinates are in the WGS 84 reference system.
+    #[inline]
+    pub fn is_wgs84(&self) -> bool {
+        self.crs() == "wgs84"
+    }
+
+    /// Returns the great-circle distance in meters to another location by the haversine formula, or `None` if either is not in WGS 84.
+    ///
+    /// The altitudes are ignored.
+    ///
+    /// ```rust
+    /// use vcard::values::GeoUri;
+    ///
+    /// let taipei = GeoUri::new(25.0330, 121.5654).unwrap();
+    /// let tokyo = GeoUri::new(35.6762, 139.6503).unwrap();
+    ///
+    /// let kilometers = taipei.distance_to(&tokyo).unwrap() / 1000.0;
+    ///
+    /// assert!((2090.0..2110.0).contains(&kilometers));
+    /// ```
+    pub fn distance_to(&self, other: &Self) -> Option<f64> {
+        if !self.is_wgs84() || !other.is_wgs84() {
+            return None;
+        }
+
+        let (latitude_a, latitude_b) = (self.latitude.to_radians(), other.latitude.to_radians());
+        let half_latitude = (latitude_b - latitude_a) / 2.0;
+        let half_longitude = (other.longitude - self.longitude).to_radians() / 2.0;
+
+        let h = half_latitude.sin().powi(2)
+            + latitude_a.cos() * latitude_b.cos() * half_longitude.sin().powi(2);
+
+        Some(2.0 * EARTH_RADIUS * h.sqrt().min(1.0).asin())
+    }
+
+    /// Converts this location into a URI.
+    #[inline]
+    pub fn to_uri(&self) -> Uri {
+        // The written form is always a valid URI.
+        Uri::from_str(&self.to_string()).unwrap()
+    }
+}
+
+/// Parses a number of RFC 5870, which has an optional minus sign and an optional fraction.
+fn parse_number(s: &str) -> Option<f64> {
+    let digits = s.strip_prefix('-').unwrap_or(s);
+
+    let (integer, fraction) = digits.split_once('.').unwrap_or((digits, "0"));
+
+    if integer.is_empty()
+        || fraction.is_empty()
+        || !integer.bytes().chain(fraction.bytes()).all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+
+    s.parse().ok()
+}
+
+impl FromStr for GeoUri {
+    type Err = InvalidValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rest = s
+            .get(..4)
+            .filter(|scheme| scheme.eq_ignore_ascii_case("geo:"))
+            .map(|_| &s[4..])
+            .ok_or(GEO_ERROR)?;
+
+        let mut parts = rest.split(';');
+
+        let coordinates: Vec<f64> = parts
+            .next()
+            .unwrap_or_default()
+            .split(',')
+            .map(parse_number)
+            .collect::<Option<_>>()
+            .ok_or(GEO_ERROR)?;
+
+        let mut crs = None;
+        let mut uncertainty = None;
+        let mut parameters = Vec::new();
+
+        for part in parts {
+            let (name, value) = match part.split_once('=') {
+                Some((name, value)) => (name.to_ascii_lowercase(), Some(value)),
+                None => (part.to_ascii_lowercase(), None),
+            };
+
+            match (name.as_str(), value) {
+                ("crs", Some(value)) if crs.is_none() && parameters.is_empty() => {
+                    crs = Some(value.to_ascii_lowercase())
+                },
+                ("u", Some(value)) if uncertainty.is_none() && parameters.is_empty() => {
+                    uncertainty =
+                        Some(parse_number(value).filter(|value| *value >= 0.0).ok_or(GEO_ERROR)?)
+                },
+                ("crs" | "u", _) => return Err(GEO_ERROR),
+                _ if name.is_empty() => return Err(GEO_ERROR),
+                _ => parameters.push((name, value.map(str::to_string))),
+            }
+        }
+
+        let (latitude, longitude, altitude) = match coordinates[..] {
+            [latitude, longitude] => (latitude, longitude, None),
+            [latitude, longitude, altitude] => (latitude, longitude, Some(altitude)),
+            _ => return Err(GEO_ERROR),
+        };
+
+        let geo = Self {
+            latitude,
+            longitude,
+            altitude,
+            uncertainty,
+            crs,
+            parameters,
+        };
+
+        // Only the ranges of WGS 84 are known.
+        if geo.is_wgs84()
+            && (!(-90.0..=90.0).contains(&latitude) || !(-180.0..=180.0).contains(&longitude))
+        {
+            return Err(GEO_ERROR);
+        }
+
+        Ok(geo)
+    }
+}
+
+impl Display for GeoUri {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "geo:{},{}", self.latitude, self.longitude)?;
+
+        if let Some(altitude) = self.altitude {
+            write!(f, ",{altitude}")?;
+        }
+
+        if let Some(crs) = &self.crs {
+            write!(f, ";crs={crs}")?;
+        }
+
+        if let Some(uncertainty) = self.uncertainty {
+            write!(f, ";u={uncertainty}")?;
+        }
+
+        for (name, value) in &self.parameters {
+            match value {
+                Some(value) => write!(f, ";{name}={value}")?,
+                None => write!(f, ";{name}")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<&Uri> for GeoUri {
+    type Error = InvalidValueError;
+
+    #[inline]
+    fn try_from(uri: &Uri) -> Result<Self, Self::Error> {
+        Self::from_str(uri.as_str())
+    }
+}
+
+impl From<GeoUri> for Uri {
+    #[inline]
+    fn from(geo: GeoUri) -> Self {
+        geo.to_uri()
+    }
+}
@@ -1,8 +1,15 @@
 //! Value types used by vCard properties, defined in RFC 6350 section 4 and extended by RFC 9554.
 
+mod address_format;
+mod address_parse;
+mod calendar;
 mod date_time;
+mod email;
+mod geo;
 mod language_tag;
 mod name;
+mod name_format;
+mod phone;
 mod uri;
 
 use std::{
@@ -10,9 +17,16 @@ use std::{
     str::FromStr,
 };
 
+pub use address_format::AddressFormat;
+pub use address_parse::ParsedAddress;
+pub use calendar::{CalendarDate, CalendarSystem};
 pub use date_time::{Date, DateAndOrTime, DateTime, Time, Timestamp, UtcOffset, Zone};
+pub(crate) use geo::EARTH_RADIUS;
+pub use geo::GeoUri;
 pub use language_tag::LanguageTag;
 pub use name::{AddressValue, NameValue};
+pub use name_format::{NameConvention, NameStyle};
+pub use phone::{PhoneFormat, PhoneNumber};
 pub use uri::Uri;
 use validators::prelude::*;
 
@@ -0,0 +1,301 @@
+//! Display formatting of structured names by the conventions of different locales.
+
+use super::{LanguageTag, NameValue};
+use crate::property::{FormattedName, Name};
+
+/// The order and spacing conventions for writing a person's name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum NameConvention {
+    /// Given names first, e.g. `John Quincy Public`. This is the default.
+    #[default]
+    Western,
+    /// Family names first, without spaces between ideographic names, e.g. `王大衛`.
+    ///
+    /// Names in other scripts are still separated by spaces, e.g. `Nguyễn Văn An`.
+    EastAsian,
+    /// Family names first, with spaces, e.g. `Kovács János`.
+    Hungarian,
+    /// Given names first, followed by the paternal and the maternal surnames, e.g. `Gabriel García Márquez`.
+    ///
+    /// The short and formal forms only use the paternal surname, and the sort key moves particles like `de la` after the given names.
+    Spanish,
+}
+
+impl NameConvention {
+    /// Picks the convention of the primary language of a language tag, falling back to `Western`.
+    pub fn from_language(language: &LanguageTag) -> Self {
+        match language.as_language_tag().primary_language().to_ascii_lowercase().as_str() {
+            "zh" | "ja" | "ko" | "vi" => Self::EastAsian,
+            "hu" => Self::Hungarian,
+            "es" | "ca" | "gl" => Self::Spanish,
+            _ => Self::Western,
+        }
+    }
+
+    /// Picks a convention for a name, using the language when it is known and the script of the name otherwise.
+    pub fn detect(name: &NameValue, language: Option<&LanguageTag>) -> Self {
+        match language {
+            Some(language) => Self::from_language(language),
+            None if name
+                .family_names
+                .iter()
+                .chain(&name.given_names)
+                .any(|s| s.chars().any(is_ideographic)) =>
+            {
+                Self::EastAsian
+            },
+            None => Self::Western,
+        }
+    }
+}
+
+/// What a formatted name is used for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum NameStyle {
+    /// Every component, e.g. `Dr. John Quincy Public Jr., M.D.`. This is the default.
+    #[default]
+    Full,
+    /// Only the given and family names, e.g. `John Public`.
+    Short,
+    /// The honorific prefixes and the family names for salutations, e.g. `Dr. Public`.
+    Formal,
+    /// The family names first, for sorting, e.g. `Public, John Quincy`.
+    SortKey,
+}
+
+/// Checks whether a character belongs to a script that is written without spaces between names.
+fn is_ideographic(c: char) -> bool {
+    matches!(
+        c,
+        '\u{1100}'..='\u{11FF}'
+            | '\u{2E80}'..='\u{9FFF}'
+            | '\u{AC00}'..='\u{D7AF}'
+            | '\u{F900}'..='\u{FAFF}'
+            | '\u{FF66}'..='\u{FF9F}'
+            | '\u{20000}'..='\u{3FFFF}'
+    )
+}
+
+/// Joins name parts with spaces, except between two ideographic characters when `tight` is enabled.
+fn join(parts: &[&[String]], tight: bool) -> String {
+    let mut out = String::new();
+
+    for part in
+        parts.iter().flat_map(|list| list.iter()).map(|s| s.trim()).filter(|s| !s.is_empty())
+    {
+        let joined_tightly = tight
+            && out.chars().next_back().is_some_and(is_ideographic)
+            && part.chars().next().is_some_and(is_ideographic);
+
+        if !out.is_empty() && !joined_tightly {
+            out.push(' ');
+        }
+
+        out.push_str(part);
+    }
+
+    out
+}
+
+/// Splits the lowercase particles off the start of a Spanish surname, e.g. `de la` from `de la Fuente`.
+fn split_particle(surname: &str) -> (&str, &str) {
+    let mut rest = surname;
+
+    while let Some((word, after)) = rest.split_once(' ')
+        && matches!(word, "de" | "del" | "la" | "las" | "los" | "y")
+        && !after.trim().is_empty()
+    {
+        rest = after.trim_start();
+    }
+
+    (surname[..surname.len() - rest.len()].trim_end(), rest)
+}
+
+/// Appends the honorific suffixes after a comma, e.g. `, M.D.`.
+fn with_suffixes(mut name: String, suffixes: &[String]) -> String {
+    let suffixes = join(&[suffixes], false);
+
+    if !suffixes.is_empty() {
+        if !name.is_empty() {
+            name.push_str(", ");
+        }
+
+        name.push_str(&suffixes);
+    }
+
+    name
+}
+
+impl NameValue {
+    /// Formats this name for display.
+    ///
+    /// ```rust
+    /// use vcard::values::{NameConvention, NameStyle, NameValue};
+    ///
+    /// let name = NameValue {
+    ///     family_names: vec![String::from("García")],
+    ///     given_names: vec![String::from("Gabriel")],
+    ///     surname2: vec![String::from("Márquez")],
+    ///     ..NameValue::default()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     "Gabriel García Márquez",
+    ///     name.format(NameConvention::Spanish, NameStyle::Full)
+    /// );
+    /// assert_eq!(
+    ///     "García Márquez, Gabriel",
+    ///     name.format(NameConvention::Spanish, NameStyle::SortKey)
+    /// );
+    /// ```
+    pub fn format(&self, convention: NameConvention, style: NameStyle) -> String {
+        match (convention, style) {
+            (NameConvention::EastAsian | NameConvention::Hungarian, _) => {
+                let tight = convention == NameConvention::EastAsian;
+
+                match style {
+                    NameStyle::Full => {
+                        let name = join(
+                            &[
+                                &self.honorific_prefixes,
+                                &self.family_names,
+                                &self.surname2,
+                                &self.given_names,
+                                &self.additional_names,
+                                &self.generation,
+                            ],
+                            tight,
+                        );
+
+                        // Honorifics such as 先生 follow the name directly in East Asian languages.
+                        if tight {
+                            join(&[std::slice::from_ref(&name), &self.honorific_suffixes], tight)
+                        } else {
+                            with_suffixes(name, &self.honorific_suffixes)
+                        }
+                    },
+                    NameStyle::Short | NameStyle::SortKey => {
+                        join(&[&self.family_names, &self.surname2, &self.given_names], tight)
+                    },
+                    NameStyle::Formal => self.formal(tight),
+                }
+            },
+            (NameConvention::Spanish, NameStyle::Short) => {
+                let (paternal, _) = self.spanish_surnames();
+
+                join(&[&self.given_names, paternal], false)
+            },
+            (NameConvention::Spanish, NameStyle::Formal) => {
+                let (paternal, _) = self.spanish_surnames();
+
+                if paternal.iter().all(|s| s.trim().is_empty()) {
+                    join(&[&self.honorific_prefixes, &self.given_names], false)
+                } else {
+                    join(&[&self.honorific_prefixes, paternal], false)
+                }
+            },
+            (NameConvention::Spanish, NameStyle::SortKey) => {
+                let (paternal, maternal) = self.spanish_surnames();
+
+                // Particles such as `de la` are moved after the given names, so `de la Fuente` sorts under F.
+                let paternal = join(&[paternal], false);
+                let (particle, paternal) = split_particle(&paternal);
+
+                let particle = [particle.to_string()];
+
+                let family = join(&[&[paternal.to_string()], maternal], false);
+                let given = join(
+                    &[&self.given_names, &self.additional_names, &self.generation, &particle],
+                    false,
+                );
+
+                match (family.is_empty(), given.is_empty()) {
+                    (false, false) => format!("{family}, {given}"),
+                    (true, _) => given,
+                    (_, true) => family,
+                }
+            },
+            (_, NameStyle::Full) => with_suffixes(
+                join(
+                    &[
+                        &self.honorific_prefixes,
+                        &self.given_names,
+                        &self.additional_names,
+                        &self.family_names,
+                        &self.surname2,
+                        &self.generation,
+                    ],
+                    false,
+                ),
+                &self.honorific_suffixes,
+            ),
+            (_, NameStyle::Short) => join(&[&self.given_names, &self.family_names], false),
+            (_, NameStyle::Formal) => self.formal(false),
+            (_, NameStyle::SortKey) => {
+                let family = join(&[&self.family_names, &self.surname2], false);
+                let given =
+                    join(&[&self.given_names, &self.additional_names, &self.generation], false);
+
+                match (family.is_empty(), given.is_empty()) {
+                    (false, false) => format!("{family}, {given}"),
+                    (true, _) => given,
+                    (_, true) => family,
+                }
+            },
+        }
+    }
+
+    /// Returns the paternal and the maternal surnames, where two family names without a secondary surname are read as both surnames.
+    fn spanish_surnames(&self) -> (&[String], &[String]) {
+        if self.surname2.is_empty() && self.family_names.len() > 1 {
+            self.family_names.split_at(1)
+        } else {
+            (&self.family_names, &self.surname2)
+        }
+    }
+
+    /// Formats the salutation form, which falls back to the given names when there is no family name.
+    fn formal(&self, tight: bool) -> String {
+        if self.family_names.iter().all(|s| s.trim().is_empty()) {
+            return join(&[&self.honorific_prefixes, &self.given_names], tight);
+        }
+
+        if tight {
+            join(&[&self.honorific_prefixes, &self.family_names, &self.honorific_suffixes], tight)
+        } else {
+            join(&[&self.honorific_prefixes, &self.family_names], tight)
+        }
+    }
+}
+
+impl Name {
+    /// Derives an FN property from this N property, using the convention of its LANGUAGE parameter or of its script.
+    ///
+    /// The LANGUAGE and ALTID parameters are copied and the DERIVED parameter of RFC 9554 is set.
+    ///
+    /// ```rust
+    /// use vcard::{Name, values::NameValue};
+    ///
+    /// let mut name = Name::new(NameValue {
+    ///     family_names: vec![String::from("王")],
+    ///     given_names: vec![String::from("大衛")],
+    ///     ..NameValue::default()
+    /// });
+    ///
+    /// let formatted_name = name.to_formatted_name();
+    ///
+    /// assert_eq!("王大衛", formatted_name.value);
+    /// assert_eq!(Some(true), formatted_name.parameters.derived);
+    /// ```
+    pub fn to_formatted_name(&self) -> FormattedName {
+        let convention = NameConvention::detect(&self.value, self.parameters.language.as_ref());
+
+        let mut formatted_name = FormattedName::new(self.value.format(convention, NameStyle::Full));
+
+        formatted_name.parameters.language = self.parameters.language.clone();
+        formatted_name.parameters.altid = self.parameters.altid.clone();
+        formatted_name.parameters.derived = Some(true);
+
+        formatted_name
+    }
+}
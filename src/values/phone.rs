@@ -0,0 +1,227 @@
+//! Structured phone numbers for the TEL property, backed by the `phonenumber` crate that `validators` re-exports.
+
+use std::{
+    fmt::{self, Display, Formatter, Write as _},
+    str::FromStr,
+};
+
+use validators::phonenumber::{self, Mode, country};
+
+use super::{TelValue, Uri};
+use crate::error::InvalidValueError;
+
+/// The ways a phone number can be written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PhoneFormat {
+    /// The country code and the national number without any separator, e.g. `+886912345678`. This is the default.
+    #[default]
+    E164,
+    /// The country code followed by the national number grouped by the conventions of the country, e.g. `+886 912 345 678`.
+    International,
+    /// The number as it is dialed inside the country, e.g. `0912 345 678`.
+    National,
+    /// A `tel:` URI of RFC 3966, e.g. `tel:+886-912-345-678`.
+    Rfc3966,
+}
+
+impl PhoneFormat {
+    #[inline]
+    const fn mode(self) -> Mode {
+        match self {
+            Self::E164 => Mode::E164,
+            Self::International => Mode::International,
+            Self::National => Mode::National,
+            Self::Rfc3966 => Mode::Rfc3966,
+        }
+    }
+}
+
+/// A parsed phone number with its country calling code, national number and extension.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PhoneNumber(phonenumber::PhoneNumber);
+
+impl PhoneNumber {
+    /// Parses a phone number written as text or as a `tel:` URI.
+    ///
+    /// A number without a country calling code is read as a national number of `default_region`, which is an ISO 3166-1 alpha-2 code like `TW`.
+    ///
+    /// ```rust
+    /// use vcard::values::{PhoneFormat, PhoneNumber};
+    ///
+    /// let number = PhoneNumber::parse("0912-345-678", Some("TW")).unwrap();
+    ///
+    /// assert_eq!(886, number.country_code());
+    /// assert_eq!("+886912345678", number.to_e164());
+    /// assert_eq!("+886 912 345 678", number.format(PhoneFormat::International));
+    /// ```
+    pub fn parse(s: &str, default_region: Option<&str>) -> Result<Self, InvalidValueError> {
+        let region = default_region
+            .map(|region| {
+                country::Id::from_str(&region.trim().to_ascii_uppercase())
+                    .map_err(|_| InvalidValueError::new("region"))
+            })
+            .transpose()?;
+
+        phonenumber::parse(region, s.trim())
+            .map(Self)
+            .map_err(|_| InvalidValueError::new("phone number"))
+    }
+
+    /// Returns the country calling code, e.g. `886`.
+    #[inline]
+    pub fn country_code(&self) -> u16 {
+        self.0.code().value()
+    }
+
+    /// Returns the national significant number, including the leading zeros that some countries keep, e.g. `912345678`.
+    #[inline]
+    pub fn national_number(&self) -> String {
+        self.0.national().to_string()
+    }
+
+    /// Returns the extension, which is written as `;ext=` in a `tel:` URI.
+    #[inline]
+    pub fn extension(&self) -> Option<&str> {
+        self.0.extension().map(|extension| extension.as_ref())
+    }
+
+    /// Returns the ISO 3166-1 alpha-2 code of the region the number belongs to, if it can be determined.
+    #[inline]
+    pub fn region(&self) -> Option<String> {
+        self.0.country().id().map(|id| id.as_ref().to_string())
+    }
+
+    /// Checks whether the number is assigned a valid pattern in its region, which is stricter than parsing.
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        self.0.is_valid()
+    }
+
+    /// Returns the E.164 form, e.g. `+886912345678`. The extension is not part of E.164.
+    #[inline]
+    pub fn to_e164(&self) -> String {
+        format!("+{}{}", self.country_code(), self.national_number())
+    }
+
+    /// Formats the number in the given style. The extension is included except in the E.164 form.
+    pub fn format(&self, format: PhoneFormat) -> String {
+        let mut s = String::new();
+
+        // The formatter fails when the region has no metadata, in which case the E.164 form is used.
+        if write!(s, "{}", self.0.format().mode(format.mode())).is_err() {
+            s = self.to_e164();
+        }
+
+        s
+    }
+
+    /// Returns a `tel:` URI with the global number and the extension but no visual separators, e.g. `tel:+886912345678;ext=123`.
+    pub fn to_tel_uri(&self) -> Uri {
+        let mut uri = format!("tel:{}", self.to_e164());
+
+        if let Some(extension) = self.extension() {
+            uri.push_str(";ext=");
+            uri.push_str(extension);
+        }
+
+        // A `tel:` URI made of a global number is always a valid URI, so parsing never fails.
+        Uri::from_str(&uri).unwrap()
+    }
+}
+
+impl FromStr for PhoneNumber {
+    type Err = InvalidValueError;
+
+    /// Parses a phone number that has a country calling code.
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, None)
+    }
+}
+
+impl Display for PhoneNumber {
+    /// Writes the E.164 form.
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(&self.to_e164())
+    }
+}
+
+impl From<PhoneNumber> for TelValue {
+    /// Converts a phone number into the `tel:` URI form.
+    #[inline]
+    fn from(number: PhoneNumber) -> Self {
+        Self::Uri(number.to_tel_uri())
+    }
+}
+
+impl TelValue {
+    /// Parses this value, in either form, into a structured phone number.
+    ///
+    /// A number without a country calling code is read as a national number of `default_region`.
+    #[inline]
+    pub fn phone_number(
+        &self,
+        default_region: Option<&str>,
+    ) -> Result<PhoneNumber, InvalidValueError> {
+        match self {
+            Self::Uri(uri)
+                if !uri.as_str().get(..4).is_some_and(|s| s.eq_ignore_ascii_case("tel:")) =>
+            {
+                Err(InvalidValueError::new("tel uri"))
+            },
+            Self::Uri(uri) => PhoneNumber::parse(uri.as_str(), default_region),
+            Self::Text(text) => PhoneNumber::parse(text, default_region),
+        }
+    }
+
+    /// Returns the same form with the number normalized to E.164.
+    ///
+    /// The text form becomes e.g. `+886912345678 ext. 123` and the URI form becomes e.g. `tel:+886912345678;ext=123`.
+    ///
+    /// ```rust
+    /// use vcard::values::TelValue;
+    ///
+    /// let tel = TelValue::Text(String::from("(02) 2345-6789"));
+    ///
+    /// assert_eq!(
+    ///     TelValue::Text(String::from("+886223456789")),
+    ///     tel.normalized(Some("TW")).unwrap()
+    /// );
+    /// ```
+    pub fn normalized(&self, default_region: Option<&str>) -> Result<Self, InvalidValueError> {
+        let number = self.phone_number(default_region)?;
+
+        Ok(match self {
+            Self::Uri(_) => Self::Uri(number.to_tel_uri()),
+            Self::Text(_) => {
+                let mut text = number.to_e164();
+
+                if let Some(extension) = number.extension() {
+                    text.push_str(" ext. ");
+                    text.push_str(extension);
+                }
+
+                Self::Text(text)
+            },
+        })
+    }
+
+    /// Converts this value into the RFC 3966 `tel:` URI form, e.g. `tel:+886-912-345-678`.
+    pub fn to_uri(&self, default_region: Option<&str>) -> Result<Self, InvalidValueError> {
+        let number = self.phone_number(default_region)?;
+
+        Ok(Self::Uri(
+            Uri::from_str(&number.format(PhoneFormat::Rfc3966))
+                .unwrap_or_else(|_| number.to_tel_uri()),
+        ))
+    }
+
+    /// Converts this value into the text form in the international style, e.g. `+886 912 345 678`.
+    #[inline]
+    pub fn to_text(&self, default_region: Option<&str>) -> Result<Self, InvalidValueError> {
+        let number = self.phone_number(default_region)?;
+
+        Ok(Self::Text(number.format(PhoneFormat::International)))
+    }
+}
@@ -8,7 +8,7 @@ use std::{
 use base64::Engine;
 use mime::Mime;
 
-use crate::error::InvalidValueError;
+use crate::{error::InvalidValueError, syntax::BASE64};
 
 /// A URI value backed by [`url::Url`], which handles validation, IDNA and percent encoding.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -42,6 +42,73 @@ impl Uri {
         Ok(Self::from_data(&mime, &data))
     }
 
+    /// Decodes a `data:` URI of RFC 2397 into its media type and bytes, returning `None` for other URIs or a malformed one.
+    ///
+    /// Both the base64 form and the percent-encoded form are decoded. The media type keeps its parameters, e.g. `charset`, and defaults to `text/plain;charset=US-ASCII`.
+    ///
+    /// ```rust
+    /// use vcard::{mime, values::Uri};
+    ///
+    /// let uri = Uri::from_data(&mime::IMAGE_PNG, b"\x89PNG");
+    ///
+    /// assert_eq!(Some((mime::IMAGE_PNG, b"\x89PNG".to_vec())), uri.data());
+    ///
+    /// let uri: Uri =
+    ///     "data:text/plain;charset=utf-8,Hello%2C%20World".parse().unwrap();
+    /// let (mime, data) = uri.data().unwrap();
+    ///
+    /// assert_eq!(Some(mime::UTF_8), mime.get_param(mime::CHARSET));
+    /// assert_eq!(b"Hello, World", data.as_slice());
+    /// ```
+    pub fn data(&self) -> Option<(Mime, Vec<u8>)> {
+        if self.0.scheme() != "data" {
+            return None;
+        }
+
+        let (meta, data) = self.0.path().split_once(',')?;
+
+        let meta = percent_encoding::percent_decode_str(meta).decode_utf8().ok()?;
+
+        let (meta, base64) = match meta.rsplit_once(';') {
+            Some((meta, token)) if token.trim().eq_ignore_ascii_case("base64") => (meta, true),
+            _ => (meta.as_ref(), false),
+        };
+
+        let mime = if meta.is_empty() {
+            Mime::from_str("text/plain;charset=US-ASCII").ok()?
+        } else if meta.starts_with(';') {
+            // Parameters without a type, e.g. `data:;charset=utf-8,...`, apply to `text/plain`.
+            Mime::from_str(&format!("text/plain{meta}")).ok()?
+        } else {
+            Mime::from_str(meta).ok()?
+        };
+
+        // A question mark belongs to the data, while a fragment identifier does not.
+        let mut encoded = data.to_string();
+
+        if let Some(query) = self.0.query() {
+            encoded.push('?');
+            encoded.push_str(query);
+        }
+
+        let bytes: Vec<u8> = percent_encoding::percent_decode_str(&encoded).collect();
+
+        if !base64 {
+            return Some((mime, bytes));
+        }
+
+        // Folded or hand-written base64 can contain whitespace and can omit the padding.
+        let bytes: Vec<u8> = bytes.into_iter().filter(|b| !b.is_ascii_whitespace()).collect();
+
+        BASE64.decode(bytes).ok().map(|data| (mime, data))
+    }
+
+    /// Checks whether this is a `data:` URI.
+    #[inline]
+    pub fn is_data(&self) -> bool {
+        self.0.scheme() == "data"
+    }
+
     /// Returns the URI as a string slice.
     #[inline]
     pub fn as_str(&self) -> &str {
@@ -9,6 +9,7 @@ use std::{
 use crate::{
     error::ValidationError,
     fold::FoldingWriter,
+    projection::is_projected,
     property::{
         Address, Anniversary, Birthday, CalendarAddressUri, CalendarUri, Categories, ClientPidMap,
         Created, Email, ExtensionProperty, Fburl, FormattedName, Gender, Geo, GramGender, Impp,
@@ -179,7 +180,7 @@ pub struct VCard {
 impl VCard {
     /// Creates a vCard with a single FN property built from the given name.
     ///
-    /// Unlike older versions of this crate, the REV property is no longer set automatically.
+    /// Unlike older versions of this crate, the REV property is no longer set automatically, but [`TrackedVCard`](crate::TrackedVCard) can maintain it.
     #[inline]
     pub fn new<S: Into<String>>(formatted_name: S) -> Self {
         Self {
@@ -226,8 +227,9 @@ macro_rules! write_field {
     };
 }
 
-impl Display for VCard {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+impl VCard {
+    /// Writes this vCard, keeping only the properties in the projection when one is given.
+    pub(crate) fn write(&self, f: &mut dyn fmt::Write, projection: Option<&[&str]>) -> fmt::Result {
         let mut w = FoldingWriter::new(f);
 
         w.write_str("BEGIN:VCARD")?;
@@ -238,17 +240,30 @@ impl Display for VCard {
 
         macro_rules! write_fields {
             ($(($field:ident, $name:literal, $card:tt)),* $(,)?) => {
-                $(write_field!($card, self, $field, $name, &mut w);)*
+                $(
+                    if projection.is_none_or(|names| is_projected(names, $name)) {
+                        write_field!($card, self, $field, $name, &mut w);
+                    }
+                )*
             };
         }
 
         for_each_property!(write_fields);
 
         for extension in &self.extensions {
-            extension.write(&mut w)?;
+            if projection.is_none_or(|names| is_projected(names, extension.name.as_str())) {
+                extension.write(&mut w)?;
+            }
         }
 
         w.write_str("END:VCARD")?;
         w.end_line()
     }
 }
+
+impl Display for VCard {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.write(f, None)
+    }
+}
@@ -0,0 +1,95 @@
+use vcard::{
+    Address,
+    values::{AddressFormat, AddressValue},
+};
+
+fn strings(items: &[&str]) -> Vec<String> {
+    items.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn structured_components() {
+    let address = AddressValue {
+        street_names: strings(&["Unter den Linden"]),
+        street_numbers: strings(&["77"]),
+        floors: strings(&["3. OG"]),
+        localities: strings(&["Berlin"]),
+        postal_codes: strings(&["10117"]),
+        countries: strings(&["Deutschland"]),
+        ..AddressValue::default()
+    };
+
+    assert_eq!("Unter den Linden 77\n3. OG\n10117 Berlin\nDEUTSCHLAND", address.format_label());
+}
+
+#[test]
+fn legacy_components() {
+    let address = AddressValue {
+        extended_addresses: strings(&["Suite 100"]),
+        street_addresses: strings(&["1 Infinite Loop"]),
+        localities: strings(&["Cupertino"]),
+        postal_codes: strings(&["95014"]),
+        countries: strings(&["USA"]),
+        ..AddressValue::default()
+    };
+
+    // The missing region drops the comma after the city.
+    assert_eq!("Suite 100\n1 Infinite Loop\nCUPERTINO 95014\nUSA", address.format_label());
+
+    let address = AddressValue {
+        street_addresses: strings(&["市府路1號"]),
+        localities: strings(&["信義區"]),
+        regions: strings(&["臺北市"]),
+        postal_codes: strings(&["110"]),
+        countries: strings(&["TW"]),
+        ..AddressValue::default()
+    };
+
+    assert_eq!("110\n臺北市信義區\n市府路1號\nTAIWAN", address.format_label());
+}
+
+#[test]
+fn templates() {
+    let japan = AddressFormat::for_country("jp").unwrap();
+    assert_eq!("JAPAN", japan.name());
+
+    let address = AddressValue {
+        street_addresses: strings(&["千代田1-1"]),
+        localities: strings(&["千代田区"]),
+        regions: strings(&["東京都"]),
+        postal_codes: strings(&["100-8111"]),
+        ..AddressValue::default()
+    };
+
+    assert_eq!("〒100-8111\n東京都千代田区\n千代田1-1", japan.format(&address, None));
+
+    assert_eq!("CH", AddressFormat::for_country("Suisse").unwrap().code());
+    assert_eq!(None, AddressFormat::for_country("Atlantis"));
+
+    let address = AddressValue {
+        street_addresses: strings(&["1 Main Street"]),
+        localities: strings(&["Poseidonia"]),
+        countries: strings(&["Atlantis"]),
+        ..AddressValue::default()
+    };
+
+    assert_eq!("1 Main Street\nPoseidonia\nATLANTIS", address.format_label());
+}
+
+#[test]
+fn generate_label() {
+    let mut address = Address::new(AddressValue {
+        street_addresses: strings(&["10 Downing Street"]),
+        localities: strings(&["London"]),
+        postal_codes: strings(&["SW1A 2AA"]),
+        countries: strings(&["GB"]),
+        ..AddressValue::default()
+    });
+
+    address.generate_label();
+
+    assert_eq!(
+        Some("10 Downing Street\nLONDON\nSW1A 2AA\nUNITED KINGDOM"),
+        address.parameters.label.as_deref()
+    );
+}
@@ -0,0 +1,153 @@
+use vcard::values::AddressValue;
+
+fn strings(items: &[&str]) -> Vec<String> {
+    items.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn postal_code_after_locality() {
+    let parsed = AddressValue::parse_free_text("123 Main St Apt 4B\nSpringfield, IL 62704\nUSA");
+
+    let address = parsed.address;
+
+    assert_eq!(strings(&["123 Main St"]), address.street_addresses);
+    assert_eq!(strings(&["123"]), address.street_numbers);
+    assert_eq!(strings(&["Main St"]), address.street_names);
+    assert_eq!(strings(&["Apt 4B"]), address.apartments);
+    assert_eq!(strings(&["Springfield"]), address.localities);
+    assert_eq!(strings(&["IL"]), address.regions);
+    assert_eq!(strings(&["62704"]), address.postal_codes);
+    assert_eq!(strings(&["USA"]), address.countries);
+    assert!(parsed.confidence > 0.9);
+
+    let parsed =
+        AddressValue::parse_free_text("10 Downing Street, London, SW1A 2AA, United Kingdom");
+
+    assert_eq!(strings(&["10"]), parsed.address.street_numbers);
+    assert_eq!(strings(&["Downing Street"]), parsed.address.street_names);
+    assert_eq!(strings(&["London"]), parsed.address.localities);
+    assert_eq!(strings(&["SW1A 2AA"]), parsed.address.postal_codes);
+
+    let parsed = AddressValue::parse_free_text("Suite 400, 55 King St W, Toronto ON M5K 1E7");
+
+    assert_eq!(strings(&["Suite 400"]), parsed.address.rooms);
+    assert_eq!(strings(&["55"]), parsed.address.street_numbers);
+    assert_eq!(strings(&["Toronto"]), parsed.address.localities);
+    assert_eq!(strings(&["ON"]), parsed.address.regions);
+    assert_eq!(strings(&["M5K 1E7"]), parsed.address.postal_codes);
+}
+
+#[test]
+fn postal_code_before_locality() {
+    let parsed = AddressValue::parse_free_text("Unter den Linden 77, 10117 Berlin, Deutschland");
+
+    let address = parsed.address;
+
+    assert_eq!(strings(&["77"]), address.street_numbers);
+    assert_eq!(strings(&["Unter den Linden"]), address.street_names);
+    assert_eq!(strings(&["Berlin"]), address.localities);
+    assert_eq!(strings(&["10117"]), address.postal_codes);
+    assert_eq!(strings(&["Deutschland"]), address.countries);
+    assert!(parsed.confidence > 0.9);
+
+    let parsed = AddressValue::parse_free_text("Via del Corso 12\n00186 Roma RM\nItalia");
+
+    assert_eq!(strings(&["Roma"]), parsed.address.localities);
+    assert_eq!(strings(&["RM"]), parsed.address.regions);
+
+    let parsed = AddressValue::parse_free_text("Damrak 1, 1012 LG Amsterdam");
+
+    assert_eq!(strings(&["1012 LG"]), parsed.address.postal_codes);
+    assert_eq!(strings(&["Amsterdam"]), parsed.address.localities);
+}
+
+#[test]
+fn country_rules() {
+    // Without a country, five digits before the locality are read as a German-style postal code.
+    let guessed = AddressValue::parse_free_text("Hauptstraße 5, 80331 München");
+
+    assert_eq!(strings(&["80331"]), guessed.address.postal_codes);
+    assert_eq!(strings(&["München"]), guessed.address.localities);
+
+    let given = AddressValue::parse_free_text_in("Hauptstraße 5, 80331 München", "DE");
+
+    assert_eq!(guessed.address, given.address);
+    assert!(given.confidence > guessed.confidence);
+
+    // A street number alone is not taken for a postal code.
+    let parsed = AddressValue::parse_free_text("1600 Amphitheatre Parkway, Mountain View");
+
+    assert!(parsed.address.postal_codes.is_empty());
+    assert_eq!(strings(&["1600"]), parsed.address.street_numbers);
+}
+
+#[test]
+fn region_or_country() {
+    // A US address without a country ends with the state, not the country code of Canada.
+    let parsed = AddressValue::parse_free_text("1 Infinite Loop, Cupertino, CA");
+
+    assert!(parsed.address.countries.is_empty());
+    assert_eq!(strings(&["CA"]), parsed.address.regions);
+    assert_eq!(strings(&["Cupertino"]), parsed.address.localities);
+    assert_eq!(strings(&["Infinite Loop"]), parsed.address.street_names);
+
+    let parsed = AddressValue::parse_free_text("1 Infinite Loop, Cupertino, CA 95014, US");
+
+    assert_eq!(strings(&["US"]), parsed.address.countries);
+    assert_eq!(strings(&["CA"]), parsed.address.regions);
+
+    // A country code is kept when the format has no abbreviated regions or is that country.
+    let parsed = AddressValue::parse_free_text("Unter den Linden 77, 10117 Berlin, DE");
+
+    assert_eq!(strings(&["DE"]), parsed.address.countries);
+    assert!(parsed.address.regions.is_empty());
+
+    let parsed = AddressValue::parse_free_text("1 Yonge St, Toronto, ON M5E 1W7, CA");
+
+    assert_eq!(strings(&["CA"]), parsed.address.countries);
+    assert_eq!(strings(&["ON"]), parsed.address.regions);
+
+    let parsed = AddressValue::parse_free_text("Cupertino, CA");
+
+    assert!(parsed.address.countries.is_empty());
+    assert_eq!(strings(&["CA"]), parsed.address.regions);
+    assert_eq!(strings(&["Cupertino"]), parsed.address.localities);
+    assert!(parsed.address.street_names.is_empty());
+
+    // Countries without abbreviated regions keep their codes, even when a US state has the same one.
+    for (text, country, city) in [
+        ("Berlin, DE", "DE", "Berlin"),
+        ("London, GB", "GB", "London"),
+        ("Paris, FR", "FR", "Paris"),
+    ] {
+        let parsed = AddressValue::parse_free_text(text);
+
+        assert_eq!(strings(&[country]), parsed.address.countries, "{text}");
+        assert!(parsed.address.regions.is_empty(), "{text}");
+        assert_eq!(strings(&[city]), parsed.address.localities, "{text}");
+        assert!(parsed.address.street_names.is_empty(), "{text}");
+    }
+
+    let parsed = AddressValue::parse_free_text("Springfield, IL, US");
+
+    assert_eq!(strings(&["US"]), parsed.address.countries);
+    assert_eq!(strings(&["IL"]), parsed.address.regions);
+    assert_eq!(strings(&["Springfield"]), parsed.address.localities);
+    assert!(parsed.address.street_names.is_empty());
+
+    let parsed = AddressValue::parse_free_text("Toronto, ON, CA");
+
+    assert_eq!(strings(&["CA"]), parsed.address.countries);
+    assert_eq!(strings(&["ON"]), parsed.address.regions);
+    assert_eq!(strings(&["Toronto"]), parsed.address.localities);
+}
+
+#[test]
+fn low_confidence() {
+    assert_eq!(0.0, AddressValue::parse_free_text("").confidence);
+
+    let parsed = AddressValue::parse_free_text("somewhere over the rainbow");
+
+    assert_eq!(strings(&["somewhere over the rainbow"]), parsed.address.street_addresses);
+    assert!(parsed.confidence < 0.5);
+}
@@ -0,0 +1,114 @@
+use vcard::{FormattedName, Title, VCard};
+
+const MULTILINGUAL: &str = concat!(
+    "BEGIN:VCARD\r\n",
+    "VERSION:4.0\r\n",
+    "LANGUAGE:en\r\n",
+    "FN;ALTID=1:Magic Len\r\n",
+    "FN;ALTID=1;LANGUAGE=zh-Hant:萌萌哒\r\n",
+    "FN;ALTID=1;LANGUAGE=ja:マジック・レン\r\n",
+    "N;ALTID=2;LANGUAGE=zh-Hant:萌;萌哒;;;\r\n",
+    "N;ALTID=2;LANGUAGE=zh-Hant;PHONETIC=jyut;SCRIPT=Latn:mang4;mang4daat6;;;\r\n",
+    "N;ALTID=2;LANGUAGE=zh-Hant;PHONETIC=piny;SCRIPT=Latn:meng2;meng2da1;;;\r\n",
+    "TITLE:Engineer\r\n",
+    "END:VCARD\r\n",
+);
+
+#[test]
+fn grouping() {
+    let vcard: VCard = MULTILINGUAL.parse().unwrap();
+
+    let groups = VCard::group_by_altid(&vcard.formatted_names);
+
+    assert_eq!(1, groups.len());
+    assert_eq!(3, groups[0].len());
+
+    assert_eq!(1, VCard::group_by_altid(&vcard.names).len());
+    assert_eq!(1, VCard::group_by_altid(&vcard.titles).len());
+}
+
+#[test]
+fn language_lookup() {
+    let vcard: VCard = MULTILINGUAL.parse().unwrap();
+
+    let fn_for =
+        |languages: &[&str]| vcard.localized(&vcard.formatted_names, languages)[0].value.clone();
+
+    assert_eq!("萌萌哒", fn_for(&["zh-Hant-TW"]));
+    assert_eq!("萌萌哒", fn_for(&["ZH-hant"]));
+    assert_eq!("マジック・レン", fn_for(&["fr", "ja-JP"]));
+    // The property without LANGUAGE has the language of the vCard.
+    assert_eq!("Magic Len", fn_for(&["en-US"]));
+    // `zh` is not a prefix match of `zh-Hant`.
+    assert_eq!("Magic Len", fn_for(&["zh"]));
+    assert_eq!("Magic Len", fn_for(&[]));
+
+    let language = vcard.language_of(&vcard.formatted_names[0]).unwrap();
+
+    assert_eq!("en", language.as_str());
+
+    // The phonetic names are never chosen as the display name.
+    let names = vcard.localized(&vcard.names, &["zh-Hant"]);
+
+    assert_eq!(1, names.len());
+    assert_eq!(None, names[0].parameters.phonetic);
+}
+
+#[test]
+fn phonetic_names() {
+    let vcard: VCard = MULTILINGUAL.parse().unwrap();
+
+    let name = vcard.localized(&vcard.names, &["zh-Hant"])[0];
+    let phonetic = vcard.phonetic_of(&vcard.names, name).unwrap();
+
+    assert_eq!("jyut", phonetic.parameters.phonetic.as_ref().unwrap().to_string().to_lowercase());
+    assert_eq!("Latn", phonetic.parameters.script.as_ref().unwrap().as_str());
+
+    assert_eq!(None, vcard.phonetic_of(&vcard.formatted_names, &vcard.formatted_names[0]));
+}
+
+#[test]
+fn adding_alternatives() {
+    let mut vcard = VCard::new("Magic Len");
+
+    vcard.titles.push(Title::new(String::from("Engineer")));
+    vcard.titles.push(Title::new(String::from("Manager")));
+
+    let mut title = Title::new(String::from("經理"));
+
+    title.parameters.language = Some("zh-Hant".parse().unwrap());
+
+    assert_eq!("1", VCard::add_alternative(&mut vcard.titles, 1, title));
+
+    let mut title = Title::new(String::from("工程師"));
+
+    title.parameters.language = Some("zh-Hant".parse().unwrap());
+
+    assert_eq!("2", VCard::add_alternative(&mut vcard.titles, 0, title));
+
+    let titles: Vec<&str> = vcard.titles.iter().map(|title| title.value.as_str()).collect();
+
+    // Each alternative follows the last one of its group.
+    assert_eq!(["Engineer", "工程師", "Manager", "經理"], titles.as_slice());
+
+    let mut name = FormattedName::new(String::from("萌萌哒"));
+
+    name.parameters.language = Some("zh-Hant".parse().unwrap());
+
+    // An existing ALTID is reused.
+    vcard.formatted_names[0].parameters.altid = Some(String::from("a"));
+
+    assert_eq!("a", VCard::add_alternative(&mut vcard.formatted_names, 0, name));
+
+    let parsed: VCard = vcard.to_string().parse().unwrap();
+
+    assert_eq!(parsed, vcard);
+
+    let titles: Vec<&str> = parsed
+        .localized(&parsed.titles, &["zh-Hant"])
+        .iter()
+        .map(|title| title.value.as_str())
+        .collect();
+
+    assert_eq!(["工程師", "經理"], titles.as_slice());
+}
@@ -0,0 +1,92 @@
+use vcard::{
+    Calscale,
+    chrono::NaiveDate,
+    values::{CalendarDate, CalendarSystem, Date, DateAndOrTime},
+};
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn round_trip(calendar: CalendarSystem, gregorian: NaiveDate, expected: (i32, u8, bool, u8)) {
+    let converted = CalendarDate::from_gregorian(gregorian, calendar).unwrap();
+
+    assert_eq!(
+        expected,
+        (converted.year(), converted.month(), converted.is_leap_month(), converted.day()),
+        "{calendar} {gregorian}"
+    );
+
+    let (year, month, leap_month, day) = expected;
+
+    assert_eq!(
+        Some(gregorian),
+        CalendarDate::new(calendar, year, month, leap_month, day).unwrap().to_gregorian(),
+        "{calendar} {gregorian}"
+    );
+}
+
+#[test]
+fn chinese() {
+    round_trip(CalendarSystem::Chinese, date(2025, 1, 29), (2025, 1, false, 1));
+    round_trip(CalendarSystem::Chinese, date(2025, 1, 28), (2024, 12, false, 29));
+    round_trip(CalendarSystem::Chinese, date(2024, 9, 17), (2024, 8, false, 15));
+    round_trip(CalendarSystem::Chinese, date(2023, 3, 22), (2023, 2, true, 1));
+    round_trip(CalendarSystem::Chinese, date(2023, 4, 20), (2023, 3, false, 1));
+    round_trip(CalendarSystem::Chinese, date(2020, 5, 23), (2020, 4, true, 1));
+    round_trip(CalendarSystem::Chinese, date(1985, 2, 20), (1985, 1, false, 1));
+
+    assert!(CalendarDate::new(CalendarSystem::Chinese, 2024, 2, true, 1).is_err());
+    assert_eq!(Some(29), CalendarSystem::Chinese.days_in_month(2023, 2, true));
+}
+
+#[test]
+fn hebrew() {
+    round_trip(CalendarSystem::Hebrew, date(2025, 9, 23), (5786, 1, false, 1));
+    round_trip(CalendarSystem::Hebrew, date(2024, 10, 3), (5785, 1, false, 1));
+    round_trip(CalendarSystem::Hebrew, date(2025, 4, 13), (5785, 7, false, 15));
+    // 5784 is a leap year, so Purim is in Adar II and Adar I is the leap month.
+    round_trip(CalendarSystem::Hebrew, date(2024, 3, 24), (5784, 6, false, 14));
+    round_trip(CalendarSystem::Hebrew, date(2024, 2, 23), (5784, 5, true, 14));
+    round_trip(CalendarSystem::Hebrew, date(2025, 3, 14), (5785, 6, false, 14));
+
+    assert!(CalendarDate::new(CalendarSystem::Hebrew, 5785, 5, true, 1).is_err());
+}
+
+#[test]
+fn islamic_and_persian() {
+    round_trip(CalendarSystem::IslamicCivil, date(622, 7, 19), (1, 1, false, 1));
+    // The tabular calendar can be a day off the sighted or the Umm al-Qura calendar, which start 1446 on July 7.
+    round_trip(CalendarSystem::IslamicCivil, date(2024, 7, 8), (1446, 1, false, 1));
+    round_trip(CalendarSystem::IslamicCivil, date(2024, 7, 7), (1445, 12, false, 30));
+
+    round_trip(CalendarSystem::Persian, date(2025, 3, 21), (1404, 1, false, 1));
+    round_trip(CalendarSystem::Persian, date(2024, 3, 20), (1403, 1, false, 1));
+    round_trip(CalendarSystem::Persian, date(2025, 3, 20), (1403, 12, false, 30));
+    round_trip(CalendarSystem::Persian, date(2025, 9, 23), (1404, 7, false, 1));
+
+    assert_eq!(Some(29), CalendarSystem::Persian.days_in_month(1404, 12, false));
+}
+
+#[test]
+fn calscale() {
+    let hebrew = "hebrew".parse::<Calscale>().unwrap();
+
+    assert_eq!(Some(CalendarSystem::Hebrew), CalendarSystem::from_calscale(Some(&hebrew)));
+    assert_eq!(hebrew, CalendarSystem::Hebrew.to_calscale());
+    assert_eq!(Some(CalendarSystem::Gregorian), CalendarSystem::from_calscale(None));
+    assert_eq!(
+        None,
+        CalendarSystem::from_calscale(Some(&"x-unknown".parse::<Calscale>().unwrap()))
+    );
+    assert_eq!("ISLAMIC-CIVIL", CalendarSystem::IslamicCivil.rscale());
+
+    let birthday = Date::from_year_month_day(1404, 1, 1).unwrap();
+
+    assert_eq!(Some(date(2025, 3, 21)), birthday.to_gregorian(CalendarSystem::Persian));
+    assert_eq!(Ok(birthday), Date::from_gregorian(date(2025, 3, 21), CalendarSystem::Persian));
+    assert_eq!(
+        Some(DateAndOrTime::Date(Date::from_year_month_day(2025, 3, 21).unwrap())),
+        DateAndOrTime::Date(birthday).to_gregorian(CalendarSystem::Persian)
+    );
+}
@@ -0,0 +1,183 @@
+#![cfg(feature = "carddav")]
+
+use std::io;
+
+use vcard::{
+    VCard,
+    carddav::{CardDavError, Client, Request, Response},
+    url::Url,
+};
+
+/// Builds a multistatus response from the inner XML.
+fn multistatus(inner: &str) -> Response {
+    let mut response = Response::new(207);
+
+    response.body = format!(
+        "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<D:multistatus xmlns:D=\"DAV:\" \
+         xmlns:C=\"urn:ietf:params:xml:ns:carddav\">{inner}</D:multistatus>"
+    )
+    .into_bytes();
+
+    response
+}
+
+/// A mock server that answers by method and path, like a tiny CardDAV server would.
+fn mock_server(request: Request) -> Result<Response, io::Error> {
+    let url = Url::parse(&request.target).unwrap();
+    let body = String::from_utf8(request.body).unwrap();
+
+    Ok(match (request.method.as_str(), url.path()) {
+        ("PROPFIND", "/") => {
+            assert!(body.contains("current-user-principal"));
+
+            multistatus(
+                "<D:response><D:href>/</D:href><D:propstat><D:prop><D:current-user-principal><D:\
+                 href>/principals/len/</D:href></D:current-user-principal></D:prop><D:status>HTTP/\
+                 1.1 200 OK</D:status></D:propstat></D:response>",
+            )
+        },
+        ("PROPFIND", "/principals/len/") => multistatus(
+            "<D:response><D:href>/principals/len/</D:href><D:propstat><D:prop><C:\
+             addressbook-home-set><D:href>/addressbooks/len/</D:href></C:addressbook-home-set></D:\
+             prop><D:status>HTTP/1.1 200 OK</D:status></D:propstat></D:response>",
+        ),
+        ("PROPFIND", "/addressbooks/len/") => multistatus(
+            "<D:response><D:href>/addressbooks/len/</D:href><D:propstat><D:prop><D:\
+             resourcetype><D:collection/></D:resourcetype></D:prop><D:status>HTTP/1.1 200 \
+             OK</D:status></D:propstat></D:response><D:response><D:href>/addressbooks/len/\
+             contacts/</D:href><D:propstat><D:prop><D:resourcetype><D:collection/><C:addressbook/\
+             ></D:resourcetype><D:displayname>Contacts &amp; \
+             Friends</D:displayname><D:sync-token>token-1</D:sync-token></D:prop><D:status>HTTP/1.\
+             1 200 OK</D:status></D:propstat><D:propstat><D:prop><C:addressbook-description/></D:\
+             prop><D:status>HTTP/1.1 404 Not Found</D:status></D:propstat></D:response>",
+        ),
+        ("PROPFIND", "/addressbooks/len/contacts/") => {
+            assert_eq!(
+                Some("1"),
+                request.headers.iter().find(|(n, _)| n == "Depth").map(|(_, v)| v.as_str())
+            );
+
+            multistatus(
+                "<D:response><D:href>/addressbooks/len/contacts/</D:href><D:propstat><D:prop><D:\
+                 resourcetype><D:collection/><C:addressbook/></D:resourcetype></D:prop><D:\
+                 status>HTTP/1.1 200 \
+                 OK</D:status></D:propstat></D:response><D:response><D:href>/addressbooks/len/\
+                 contacts/a.vcf</D:href><D:propstat><D:prop><D:resourcetype/><D:getetag>\"1\"</D:\
+                 getetag></D:prop><D:status>HTTP/1.1 200 OK</D:status></D:propstat></D:response>",
+            )
+        },
+        ("REPORT", "/addressbooks/len/contacts/") if body.contains("addressbook-multiget") => {
+            assert!(body.contains("<d:href>/addressbooks/len/contacts/a.vcf</d:href>"));
+
+            multistatus(
+                "<D:response><D:href>/addressbooks/len/contacts/a.vcf</D:href><D:propstat><D:\
+                 prop><D:getetag>\"1\"</D:getetag><C:address-data><![CDATA[BEGIN:VCARD\r\nVERSION:\
+                 4.0\r\nFN:Magic \
+                 Len\r\nEND:VCARD\r\n]]></C:address-data></D:prop><D:status>HTTP/1.1 200 \
+                 OK</D:status></D:propstat></D:response>",
+            )
+        },
+        ("REPORT", "/addressbooks/len/contacts/") => {
+            assert!(body.contains("<d:sync-token>token-1</d:sync-token>"));
+
+            multistatus(
+                "<D:response><D:href>/addressbooks/len/contacts/b.vcf</D:href><D:propstat><D:\
+                 prop><D:getetag>\"7\"</D:getetag></D:prop><D:status>HTTP/1.1 200 \
+                 OK</D:status></D:propstat></D:response><D:response><D:href>/addressbooks/len/\
+                 contacts/a.vcf</D:href><D:status>HTTP/1.1 404 Not \
+                 Found</D:status></D:response><D:sync-token>token-2</D:sync-token>",
+            )
+        },
+        ("PUT", "/addressbooks/len/contacts/a.vcf") => {
+            let if_match = request.headers.iter().find(|(n, _)| n == "If-Match");
+
+            if if_match.is_some_and(|(_, etag)| etag == "\"1\"") {
+                let mut response = Response::new(204);
+                response.headers.push((String::from("ETag"), String::from("\"2\"")));
+                response
+            } else {
+                Response::new(412)
+            }
+        },
+        _ => Response::new(404),
+    })
+}
+
+fn client() -> Client<fn(Request) -> Result<Response, io::Error>> {
+    Client::new(mock_server, Url::parse("https://dav.example.com/").unwrap())
+}
+
+#[test]
+fn discovery() {
+    let mut client = client();
+
+    let principal = client.current_user_principal().unwrap();
+    assert_eq!("https://dav.example.com/principals/len/", principal.as_str());
+
+    let home = client.address_book_home_set(&principal).unwrap();
+    assert_eq!("https://dav.example.com/addressbooks/len/", home.as_str());
+
+    let address_books = client.address_books(&home).unwrap();
+    assert_eq!(1, address_books.len());
+    assert_eq!("https://dav.example.com/addressbooks/len/contacts/", address_books[0].url.as_str());
+    assert_eq!(Some("Contacts & Friends"), address_books[0].display_name.as_deref());
+    assert_eq!(Some("token-1"), address_books[0].sync_token.as_deref());
+    assert_eq!(None, address_books[0].description);
+}
+
+#[test]
+fn list_and_multiget() {
+    let mut client = client();
+
+    let address_book = Url::parse("https://dav.example.com/addressbooks/len/contacts/").unwrap();
+
+    let resources = client.list(&address_book).unwrap();
+    assert_eq!(1, resources.len());
+    assert_eq!(
+        "https://dav.example.com/addressbooks/len/contacts/a.vcf",
+        resources[0].url.as_str()
+    );
+    assert_eq!(Some("\"1\""), resources[0].etag.as_deref());
+
+    let urls: Vec<Url> = resources.into_iter().map(|resource| resource.url).collect();
+
+    let objects = client.multiget(&address_book, &urls).unwrap();
+    assert_eq!(1, objects.len());
+    assert_eq!("Magic Len", objects[0].vcard.formatted_names[0].value);
+}
+
+#[test]
+fn put_with_etag() {
+    let mut client = client();
+
+    let url = Url::parse("https://dav.example.com/addressbooks/len/contacts/a.vcf").unwrap();
+
+    let vcard = VCard::new("Len");
+
+    assert_eq!(Some(String::from("\"2\"")), client.put(&url, &vcard, Some("\"1\"")).unwrap());
+
+    assert!(matches!(
+        client.put(&url, &vcard, Some("\"0\"")),
+        Err(CardDavError::PreconditionFailed)
+    ));
+}
+
+#[test]
+fn sync_collection() {
+    let mut client = client();
+
+    let address_book = Url::parse("https://dav.example.com/addressbooks/len/contacts/").unwrap();
+
+    let changes = client.sync_collection(&address_book, Some("token-1")).unwrap();
+
+    assert_eq!("token-2", changes.sync_token);
+    assert_eq!(1, changes.changed.len());
+    assert_eq!(
+        "https://dav.example.com/addressbooks/len/contacts/b.vcf",
+        changes.changed[0].url.as_str()
+    );
+    assert_eq!(
+        ["https://dav.example.com/addressbooks/len/contacts/a.vcf"],
+        changes.removed.iter().map(Url::as_str).collect::<Vec<_>>().as_slice()
+    );
+}
@@ -0,0 +1,285 @@
+#![cfg(feature = "carddav")]
+
+use std::{fs, io};
+
+use vcard::{
+    VCard,
+    carddav::{
+        CardDavError, Client, FileSystemStore, Handler, MemoryStore, Request, Response, Store,
+    },
+    url::Url,
+};
+
+fn handler() -> Handler<MemoryStore> {
+    let mut store = MemoryStore::new();
+
+    store.create_address_book("contacts");
+
+    Handler::new(store, "/dav")
+}
+
+/// Connects a client to a handler in the same process.
+fn client(
+    mut handler: Handler<MemoryStore>,
+) -> Client<impl FnMut(Request) -> Result<Response, io::Error>> {
+    Client::new(
+        move |request| Ok(handler.handle(request)),
+        Url::parse("https://dav.example.com/dav/").unwrap(),
+    )
+}
+
+fn put(handler: &mut Handler<MemoryStore>, target: &str, body: &str) -> Response {
+    let mut request = Request::new("PUT", target);
+
+    request.headers.push((String::from("Content-Type"), String::from("text/vcard")));
+    request.body = body.as_bytes().to_vec();
+
+    handler.handle(request)
+}
+
+#[test]
+fn client_round_trip() {
+    let mut client = client(handler());
+
+    let principal = client.current_user_principal().unwrap();
+    let home = client.address_book_home_set(&principal).unwrap();
+    assert_eq!("https://dav.example.com/dav/", home.as_str());
+
+    let address_books = client.address_books(&home).unwrap();
+    assert_eq!(1, address_books.len());
+    assert_eq!("https://dav.example.com/dav/contacts/", address_books[0].url.as_str());
+    assert_eq!(Some("contacts"), address_books[0].display_name.as_deref());
+
+    let url = address_books[0].url.join("len.vcf").unwrap();
+
+    let etag = client.put(&url, &VCard::new("Magic Len"), None).unwrap().unwrap();
+
+    assert!(matches!(
+        client.put(&url, &VCard::new("Len"), None),
+        Err(CardDavError::PreconditionFailed)
+    ));
+
+    let resources = client.list(&address_books[0].url).unwrap();
+    assert_eq!(1, resources.len());
+    assert_eq!(url, resources[0].url);
+    assert_eq!(Some(etag.as_str()), resources[0].etag.as_deref());
+
+    let objects = client.multiget(&address_books[0].url, std::slice::from_ref(&url)).unwrap();
+    assert_eq!(1, objects.len());
+    assert_eq!("Magic Len", objects[0].vcard.formatted_names[0].value);
+
+    let new_etag = client.put(&url, &VCard::new("Len"), Some(&etag)).unwrap().unwrap();
+    assert_ne!(etag, new_etag);
+
+    assert!(matches!(client.delete(&url, Some(&etag)), Err(CardDavError::PreconditionFailed)));
+
+    client.delete(&url, Some(&new_etag)).unwrap();
+
+    assert!(client.list(&address_books[0].url).unwrap().is_empty());
+}
+
+#[test]
+fn put_validation() {
+    let mut handler = handler();
+
+    let response = put(&mut handler, "/dav/contacts/a.vcf", "BEGIN:VCARD\r\nEND:VCARD\r\n");
+    assert_eq!(403, response.status);
+    assert!(String::from_utf8(response.body).unwrap().contains("valid-address-data"));
+
+    let response = put(&mut handler, "/dav/missing/a.vcf", &VCard::new("Len").to_string());
+    assert_eq!(409, response.status);
+
+    let response = put(&mut handler, "/dav/contacts/../a.vcf", &VCard::new("Len").to_string());
+    assert_eq!(404, response.status);
+
+    let response = put(&mut handler, "/dav/contacts/a.vcf", &VCard::new("Len").to_string());
+    assert_eq!(201, response.status);
+
+    let response = handler.handle(Request::new("GET", "/dav/contacts/a.vcf"));
+    assert_eq!(200, response.status);
+    assert_eq!(Some("text/vcard; charset=utf-8"), response.header("content-type"));
+    assert_eq!(VCard::new("Len").to_string().into_bytes(), response.body);
+
+    let with_uid = "BEGIN:VCARD\r\nVERSION:4.0\r\nFN:Len\r\nUID:urn:uuid:1\r\nEND:VCARD\r\n";
+
+    assert_eq!(201, put(&mut handler, "/dav/contacts/b.vcf", with_uid).status);
+    // Updating the same resource is not a conflict.
+    assert_eq!(204, put(&mut handler, "/dav/contacts/b.vcf", with_uid).status);
+
+    let response = put(&mut handler, "/dav/contacts/c.vcf", with_uid);
+    assert_eq!(409, response.status);
+    assert!(
+        String::from_utf8(response.body).unwrap().contains(
+            "<C:no-uid-conflict><D:href>/dav/contacts/b.vcf</D:href></C:no-uid-conflict>"
+        )
+    );
+    assert_eq!(404, handler.handle(Request::new("GET", "/dav/contacts/c.vcf")).status);
+
+    let etag = handler
+        .handle(Request::new("GET", "/dav/contacts/a.vcf"))
+        .header("ETag")
+        .unwrap()
+        .to_string();
+
+    let conditional_put = |handler: &mut Handler<MemoryStore>, name: &str, value: String| {
+        let mut request = Request::new("PUT", "/dav/contacts/a.vcf");
+
+        request.headers.push((String::from(name), value));
+        request.body = VCard::new("Len").to_string().into_bytes();
+
+        handler.handle(request).status
+    };
+
+    // If-Match uses the strong comparison, so a weak tag never matches.
+    assert_eq!(412, conditional_put(&mut handler, "If-Match", format!("W/{etag}")));
+    assert_eq!(204, conditional_put(&mut handler, "If-Match", etag.clone()));
+    // If-None-Match uses the weak comparison.
+    assert_eq!(412, conditional_put(&mut handler, "If-None-Match", format!("W/{etag}")));
+}
+
+#[test]
+fn reports() {
+    let mut handler = handler();
+
+    put(
+        &mut handler,
+        "/dav/contacts/a.vcf",
+        "BEGIN:VCARD\r\nVERSION:4.0\r\nFN:Len\r\nNOTE:Hi\r\nTEL:+886-912-345-678\r\nEND:VCARD\r\n",
+    );
+
+    let mut request = Request::new("REPORT", "/dav/contacts/");
+
+    request.body = b"<C:addressbook-query xmlns:D=\"DAV:\" \
+                     xmlns:C=\"urn:ietf:params:xml:ns:carddav\"><D:prop><D:getetag/><C:\
+                     address-data/><D:unknown/></D:prop></C:addressbook-query>"
+        .to_vec();
+
+    let response = handler.handle(request);
+    assert_eq!(207, response.status);
+
+    let body = String::from_utf8(response.body).unwrap();
+    assert!(body.contains("<D:href>/dav/contacts/a.vcf</D:href>"));
+    assert!(body.contains("FN:Len"));
+    assert!(body.contains("<D:unknown/></D:prop><D:status>HTTP/1.1 404 Not Found</D:status>"));
+
+    let mut request = Request::new("REPORT", "/dav/contacts/");
+
+    request.body = b"<C:addressbook-multiget xmlns:D=\"DAV:\" \
+                     xmlns:C=\"urn:ietf:params:xml:ns:carddav\"><D:href>/dav/contacts/b.vcf</D:\
+                     href></C:addressbook-multiget>"
+        .to_vec();
+
+    let body = String::from_utf8(handler.handle(request).body).unwrap();
+    assert!(body.contains(
+        "<D:href>/dav/contacts/b.vcf</D:href><D:status>HTTP/1.1 404 Not Found</D:status>"
+    ));
+
+    let mut request = Request::new("REPORT", "/dav/contacts/");
+
+    request.body = b"<C:addressbook-multiget xmlns:D=\"DAV:\" \
+                     xmlns:C=\"urn:ietf:params:xml:ns:carddav\"><D:prop><C:address-data><C:prop \
+                     name=\"TEL\"/></C:address-data></D:prop><D:href>/dav/contacts/a.vcf</D:\
+                     href></C:addressbook-multiget>"
+        .to_vec();
+
+    let body = String::from_utf8(handler.handle(request).body).unwrap();
+    assert!(body.contains("FN:Len\r\nTEL:+886-912-345-678\r\nEND:VCARD"));
+
+    let response = handler.handle(Request::new("OPTIONS", "/dav/"));
+    assert_eq!(Some("1, 3, addressbook"), response.header("DAV"));
+}
+
+#[test]
+fn query_filter() {
+    let mut handler = handler();
+
+    put(&mut handler, "/dav/contacts/a.vcf", &VCard::new("Magic Len").to_string());
+    put(&mut handler, "/dav/contacts/b.vcf", &VCard::new("David Wang").to_string());
+
+    let query = |filter: &str| {
+        let mut request = Request::new("REPORT", "/dav/contacts/");
+
+        request.body = format!(
+            "<C:addressbook-query xmlns:D=\"DAV:\" \
+             xmlns:C=\"urn:ietf:params:xml:ns:carddav\"><D:prop><D:getetag/></D:prop>{filter}</C:\
+             addressbook-query>"
+        )
+        .into_bytes();
+
+        request
+    };
+
+    let response = handler.handle(query(
+        "<C:filter><C:prop-filter name=\"FN\"><C:text-match \
+         match-type=\"starts-with\">magic</C:text-match></C:prop-filter></C:filter>",
+    ));
+    assert_eq!(207, response.status);
+
+    let body = String::from_utf8(response.body).unwrap();
+    assert!(body.contains("/dav/contacts/a.vcf"));
+    assert!(!body.contains("/dav/contacts/b.vcf"));
+
+    let response = handler.handle(query(
+        "<C:filter><C:prop-filter name=\"FN\"><C:text-match \
+         collation=\"i;octet\">magic</C:text-match></C:prop-filter></C:filter>",
+    ));
+    assert_eq!(403, response.status);
+    assert!(String::from_utf8(response.body).unwrap().contains("supported-collation"));
+}
+
+#[test]
+fn file_system_store() {
+    let root = std::env::temp_dir().join(format!("vcard-carddav-{}", std::process::id()));
+
+    fs::create_dir_all(&root).unwrap();
+
+    let mut store = FileSystemStore::new(&root);
+
+    store.create_address_book("contacts").unwrap();
+
+    assert_eq!(vec![String::from("contacts")], store.address_books().unwrap());
+    assert_eq!(None, store.objects("missing").unwrap());
+
+    store.put("contacts", "a.vcf", VCard::new("Len").to_string()).unwrap();
+
+    assert_eq!(Some(vec![String::from("a.vcf")]), store.objects("contacts").unwrap());
+    assert_eq!(Some(VCard::new("Len").to_string()), store.get("contacts", "a.vcf").unwrap());
+
+    assert!(store.put("contacts", "../a.vcf", String::new()).is_err());
+
+    assert!(store.delete("contacts", "a.vcf").unwrap());
+    assert!(!store.delete("contacts", "a.vcf").unwrap());
+
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn malformed_bodies() {
+    let mut handler = handler();
+
+    let mut request = Request::new("PROPFIND", "/dav/contacts/");
+
+    request.body = format!("{}{}", "<a>".repeat(200_000), "</a>".repeat(200_000)).into_bytes();
+
+    assert_eq!(400, handler.handle(request).status);
+
+    // Names with characters outside NameChar would be written back as malformed XML.
+    let mut request = Request::new("PROPFIND", "/dav/contacts/");
+
+    request.body = b"<D:propfind xmlns:D=\"DAV:\"><D:prop><x:a&b<c \
+                     xmlns:x=\"urn:x\"/></D:prop></D:propfind>"
+        .to_vec();
+
+    assert_eq!(400, handler.handle(request).status);
+
+    let mut request = Request::new("PROPFIND", "/dav/contacts/");
+
+    request.body = b"<D:propfind xmlns:D=\"DAV:\"><D:prop><x:a-b.c \
+                     xmlns:x=\"urn:x\"/></D:prop></D:propfind>"
+        .to_vec();
+
+    let response = handler.handle(request);
+
+    assert_eq!(207, response.status);
+    assert!(String::from_utf8(response.body).unwrap().contains("a-b.c"));
+}
@@ -0,0 +1,135 @@
+use std::cmp::Ordering;
+
+use vcard::{
+    chrono::{FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc},
+    chrono_tz::America::New_York,
+    values::{Date, DateAndOrTime, DateTime, Time},
+};
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[test]
+fn date_conversions() {
+    let complete: Date = "19850412".parse().unwrap();
+
+    assert_eq!(Some(date(1985, 4, 12)), complete.to_naive_date());
+    assert_eq!(None, "--0412".parse::<Date>().unwrap().to_naive_date());
+
+    let default = date(2023, 1, 31);
+
+    assert_eq!(
+        Some(date(1985, 4, 30)),
+        "1985-04".parse::<Date>().unwrap().to_naive_date_or(date(2023, 1, 30))
+    );
+    assert_eq!(None, "1985-04".parse::<Date>().unwrap().to_naive_date_or(default));
+    assert_eq!(None, "--0229".parse::<Date>().unwrap().to_naive_date_or(default));
+    assert_eq!(Some(date(2023, 1, 12)), "---12".parse::<Date>().unwrap().to_naive_date_or(default));
+}
+
+#[test]
+fn time_conversions() {
+    let time: Time = "102200".parse().unwrap();
+
+    assert_eq!(NaiveTime::from_hms_opt(10, 22, 0), time.to_naive_time());
+    assert_eq!(None, "10".parse::<Time>().unwrap().to_naive_time());
+    assert_eq!(
+        NaiveTime::from_hms_opt(10, 0, 0),
+        "10".parse::<Time>().unwrap().to_naive_time_or(NaiveTime::MIN)
+    );
+
+    let leap_second: Time = "235960".parse().unwrap();
+    let naive = leap_second.to_naive_time().unwrap();
+
+    assert_eq!(leap_second, Time::from(naive));
+}
+
+#[test]
+fn date_time_conversions() {
+    let floating: DateTime = "20250412T143000".parse().unwrap();
+
+    assert_eq!(
+        Some(NaiveDateTime::new(date(2025, 4, 12), NaiveTime::from_hms_opt(14, 30, 0).unwrap())),
+        floating.to_naive_date_time()
+    );
+    assert_eq!(None, floating.to_fixed_offset());
+    assert_eq!(
+        New_York.with_ymd_and_hms(2025, 4, 12, 14, 30, 0).single(),
+        floating.to_date_time(&New_York)
+    );
+
+    // 02:30 does not exist on the day daylight saving time starts.
+    let skipped: DateTime = "20250309T023000".parse().unwrap();
+
+    assert_eq!(None, skipped.to_date_time(&New_York));
+
+    let offset: DateTime = "20250412T143000+0800".parse().unwrap();
+
+    assert_eq!(
+        Some(Utc.with_ymd_and_hms(2025, 4, 12, 6, 30, 0).unwrap()),
+        offset.to_date_time(&Utc)
+    );
+
+    let fixed =
+        FixedOffset::east_opt(8 * 3600).unwrap().with_ymd_and_hms(2025, 4, 12, 14, 30, 0).unwrap();
+
+    assert_eq!(offset, DateTime::try_from(fixed).unwrap());
+    assert_eq!(
+        "20250412T063000Z",
+        DateTime::try_from(fixed.with_timezone(&Utc)).unwrap().to_string()
+    );
+    assert!(DateTime::try_from(date(10000, 1, 1).and_hms_opt(0, 0, 0).unwrap()).is_err());
+
+    let reduced: DateTime = "--0412T14".parse().unwrap();
+    let default = NaiveDateTime::new(date(2025, 1, 1), NaiveTime::MIN);
+
+    assert_eq!(
+        Some(NaiveDateTime::new(date(2025, 4, 12), NaiveTime::from_hms_opt(14, 0, 0).unwrap())),
+        reduced.to_naive_date_time_or(default)
+    );
+    assert_eq!(
+        Some(NaiveDateTime::new(date(2025, 1, 1), NaiveTime::from_hms_opt(10, 22, 0).unwrap())),
+        "T1022".parse::<DateAndOrTime>().unwrap().to_naive_date_time_or(default)
+    );
+}
+
+#[test]
+fn ordering() {
+    let parse = |s: &str| s.parse::<Date>().unwrap();
+
+    assert!(parse("1985") < parse("1986-04"));
+    assert!(parse("19850412") < parse("19850413"));
+    assert!(parse("--0412") < parse("--0501"));
+    assert_eq!(None, parse("1985").partial_cmp(&parse("1985-04")));
+    assert_eq!(None, parse("--0412").partial_cmp(&parse("19850412")));
+    assert_eq!(Some(Ordering::Equal), parse("1985-04").partial_cmp(&parse("1985-04")));
+
+    let parse = |s: &str| s.parse::<Time>().unwrap();
+
+    assert!(parse("1022") < parse("1023"));
+    assert_eq!(None, parse("1022Z").partial_cmp(&parse("1022")));
+
+    let parse = |s: &str| s.parse::<DateTime>().unwrap();
+
+    assert!(parse("20250412T140000Z") < parse("20250412T230000+0800"));
+    assert!(parse("20250412T140000") < parse("20250412T140001"));
+    // The same instant written in two zones.
+    assert_eq!(None, parse("20250412T060000Z").partial_cmp(&parse("20250412T140000+0800")));
+
+    // Instants and truncated date-times are incomparable, which keeps the ordering transitive.
+    let (a, b, c) =
+        (parse("20250413T010000+1400"), parse("20250412T120000Z"), parse("20250412T13Z"));
+
+    assert!(a < b);
+    assert_eq!(None, b.partial_cmp(&c));
+    assert_eq!(None, a.partial_cmp(&c));
+    assert!(parse("20250412T12Z") < c);
+
+    let parse = |s: &str| s.parse::<DateAndOrTime>().unwrap();
+
+    assert!(parse("19850411T2359") < parse("19850412"));
+    assert_eq!(None, parse("19850412T1000").partial_cmp(&parse("19850412")));
+    assert_eq!(None, parse("T1000").partial_cmp(&parse("19850412")));
+    assert_eq!(None, parse("19850411T235900Z").partial_cmp(&parse("19850412")));
+}
@@ -0,0 +1,50 @@
+use vcard::values::EmailValue;
+
+fn email(s: &str) -> EmailValue {
+    s.parse().unwrap()
+}
+
+#[test]
+fn parts() {
+    let address = email("\"john doe\"@Example.COM");
+
+    assert_eq!("\"john doe\"", address.local_part());
+    assert_eq!("Example.COM", address.domain());
+    assert_eq!("example.com", address.ascii_domain());
+    assert!(!address.is_internationalized());
+
+    let address = email("用户@例子.广告");
+
+    assert!(address.is_internationalized());
+    assert_eq!("xn--fsqu00a.xn--4rr70v", address.ascii_domain());
+    assert_eq!("例子.广告", email("用户@xn--fsqu00a.xn--4rr70v").unicode_domain());
+}
+
+#[test]
+fn normalized_equality() {
+    assert_ne!(email("Foo@Example.COM"), email("Foo@example.com"));
+    assert!(email("Foo@Example.COM").eq_normalized(&email("Foo@example.com")));
+    assert!(email("len@Bücher.example").eq_normalized(&email("len@xn--bcher-kva.example")));
+
+    // Local parts may be case-sensitive, and composed and decomposed characters are the same.
+    assert!(!email("Foo@example.com").eq_normalized(&email("foo@example.com")));
+    assert!(email("jos\u{e9}@example.com").eq_normalized(&email("jose\u{301}@example.com")));
+}
+
+#[test]
+fn canonicalized() {
+    assert_eq!("johndoe@gmail.com", email("John.Doe+vcard@gmail.com").canonicalized().as_str());
+    assert_eq!("johndoe@gmail.com", email("johndoe@googlemail.com").canonicalized().as_str());
+    assert_eq!("j.doe@outlook.com", email("J.Doe+shop@Outlook.com").canonicalized().as_str());
+    // Yahoo disposable addresses may belong to someone else, so only the case is folded.
+    assert_eq!("jdoe-lists@yahoo.com", email("JDoe-Lists@yahoo.com").canonicalized().as_str());
+
+    // Unknown providers keep the tags and the case of the local part.
+    assert_eq!("J.Doe+tag@example.com", email("J.Doe+tag@EXAMPLE.com").canonicalized().as_str());
+
+    // An empty local part would be invalid, so the normalized address is kept.
+    let canonicalized = email("+news@GMail.com").canonicalized();
+
+    assert_eq!("+news@gmail.com", canonicalized.as_str());
+    assert_eq!(canonicalized, canonicalized.as_str().parse().unwrap());
+}
@@ -0,0 +1,99 @@
+use vcard::{Collation, Filter, FilterTest, MatchType, ParamFilter, PropFilter, TextMatch, VCard};
+
+const INPUT: &str = "BEGIN:VCARD\r\nVERSION:4.0\r\nFN:Émile \
+                     Zola\r\nN:Zola;Émile;;;\r\nTEL;TYPE=cell:+33 1 23 45 67 \
+                     89\r\nTEL;TYPE=home:+33 9 87 65 43 21\r\nX-NICK:Mimi\r\nEND:VCARD\r\n";
+
+fn text_match(text: &str, collation: Collation, match_type: MatchType) -> TextMatch {
+    TextMatch {
+        text: String::from(text),
+        collation,
+        match_type,
+        negate: false,
+    }
+}
+
+#[test]
+fn collations_and_match_types() {
+    let unicode = text_match("ÉMILE", Collation::UnicodeCasemap, MatchType::StartsWith);
+    assert!(unicode.matches("émile zola"));
+
+    let ascii = text_match("ÉMILE", Collation::AsciiCasemap, MatchType::StartsWith);
+    assert!(!ascii.matches("émile zola"));
+    assert!(ascii.matches("Émile Zola"));
+
+    // Compatibility characters are decomposed by i;unicode-casemap.
+    assert!(text_match("len", Collation::UnicodeCasemap, MatchType::Equals).matches("ＬＥＮ"));
+
+    assert!(
+        text_match("zola", Collation::UnicodeCasemap, MatchType::EndsWith).matches("Émile Zola")
+    );
+    assert!(
+        !text_match("zola", Collation::UnicodeCasemap, MatchType::Equals).matches("Émile Zola")
+    );
+
+    // The simple case mapping of RFC 5051 keeps `ß` apart from `ss`.
+    assert!(!TextMatch::new("ss").matches("Straße"));
+    assert!(!TextMatch::new("ß").matches("ss"));
+    assert!(TextMatch::new("STRASSE").matches("strasse"));
+
+    let mut negated = TextMatch::new("zola");
+    negated.negate = true;
+    assert!(!negated.matches("Émile Zola"));
+    assert!(negated.matches("Magic Len"));
+}
+
+#[test]
+fn prop_and_param_filters() {
+    let vcard: VCard = INPUT.parse().unwrap();
+
+    let mut cell = PropFilter::new("tel");
+    cell.test = FilterTest::AllOf;
+    cell.text_matches.push(TextMatch::new("+33 1"));
+
+    let mut type_filter = ParamFilter::new("TYPE");
+    type_filter.text_match = Some(text_match("CELL", Collation::AsciiCasemap, MatchType::Equals));
+    cell.param_filters.push(type_filter);
+
+    assert!(cell.matches(&vcard));
+
+    // The number and the type have to be satisfied by the same property instance.
+    cell.text_matches[0] = TextMatch::new("+33 9");
+    assert!(!cell.matches(&vcard));
+
+    let mut missing = PropFilter::new("EMAIL");
+    missing.is_not_defined = true;
+    assert!(missing.matches(&vcard));
+
+    let mut pref = PropFilter::new("TEL");
+    let mut pref_param = ParamFilter::new("PREF");
+    pref_param.is_not_defined = true;
+    pref.param_filters.push(pref_param);
+    assert!(pref.matches(&vcard));
+
+    let mut extension = PropFilter::new("X-NICK");
+    extension.text_matches.push(TextMatch::new("mimi"));
+    assert!(extension.matches(&vcard));
+}
+
+#[test]
+fn filter_tests() {
+    let vcard: VCard = INPUT.parse().unwrap();
+
+    let mut name = PropFilter::new("N");
+    name.text_matches.push(TextMatch::new("zola"));
+
+    let mut email = PropFilter::new("EMAIL");
+    email.text_matches.push(TextMatch::new("@"));
+
+    let mut filter = Filter {
+        test: FilterTest::AnyOf, prop_filters: vec![name, email]
+    };
+
+    assert!(filter.matches(&vcard));
+
+    filter.test = FilterTest::AllOf;
+    assert!(!filter.matches(&vcard));
+
+    assert!(Filter::new().matches(&vcard));
+}
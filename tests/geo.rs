@@ -0,0 +1,174 @@
+use vcard::{
+    Address, BoundingBox, Geo, VCard,
+    values::{AddressValue, GeoUri, Uri},
+};
+
+fn geo(s: &str) -> GeoUri {
+    s.parse().unwrap()
+}
+
+fn vcard_at(name: &str, latitude: f64, longitude: f64) -> VCard {
+    let mut vcard = VCard::new(name);
+
+    vcard.geos.push(Geo::new(GeoUri::new(latitude, longitude).unwrap().into()));
+
+    vcard
+}
+
+#[test]
+fn parse_and_display() {
+    let uri = geo("geo:25.0330,121.5654");
+
+    assert_eq!(25.033, uri.latitude());
+    assert_eq!(121.5654, uri.longitude());
+    assert_eq!(None, uri.altitude());
+    assert_eq!(None, uri.uncertainty());
+    assert_eq!("wgs84", uri.crs());
+    assert_eq!("geo:25.033,121.5654", uri.to_string());
+
+    let uri = geo("GEO:48.2010,16.3695,183;CRS=WGS84;u=40;Foo=Bar;baz");
+
+    assert_eq!(Some(183.0), uri.altitude());
+    assert_eq!(Some(40.0), uri.uncertainty());
+    assert!(uri.is_wgs84());
+    assert_eq!("geo:48.201,16.3695,183;crs=wgs84;u=40;foo=Bar;baz", uri.to_string());
+    assert_eq!(uri, geo(&uri.to_string()));
+
+    let uri = GeoUri::new(-33.8688, 151.2093)
+        .unwrap()
+        .with_altitude(58.5)
+        .unwrap()
+        .with_uncertainty(5.0)
+        .unwrap();
+
+    assert_eq!("geo:-33.8688,151.2093,58.5;u=5", uri.to_string());
+    assert_eq!("geo:-33.8688,151.2093,58.5;u=5".parse::<Uri>().unwrap(), Uri::from(uri.clone()));
+    assert_eq!(uri, GeoUri::try_from(&uri.to_uri()).unwrap());
+
+    // Values that would not parse back are rejected.
+    let origin = GeoUri::new(0.0, 0.0).unwrap();
+
+    assert!(origin.clone().with_uncertainty(-1.0).is_err());
+    assert!(origin.clone().with_uncertainty(f64::NAN).is_err());
+    assert!(origin.clone().with_altitude(f64::INFINITY).is_err());
+    assert_eq!("geo:0,0,-10.5", origin.with_altitude(-10.5).unwrap().to_string());
+}
+
+#[test]
+fn parse_errors() {
+    for s in [
+        "",
+        "geo:",
+        "geo:25",
+        "geo:25,121,1,2",
+        "geo:25.,121",
+        "geo:+25,121",
+        "geo:1e3,121",
+        "geo:91,0",
+        "geo:0,180.5",
+        "geo:0,0;u=-1",
+        "geo:0,0;u",
+        "geo:0,0;u=1;u=2",
+        "geo:0,0;foo=bar;u=1",
+        "geo:0,0;foo=bar;crs=wgs84",
+        "geo:0,0;",
+        "http://example.com/",
+    ] {
+        assert!(s.parse::<GeoUri>().is_err(), "{s}");
+    }
+
+    assert!(GeoUri::new(-90.5, 0.0).is_err());
+    assert!(GeoUri::new(0.0, -181.0).is_err());
+
+    // The ranges of other reference systems are unknown.
+    let uri = geo("geo:4000,-2000;crs=Mars2000");
+
+    assert_eq!("mars2000", uri.crs());
+    assert!(!uri.is_wgs84());
+}
+
+#[test]
+fn distance() {
+    let taipei = GeoUri::new(25.033, 121.5654).unwrap();
+    let tokyo = GeoUri::new(35.6762, 139.6503).unwrap();
+
+    assert_eq!(Some(0.0), taipei.distance_to(&taipei));
+
+    let distance = taipei.distance_to(&tokyo).unwrap();
+
+    assert!((2_090_000.0..2_100_000.0).contains(&distance), "{distance}");
+    assert_eq!(Some(distance), tokyo.distance_to(&taipei));
+
+    // Across the antimeridian, a degree of longitude on the equator is about 111 km.
+    let distance = geo("geo:0,179.5").distance_to(&geo("geo:0,-179.5")).unwrap();
+
+    assert!((110_000.0..112_000.0).contains(&distance), "{distance}");
+
+    assert_eq!(None, taipei.distance_to(&geo("geo:25,121;crs=mars2000")));
+}
+
+#[test]
+fn bounding_box() {
+    let taipei = GeoUri::new(25.033, 121.5654).unwrap();
+    let bounding_box = BoundingBox::around(&taipei, 10_000.0);
+
+    assert!(bounding_box.south < 25.033 && 25.033 < bounding_box.north);
+    assert!(bounding_box.west < bounding_box.east);
+    assert!(bounding_box.contains(&taipei));
+    assert!(bounding_box.contains(&geo("geo:25.08,121.6")));
+    assert!(!bounding_box.contains(&geo("geo:25.2,121.5654")));
+    assert!(!bounding_box.contains(&geo("geo:25.033,121.8")));
+    assert!(!bounding_box.contains(&geo("geo:25.033,121.5654;crs=mars2000")));
+
+    // Fiji lies on the antimeridian.
+    let bounding_box = BoundingBox::around(&geo("geo:-17.7,179.9"), 50_000.0);
+
+    assert!(bounding_box.west > bounding_box.east);
+    assert!(bounding_box.contains(&geo("geo:-17.7,-179.9")));
+    assert!(bounding_box.contains(&geo("geo:-17.7,179.6")));
+    assert!(!bounding_box.contains(&geo("geo:-17.7,0")));
+
+    let bounding_box = BoundingBox::around(&geo("geo:89.9,0"), 50_000.0);
+
+    assert_eq!(90.0, bounding_box.north);
+    assert!(bounding_box.contains(&geo("geo:89.8,180")));
+}
+
+#[test]
+fn vcards_nearby() {
+    let mut with_address = VCard::new("Address");
+    let mut address = Address::new(AddressValue::default());
+
+    address.parameters.geo = Some("geo:25.05,121.55".parse().unwrap());
+    with_address.addresses.push(address);
+    with_address.geos.push(Geo::new("geo:1,2;crs=mars2000".parse().unwrap()));
+    with_address.geos.push(Geo::new("http://example.com/".parse().unwrap()));
+
+    assert_eq!(2, with_address.locations().len());
+
+    let mut vcards = vec![
+        VCard::new("Nowhere"),
+        vcard_at("Tokyo", 35.6762, 139.6503),
+        with_address,
+        vcard_at("Taipei 101", 25.0340, 121.5645),
+    ];
+
+    let me = GeoUri::new(25.0330, 121.5654).unwrap();
+
+    let names = |vcards: &[&VCard]| -> Vec<String> {
+        vcards.iter().map(|vcard| vcard.formatted_names[0].value.clone()).collect()
+    };
+
+    let nearby = VCard::within(&vcards, &BoundingBox::around(&me, 5_000.0));
+
+    assert_eq!(["Address", "Taipei 101"], names(&nearby).as_slice());
+    assert_eq!(None, vcards[0].distance_to(&me));
+    assert!(vcards[3].distance_to(&me).unwrap() < 200.0);
+
+    VCard::sort_by_distance(&mut vcards, &me);
+
+    assert_eq!(
+        ["Taipei 101", "Address", "Tokyo", "Nowhere"],
+        names(&vcards.iter().collect::<Vec<_>>()).as_slice()
+    );
+}
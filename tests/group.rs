@@ -0,0 +1,107 @@
+use vcard::{Email, GroupName, VCard, values::TelValue};
+
+const APPLE: &str = concat!(
+    "BEGIN:VCARD\r\n",
+    "VERSION:4.0\r\n",
+    "FN:Magic Len\r\n",
+    "item1.TEL:+886 2 1234 5678\r\n",
+    "item1.X-ABLabel:_$!<Work>!$_\r\n",
+    "item3.EMAIL:len@example.com\r\n",
+    "item3.X-ABLabel:School\\, Taipei\r\n",
+    "ITEM3.URL:https://example.com/\r\n",
+    "home.ADR:;;Street;City;;;\r\n",
+    "END:VCARD\r\n",
+);
+
+fn group(s: &str) -> GroupName {
+    s.parse().unwrap()
+}
+
+#[test]
+fn query_groups() {
+    let vcard: VCard = APPLE.parse().unwrap();
+
+    let groups: Vec<String> = vcard.groups().iter().map(|g| g.to_string()).collect();
+
+    assert_eq!(["home", "item1", "item3"], groups.as_slice());
+
+    let item1 = vcard.group(&group("item1"));
+
+    assert_eq!(1, item1.telephones.len());
+    assert_eq!(TelValue::Text(String::from("+886 2 1234 5678")), item1.telephones[0].value);
+    assert_eq!(1, item1.extensions.len());
+    assert!(item1.formatted_names.is_empty());
+    assert!(item1.emails.is_empty());
+
+    // Group names are case-insensitive.
+    let item3 = vcard.group(&group("Item3"));
+
+    assert_eq!(1, item3.emails.len());
+    assert_eq!(1, item3.urls.len());
+
+    assert_eq!(VCard::default(), vcard.group(&group("item2")));
+
+    assert_eq!(Some("Work"), vcard.label(&group("item1")).as_deref());
+    assert_eq!(Some("School, Taipei"), vcard.label(&group("ITEM3")).as_deref());
+    assert_eq!(None, vcard.label(&group("home")));
+}
+
+#[test]
+fn rename_and_renumber() {
+    let mut vcard: VCard = APPLE.parse().unwrap();
+
+    assert_eq!("item4", vcard.next_group().as_str());
+    assert_eq!(3, vcard.rename_group(&group("item3"), &group("item7")));
+    assert_eq!(0, vcard.rename_group(&group("item3"), &group("item8")));
+    assert_eq!("item8", vcard.next_group().as_str());
+
+    vcard.renumber_groups();
+
+    let groups: Vec<String> = vcard.groups().iter().map(|g| g.to_string()).collect();
+
+    assert_eq!(["home", "item1", "item2"], groups.as_slice());
+    assert_eq!(Some("School, Taipei"), vcard.label(&group("item2")).as_deref());
+    assert_eq!("item2", vcard.urls[0].group.as_ref().unwrap().as_str());
+
+    // Merging two groups.
+    assert_eq!(2, vcard.rename_group(&group("item1"), &group("item2")));
+    let merged = vcard.group(&group("item2"));
+
+    assert_eq!(1, merged.telephones.len());
+    assert_eq!(1, merged.emails.len());
+    assert_eq!(2, merged.extensions.len());
+}
+
+#[test]
+fn labels() {
+    let mut vcard = VCard::new("Magic Len");
+
+    vcard.emails.push(Email::new("len@example.com".parse().unwrap()));
+    vcard.emails.push(Email::new("magic@example.org".parse().unwrap()));
+
+    let first = vcard.attach_label(|vcard| &mut vcard.emails[0], "Home");
+    let second = vcard.attach_label(|vcard| &mut vcard.emails[1], "Club; Board");
+
+    assert_eq!("item1", first.as_str());
+    assert_eq!("item2", second.as_str());
+
+    // A property that has a group keeps it, and its label is replaced.
+    assert_eq!(first, vcard.attach_label(|vcard| &mut vcard.emails[0], "Personal"));
+    assert_eq!(2, vcard.extensions.len());
+    assert_eq!(Some("Personal"), vcard.label(&first).as_deref());
+
+    let serialized = vcard.to_string();
+
+    assert!(serialized.contains("item1.EMAIL:len@example.com\r\n"));
+    assert!(serialized.contains("item1.X-ABLabel:Personal\r\n"));
+    assert!(serialized.contains("item2.X-ABLabel:Club; Board\r\n"));
+
+    let parsed: VCard = serialized.parse().unwrap();
+
+    assert_eq!(Some("Club; Board"), parsed.label(&second).as_deref());
+
+    assert!(vcard.remove_label(&first));
+    assert!(!vcard.remove_label(&first));
+    assert_eq!(None, vcard.label(&first));
+    assert_eq!(1, vcard.extensions.len());
+}
@@ -0,0 +1,76 @@
+use vcard::{
+    Anniversary, Birthday, Calscale, LeapDayPolicy, Uid, VCard,
+    chrono::{TimeZone, Utc},
+    values::{Date, DateAndOrTimeOrText, TextOrUri},
+};
+
+#[test]
+fn occasion_calendar() {
+    let mut len = VCard::new("Magic Len");
+
+    len.uid =
+        Some(Uid::new(TextOrUri::from_uuid_str("f81d4fae-7dec-11d0-a765-00a0c91e6bf6").unwrap()));
+    len.birthday = Some(Birthday::new(Date::from_year_month_day(1992, 2, 29).unwrap().into()));
+    len.anniversary = Some(Anniversary::new(Date::from_month_day(6, 1).unwrap().into()));
+
+    let mut lunar = VCard::new("Lunar; Calendar");
+
+    lunar.birthday = Some(Birthday::new(Date::from_month_day(1, 15).unwrap().into()));
+
+    let mut skipped = lunar.clone();
+
+    skipped.birthday.as_mut().unwrap().parameters.calscale =
+        Some("x-lunar".parse::<Calscale>().unwrap());
+    skipped.anniversary = Some(Anniversary::new(DateAndOrTimeOrText::Text(String::from("spring"))));
+
+    let mut chinese = VCard::new("Chinese");
+
+    chinese.uid = Some(Uid::new(TextOrUri::Text(String::from("chinese"))));
+    chinese.birthday = Some(Birthday::new(Date::from_month_day(1, 15).unwrap().into()));
+    chinese.birthday.as_mut().unwrap().parameters.calscale =
+        Some("chinese".parse::<Calscale>().unwrap());
+
+    let calendar = VCard::occasion_calendar(&[len, lunar.clone(), skipped, chinese])
+        .with_timestamp(Utc.with_ymd_and_hms(2025, 1, 2, 3, 4, 5).unwrap())
+        .with_leap_day_policy(LeapDayPolicy::March1)
+        .to_string();
+
+    let events = [
+        "BEGIN:VEVENT\r\nUID:f81d4fae-7dec-11d0-a765-00a0c91e6bf6-birthday\r\nDTSTAMP:\
+         20250102T030405Z\r\nDTSTART;VALUE=DATE:19920229\r\nDTEND;VALUE=DATE:19920301\r\nRRULE:\
+         RSCALE=GREGORIAN;FREQ=YEARLY;SKIP=FORWARD\r\nSUMMARY:Birthday: Magic \
+         Len\r\nTRANSP:TRANSPARENT\r\nEND:VEVENT\r\n",
+        "BEGIN:VEVENT\r\nUID:f81d4fae-7dec-11d0-a765-00a0c91e6bf6-anniversary\r\nDTSTAMP:\
+         20250102T030405Z\r\nDTSTART;VALUE=DATE:20000601\r\nDTEND;VALUE=DATE:20000602\r\nRRULE:\
+         FREQ=YEARLY\r\nSUMMARY:Anniversary: Magic Len\r\nTRANSP:TRANSPARENT\r\nEND:VEVENT\r\n",
+    ];
+
+    assert!(calendar.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+    assert!(calendar.ends_with("END:VCALENDAR\r\n"));
+
+    for event in events {
+        assert!(calendar.contains(event), "{calendar}");
+    }
+
+    assert!(calendar.contains("SUMMARY:Birthday: Lunar\\; Calendar\r\n"));
+
+    // The Lantern Festival of Chinese year 2000.
+    assert!(calendar.contains("UID:chinese-birthday\r\nDTSTAMP:20250102T030405Z\r\n"));
+    assert!(calendar.contains("DTSTART;VALUE=DATE:20000219\r\nDTEND;VALUE=DATE:20000220\r\n"));
+    assert!(calendar.contains("RRULE:RSCALE=CHINESE;FREQ=YEARLY;SKIP=FORWARD\r\n"));
+    assert_eq!(4, calendar.matches("BEGIN:VEVENT").count());
+
+    // Without a UID, the event UID is derived from the name and the date, so it is stable across exports.
+    let uid_lines = |calendar: &str| {
+        calendar
+            .lines()
+            .filter(|line| line.starts_with("UID:"))
+            .map(str::to_string)
+            .collect::<Vec<_>>()
+    };
+
+    let again = VCard::occasion_calendar(&[lunar]).to_string();
+
+    assert_eq!(uid_lines(&calendar)[2], uid_lines(&again)[0]);
+    assert!(uid_lines(&again)[0].ends_with("-birthday"));
+}
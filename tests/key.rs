@@ -0,0 +1,193 @@
+use std::fs;
+
+use vcard::{
+    Key, KeyError, KeyKind, Pref, PublicKey, VCard,
+    base64::{Engine, engine::general_purpose::STANDARD},
+    chrono::{TimeZone, Utc},
+    mime::Mime,
+    values::{TextOrUri, Uri},
+};
+
+const OPENPGP: &str = "-----BEGIN PGP PUBLIC KEY BLOCK-----
+
+mDMEZZIAgBYJKwYBBAHaRw8BAQdA7AiKcD9pKVlK9ocljGlvRMjWKuBOdXBkc+Cl
+bREyfXW0G01hZ2ljIExlbiA8bGVuQGV4YW1wbGUuY29tPoiWBBMWCAA+FiEE90Oc
+vwO40g5ItcrRQxYe+GmqFJYFAmWSAIACGwEFCQtKgMAFCwkIBwIGFQoJCAsCBBYC
+AwECHgECF4AACgkQQxYe+GmqFJbn8wD+KXr28gRlwkzuiRGRFggyuq+B93yWdF5s
+7brAFsGDlfAA/AlnnWa2Yqsna/Hc9IB8hJ6z/swMHy9rUS8a15zCsOsJ
+=cyxK
+-----END PGP PUBLIC KEY BLOCK-----
+";
+
+const CERTIFICATE: &str = "-----BEGIN CERTIFICATE-----
+MIIB+DCCAaqgAwIBAgIUIl4tPXre2HGHtxKUBaC789PQUq8wBQYDK2VwMFkxCzAJ
+BgNVBAYTAlRXMRYwFAYDVQQKDA1FeGFtcGxlLCBJbmMuMRIwEAYDVQQDDAlNYWdp
+YyBMZW4xHjAcBgkqhkiG9w0BCQEWD2xlbkBleGFtcGxlLmNvbTAeFw0yNDAxMDEw
+MDAwMDBaFw0zNDAxMDEwMDAwMDBaMFkxCzAJBgNVBAYTAlRXMRYwFAYDVQQKDA1F
+eGFtcGxlLCBJbmMuMRIwEAYDVQQDDAlNYWdpYyBMZW4xHjAcBgkqhkiG9w0BCQEW
+D2xlbkBleGFtcGxlLmNvbTAqMAUGAytlcAMhAId3exF6d3S4fm5Ip94qCbFUSMwt
+HjQ/YOgP9ofhtcK9o4GDMIGAMB0GA1UdDgQWBBToFC2XDYkl8gLADNz46aPHmrmZ
+EzAfBgNVHSMEGDAWgBToFC2XDYkl8gLADNz46aPHmrmZEzAPBgNVHRMBAf8EBTAD
+AQH/MC0GA1UdEQQmMCSBD2xlbkBleGFtcGxlLmNvbYERbWFnaWNAZXhhbXBsZS5v
+cmcwBQYDK2VwA0EA+B3swCDTGxm2YE86HExarqVr2vs3gkRNFItp0cfN00KB8QqY
+vnGcvkC1iCvl7lhwtX0Ep5DuBuU3kRC5KSqsCQ==
+-----END CERTIFICATE-----
+";
+
+const SSH: &str = concat!(
+    "ssh-ed25519 ",
+    "AAAAC3NzaC1lZDI1NTE5AAAAIF+ipkPr1teFaC00d5XPjyj+oFTjakY93Z5B1Gyw+r14 ",
+    "len@example.com"
+);
+
+fn pem_body(pem: &str) -> Vec<u8> {
+    let body: String = pem.lines().filter(|line| !line.starts_with("-----")).collect();
+
+    STANDARD.decode(body).unwrap()
+}
+
+#[test]
+fn openpgp() {
+    let key = PublicKey::parse(OPENPGP.as_bytes()).unwrap();
+
+    assert_eq!(KeyKind::OpenPgp, key.kind);
+    assert_eq!("F7439CBF03B8D20E48B5CAD143161EF869AA1496", key.fingerprint_hex());
+    assert_eq!(["Magic Len <len@example.com>"], key.user_ids.as_slice());
+    assert_eq!(["len@example.com"], key.emails().as_slice());
+    assert_eq!(None, key.subject);
+    assert_eq!(Some(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()), key.created);
+    assert_eq!(Some(Utc.with_ymd_and_hms(2030, 1, 1, 12, 0, 0).unwrap()), key.expires);
+    assert!(!key.is_expired_at(&Utc.with_ymd_and_hms(2029, 12, 31, 0, 0, 0).unwrap()));
+    assert!(key.is_expired_at(&Utc.with_ymd_and_hms(2030, 1, 1, 12, 0, 0).unwrap()));
+
+    // The binary form decodes to the same key.
+    assert_eq!(key, PublicKey::parse(&key.data).unwrap());
+
+    let property = Key::new(TextOrUri::Uri(Uri::from_data(
+        &"application/pgp-keys".parse::<Mime>().unwrap(),
+        OPENPGP.as_bytes(),
+    )));
+
+    assert_eq!(key, property.public_key().unwrap());
+}
+
+#[test]
+fn x509() {
+    let key = PublicKey::parse(CERTIFICATE.as_bytes()).unwrap();
+
+    assert_eq!(KeyKind::X509, key.kind);
+    assert_eq!(pem_body(CERTIFICATE), key.data);
+    assert_eq!(
+        "8BDD8B03F3C61A536524DD1343472484486AD659E2996D078287A5D6908306EB",
+        key.fingerprint_hex()
+    );
+    assert_eq!(
+        Some("emailAddress=len@example.com,CN=Magic Len,O=Example\\, Inc.,C=TW"),
+        key.subject.as_deref()
+    );
+    assert_eq!(["len@example.com", "magic@example.org"], key.user_ids.as_slice());
+    assert_eq!(["len@example.com", "magic@example.org"], key.emails().as_slice());
+    assert_eq!(Some(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()), key.created);
+    assert_eq!(Some(Utc.with_ymd_and_hms(2034, 1, 1, 0, 0, 0).unwrap()), key.expires);
+
+    assert_eq!(key, PublicKey::parse(&key.data).unwrap());
+    assert_eq!(key, Key::new(TextOrUri::Text(CERTIFICATE.to_string())).public_key().unwrap());
+}
+
+#[test]
+fn ssh() {
+    let key = PublicKey::parse(SSH.as_bytes()).unwrap();
+
+    assert_eq!(KeyKind::Ssh, key.kind);
+    assert_eq!(
+        "BA7Ml4YpskOC35lDI7i7Vq0bEv+NoAGq2EXr3+yUQ4w",
+        STANDARD.encode(&key.fingerprint).trim_end_matches('=')
+    );
+    assert_eq!(["len@example.com"], key.user_ids.as_slice());
+    assert_eq!(None, key.created);
+    assert_eq!(None, key.expires);
+
+    let property = Key::from_public_key(&key);
+
+    assert_eq!(TextOrUri::Text(SSH.to_string()), property.value);
+    assert_eq!(None, property.parameters.media_type);
+}
+
+#[test]
+fn errors() {
+    assert_eq!(Err(KeyError::Unrecognized), PublicKey::parse(b"hello"));
+    assert_eq!(Err(KeyError::Unrecognized), PublicKey::parse(b""));
+    assert_eq!(
+        Err(KeyError::Malformed(KeyKind::OpenPgp)),
+        PublicKey::parse(OPENPGP.replace("mDME", "mDMX").as_bytes())
+    );
+    assert_eq!(
+        Err(KeyError::Malformed(KeyKind::X509)),
+        PublicKey::parse(&pem_body(CERTIFICATE)[..100])
+    );
+    assert_eq!(
+        Err(KeyError::Malformed(KeyKind::Ssh)),
+        PublicKey::parse(SSH.replacen("ssh-ed25519", "ssh-rsa", 1).as_bytes())
+    );
+
+    let external = Key::new(TextOrUri::Uri("https://example.com/key.asc".parse().unwrap()));
+
+    assert_eq!(Err(KeyError::NotEmbedded), external.public_key());
+}
+
+#[test]
+fn embed_key_files() {
+    let directory = std::env::temp_dir().join(format!("vcard-key-{}", std::process::id()));
+
+    fs::create_dir_all(&directory).unwrap();
+
+    let asc = directory.join("key.asc");
+    let pem = directory.join("cert.pem");
+    let text = directory.join("note.txt");
+
+    fs::write(&asc, OPENPGP).unwrap();
+    fs::write(&pem, CERTIFICATE).unwrap();
+    fs::write(&text, "not a key").unwrap();
+
+    let openpgp = Key::from_key_file(&asc).unwrap();
+
+    assert_eq!(
+        Some("application/pgp-keys"),
+        openpgp.parameters.media_type.as_ref().map(|m| m.essence_str())
+    );
+
+    let TextOrUri::Uri(uri) = &openpgp.value else { panic!("{:?}", openpgp.value) };
+    let (mime, data) = uri.data().unwrap();
+
+    assert_eq!("application/pgp-keys", mime.essence_str());
+    // The armor is removed.
+    assert_eq!(PublicKey::parse(OPENPGP.as_bytes()).unwrap().data, data);
+
+    let mut certificate = Key::from_key_file(&pem).unwrap();
+
+    assert_eq!(
+        Some("application/pkix-cert"),
+        certificate.parameters.media_type.as_ref().map(|m| m.essence_str())
+    );
+
+    assert_eq!(std::io::ErrorKind::InvalidData, Key::from_key_file(&text).unwrap_err().kind());
+
+    fs::remove_dir_all(&directory).unwrap();
+
+    let mut vcard = VCard::new("Magic Len");
+
+    certificate.parameters.pref = Some("1".parse::<Pref>().unwrap());
+
+    vcard.keys.push(Key::new(TextOrUri::Uri("https://example.com/key.asc".parse().unwrap())));
+    vcard.keys.push(openpgp);
+    vcard.keys.push(certificate);
+
+    let kinds: Vec<KeyKind> = vcard.public_keys().iter().map(|key| key.kind).collect();
+
+    assert_eq!([KeyKind::X509, KeyKind::OpenPgp], kinds.as_slice());
+
+    // The embedded keys survive a round trip through the text form.
+    let parsed: VCard = vcard.to_string().parse().unwrap();
+
+    assert_eq!(vcard.public_keys(), parsed.public_keys());
+}
@@ -0,0 +1,164 @@
+use std::{collections::HashMap, fs, io};
+
+use vcard::{
+    Key, Logo, MediaResolver, Photo, Sound, Uid, Url, VCard,
+    mime::{self, Mime},
+    values::{TextOrUri, Uri},
+};
+
+#[test]
+fn data_uri() {
+    let uri = Uri::from_data(&mime::IMAGE_JPEG, &[0xFF, 0xD8, 0xFF, 0x00]);
+
+    assert!(uri.is_data());
+    assert_eq!(Some((mime::IMAGE_JPEG, vec![0xFF, 0xD8, 0xFF, 0x00])), uri.data());
+
+    // Unpadded base64 with a folded line break inside.
+    let uri: Uri = "data:application/octet-stream;BASE64,AAEC%0D%0AAw".parse().unwrap();
+
+    assert_eq!(Some((mime::APPLICATION_OCTET_STREAM, vec![0, 1, 2, 3])), uri.data());
+
+    let uri: Uri = "data:,A%20brief%20note".parse().unwrap();
+    let (mime, data) = uri.data().unwrap();
+
+    assert_eq!(mime::TEXT_PLAIN, mime.essence_str());
+    assert_eq!(Some("us-ascii"), mime.get_param(mime::CHARSET).map(|charset| charset.as_str()));
+    assert_eq!(b"A brief note", data.as_slice());
+
+    let uri: Uri = "data:;charset=utf-8,%E5%8F%B0".parse().unwrap();
+    let (mime, data) = uri.data().unwrap();
+
+    assert_eq!(Some(mime::UTF_8), mime.get_param(mime::CHARSET));
+    assert_eq!("台", String::from_utf8(data).unwrap());
+
+    assert_eq!(None, "https://example.com/photo.jpg".parse::<Uri>().unwrap().data());
+    assert_eq!(None, "data:image/png;base64".parse::<Uri>().unwrap().data());
+    assert_eq!(None, "data:image/png;base64,!!!".parse::<Uri>().unwrap().data());
+}
+
+#[test]
+fn extract_media() {
+    let directory = std::env::temp_dir().join(format!("vcard-media-{}", std::process::id()));
+
+    fs::create_dir_all(&directory).unwrap();
+
+    let mut vcard = VCard::new("Magic Len");
+
+    vcard.photos.push(Photo::new(Uri::from_data(&mime::IMAGE_JPEG, b"first")));
+    vcard.photos.push(Photo::new("https://example.com/photo.jpg".parse().unwrap()));
+    vcard.photos.push(Photo::new(Uri::from_data(&mime::IMAGE_PNG, b"second")));
+    vcard.logos.push(Logo::new(Uri::from_data(&"image/svg+xml".parse().unwrap(), b"<svg/>")));
+    vcard.sounds.push(Sound::new(Uri::from_data(&"audio/mpeg".parse().unwrap(), b"sound")));
+
+    let names: Vec<String> = vcard
+        .extract_media(&directory)
+        .unwrap()
+        .iter()
+        .map(|path| path.file_name().unwrap().to_str().unwrap().to_string())
+        .collect();
+
+    assert_eq!(
+        vec![
+            "Magic_Len-photo.jpg",
+            "Magic_Len-photo-2.png",
+            "Magic_Len-logo.svg",
+            "Magic_Len-sound.mp3"
+        ],
+        names
+    );
+    assert_eq!(b"second", fs::read(directory.join("Magic_Len-photo-2.png")).unwrap().as_slice());
+
+    // A name without usable characters falls back to the UID.
+    let mut vcard = VCard::new("?");
+
+    vcard.uid = Some(Uid::new(
+        vcard::values::TextOrUri::from_uuid_str("f81d4fae-7dec-11d0-a765-00a0c91e6bf6").unwrap(),
+    ));
+    vcard.photos.push(Photo::new(Uri::from_data(&mime::IMAGE_GIF, b"GIF89a")));
+
+    let paths = vcard.extract_media(&directory).unwrap();
+
+    assert_eq!(
+        "f81d4fae-7dec-11d0-a765-00a0c91e6bf6-photo.gif",
+        paths[0].file_name().unwrap().to_str().unwrap()
+    );
+
+    fs::remove_dir_all(&directory).unwrap();
+}
+
+/// A content-addressed store that keeps the media in memory and only handles the PHOTO, LOGO, SOUND and KEY properties.
+#[derive(Default)]
+struct Store {
+    media: HashMap<String, (Mime, Vec<u8>)>,
+}
+
+fn is_media(property: &str) -> bool {
+    matches!(property, "PHOTO" | "LOGO" | "SOUND" | "KEY")
+}
+
+impl MediaResolver for Store {
+    fn store(
+        &mut self,
+        property: &str,
+        media_type: &Mime,
+        data: &[u8],
+        sha256: &[u8; 32],
+    ) -> Result<Option<Uri>, io::Error> {
+        if !is_media(property) {
+            return Ok(None);
+        }
+
+        let hex: String = sha256.iter().map(|byte| format!("{byte:02x}")).collect();
+        let uri = format!("cas:sha256:{hex}");
+
+        self.media.insert(uri.clone(), (media_type.clone(), data.to_vec()));
+
+        Ok(Some(uri.parse().unwrap()))
+    }
+
+    fn load(&mut self, property: &str, uri: &Uri) -> Result<Option<(Mime, Vec<u8>)>, io::Error> {
+        if !is_media(property) {
+            return Ok(None);
+        }
+
+        Ok(self.media.get(uri.as_str()).cloned())
+    }
+}
+
+#[test]
+fn externalize_and_inline() {
+    let mut vcard = VCard::new("Magic Len");
+
+    vcard.photos.push(Photo::new(Uri::from_data(&mime::IMAGE_PNG, b"abc")));
+    vcard.keys.push(Key::new(TextOrUri::Uri(Uri::from_data(
+        &"application/pgp-keys".parse().unwrap(),
+        b"key",
+    ))));
+    vcard.keys.push(Key::new(TextOrUri::Text(String::from("ssh-ed25519 AAAA"))));
+    vcard.urls.push(Url::new(Uri::from_data(&mime::TEXT_PLAIN, b"not media")));
+
+    let original = vcard.clone();
+    let mut store = Store::default();
+
+    let moved = vcard.externalize_media(&mut store).unwrap();
+
+    assert_eq!(2, moved.len());
+    assert_eq!("PHOTO", moved[0].property);
+    assert_eq!(3, moved[0].size);
+    assert_eq!(
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+        moved[0].sha256_hex()
+    );
+    assert_eq!(format!("cas:sha256:{}", moved[0].sha256_hex()), vcard.photos[0].value.as_str());
+    assert_eq!(Some(mime::IMAGE_PNG), vcard.photos[0].parameters.media_type);
+    assert_eq!("KEY", moved[1].property);
+    assert_eq!(TextOrUri::Uri(moved[1].uri.clone()), vcard.keys[0].value);
+    assert_eq!(original.keys[1], vcard.keys[1]);
+    assert_eq!(original.urls, vcard.urls);
+
+    let inlined = vcard.inline_media(&mut store).unwrap();
+
+    assert_eq!(moved, inlined);
+    assert_eq!(original.photos[0].value, vcard.photos[0].value);
+    assert_eq!(original.keys[0].value, vcard.keys[0].value);
+}
@@ -0,0 +1,97 @@
+use vcard::{Email, Note, Pid, Tel, VCard, values::TelValue};
+
+fn base_card() -> VCard {
+    let mut vcard = VCard::new("Magic Len");
+
+    let mut telephone = Tel::new(TelValue::Text(String::from("+886-2-1234-5678")));
+    telephone.parameters.pids.push(Pid {
+        id: 1, source: Some(1)
+    });
+    vcard.telephones.push(telephone);
+
+    vcard.emails.push(Email::new("len@magiclen.org".parse().unwrap()));
+
+    vcard
+}
+
+#[test]
+fn non_overlapping_edits() {
+    let base = base_card();
+
+    // We change the telephone number, which keeps its PID.
+    let mut ours = base.clone();
+    ours.telephones[0].value = TelValue::Text(String::from("+886-2-8765-4321"));
+
+    // They remove the email and add a note.
+    let mut theirs = base.clone();
+    theirs.emails.clear();
+    theirs.notes.push(Note::from("Met at a conference."));
+
+    let outcome = VCard::merge3(&base, &ours, &theirs);
+
+    assert!(outcome.is_clean());
+
+    let merged = outcome.merged;
+    assert_eq!(1, merged.telephones.len());
+    assert_eq!(TelValue::Text(String::from("+886-2-8765-4321")), merged.telephones[0].value);
+    assert!(merged.emails.is_empty());
+    assert_eq!("Met at a conference.", merged.notes[0].value);
+}
+
+#[test]
+fn both_sides_added_different_values() {
+    let base = base_card();
+
+    let mut ours = base.clone();
+    ours.notes.push(Note::from("Ours"));
+
+    let mut theirs = base.clone();
+    theirs.notes.push(Note::from("Theirs"));
+
+    // Notes without identifiers are matched by value, so both additions are kept.
+    let outcome = VCard::merge3(&base, &ours, &theirs);
+
+    assert!(outcome.is_clean());
+    assert_eq!(2, outcome.merged.notes.len());
+}
+
+#[test]
+fn conflicting_edits() {
+    let base = base_card();
+
+    let mut ours = base.clone();
+    ours.telephones[0].value = TelValue::Text(String::from("+886-2-1111-1111"));
+
+    let mut theirs = base.clone();
+    theirs.telephones[0].value = TelValue::Text(String::from("+886-2-2222-2222"));
+
+    let outcome = VCard::merge3(&base, &ours, &theirs);
+
+    assert_eq!(1, outcome.conflicts.len());
+
+    let conflict = &outcome.conflicts[0];
+    assert_eq!("TEL", conflict.name);
+    assert_eq!(Some("TEL;PID=1.1:+886-2-1234-5678"), conflict.base.as_deref());
+    assert_eq!(Some("TEL;PID=1.1:+886-2-1111-1111"), conflict.ours.as_deref());
+    assert_eq!(Some("TEL;PID=1.1:+886-2-2222-2222"), conflict.theirs.as_deref());
+
+    // The merged vCard keeps our side of a conflict.
+    assert_eq!(ours.telephones, outcome.merged.telephones);
+}
+
+#[test]
+fn delete_and_modify_conflict() {
+    let base = base_card();
+
+    let mut ours = base.clone();
+    ours.telephones.clear();
+
+    let mut theirs = base.clone();
+    theirs.telephones[0].value = TelValue::Text(String::from("+886-2-2222-2222"));
+
+    let outcome = VCard::merge3(&base, &ours, &theirs);
+
+    assert_eq!(1, outcome.conflicts.len());
+    assert_eq!(None, outcome.conflicts[0].ours);
+    assert!(outcome.merged.telephones.is_empty());
+}
@@ -0,0 +1,109 @@
+use vcard::{
+    Name,
+    values::{NameConvention, NameStyle, NameValue},
+};
+
+fn name(family: &[&str], given: &[&str]) -> NameValue {
+    NameValue {
+        family_names: family.iter().map(|s| s.to_string()).collect(),
+        given_names: given.iter().map(|s| s.to_string()).collect(),
+        ..NameValue::default()
+    }
+}
+
+#[test]
+fn western() {
+    let mut value = name(&["Public"], &["John"]);
+    value.additional_names.push(String::from("Quincy"));
+    value.honorific_prefixes.push(String::from("Dr."));
+    value.generation.push(String::from("Jr."));
+    value.honorific_suffixes.push(String::from("M.D."));
+
+    assert_eq!(
+        "Dr. John Quincy Public Jr., M.D.",
+        value.format(NameConvention::Western, NameStyle::Full)
+    );
+    assert_eq!("John Public", value.format(NameConvention::Western, NameStyle::Short));
+    assert_eq!("Dr. Public", value.format(NameConvention::Western, NameStyle::Formal));
+    assert_eq!(
+        "Public, John Quincy Jr.",
+        value.format(NameConvention::Western, NameStyle::SortKey)
+    );
+
+    assert_eq!("Cher", name(&[], &["Cher"]).format(NameConvention::Western, NameStyle::SortKey));
+}
+
+#[test]
+fn east_asian() {
+    let mut value = name(&["王"], &["大衛"]);
+
+    assert_eq!("王大衛", value.format(NameConvention::EastAsian, NameStyle::Full));
+
+    value.honorific_suffixes.push(String::from("先生"));
+    assert_eq!("王大衛先生", value.format(NameConvention::EastAsian, NameStyle::Full));
+    assert_eq!("王先生", value.format(NameConvention::EastAsian, NameStyle::Formal));
+    assert_eq!("王大衛", value.format(NameConvention::EastAsian, NameStyle::SortKey));
+
+    // Names in the Latin script keep their spaces.
+    assert_eq!(
+        "Nguyễn Văn An",
+        name(&["Nguyễn"], &["Văn", "An"]).format(NameConvention::EastAsian, NameStyle::Full)
+    );
+}
+
+#[test]
+fn hungarian_and_spanish() {
+    let mut value = name(&["Kovács"], &["János"]);
+    value.honorific_prefixes.push(String::from("Dr."));
+
+    assert_eq!("Dr. Kovács János", value.format(NameConvention::Hungarian, NameStyle::Full));
+    assert_eq!("Kovács János", value.format(NameConvention::Hungarian, NameStyle::Short));
+
+    let mut value = name(&["García"], &["Gabriel", "José"]);
+    value.surname2.push(String::from("Márquez"));
+
+    assert_eq!(
+        "Gabriel José García Márquez",
+        value.format(NameConvention::Spanish, NameStyle::Full)
+    );
+    assert_eq!("Gabriel José García", value.format(NameConvention::Spanish, NameStyle::Short));
+    assert_eq!(
+        "García Márquez, Gabriel José",
+        value.format(NameConvention::Spanish, NameStyle::SortKey)
+    );
+
+    // Two family names are the paternal and the maternal surnames.
+    let mut value = name(&["de la Fuente", "Ruiz"], &["Juan"]);
+    value.honorific_prefixes.push(String::from("Sr."));
+
+    assert_eq!(
+        "Sr. Juan de la Fuente Ruiz",
+        value.format(NameConvention::Spanish, NameStyle::Full)
+    );
+    assert_eq!("Juan de la Fuente", value.format(NameConvention::Spanish, NameStyle::Short));
+    assert_eq!("Sr. de la Fuente", value.format(NameConvention::Spanish, NameStyle::Formal));
+    assert_eq!(
+        "Fuente Ruiz, Juan de la",
+        value.format(NameConvention::Spanish, NameStyle::SortKey)
+    );
+    assert_eq!(
+        "de la Fuente Ruiz, Juan",
+        value.format(NameConvention::Western, NameStyle::SortKey)
+    );
+}
+
+#[test]
+fn to_formatted_name() {
+    let mut name = Name::new(name(&["Kovács"], &["János"]));
+    name.parameters.language = Some("hu".parse().unwrap());
+    name.parameters.altid = Some(String::from("1"));
+
+    let formatted_name = name.to_formatted_name();
+
+    assert_eq!("Kovács János", formatted_name.value);
+    assert_eq!(Some("hu"), formatted_name.parameters.language.as_ref().map(|tag| tag.as_str()));
+    assert_eq!(Some("1"), formatted_name.parameters.altid.as_deref());
+    assert_eq!(Some(true), formatted_name.parameters.derived);
+
+    assert_eq!(NameConvention::Spanish, NameConvention::from_language(&"es-MX".parse().unwrap()));
+}
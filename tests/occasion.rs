@@ -0,0 +1,139 @@
+use vcard::{
+    Anniversary, Birthday, Calscale, LeapDayPolicy, OccasionKind, VCard,
+    chrono::{NaiveDate, TimeZone, Utc},
+    values::{CalendarSystem, Date, DateAndOrTimeOrText},
+};
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn vcard(name: &str, birthday: Option<Date>) -> VCard {
+    let mut vcard = VCard::new(name);
+
+    vcard.birthday = birthday.map(|birthday| Birthday::new(birthday.into()));
+
+    vcard
+}
+
+#[test]
+fn next_occurrence() {
+    let birthday = DateAndOrTimeOrText::from(Date::from_month_day(2, 3).unwrap());
+
+    assert_eq!(
+        Some(date(2025, 2, 3)),
+        birthday.next_occurrence(date(2025, 2, 3), LeapDayPolicy::default())
+    );
+    assert_eq!(
+        Some(date(2026, 2, 3)),
+        birthday.next_occurrence(date(2025, 2, 4), LeapDayPolicy::default())
+    );
+    assert_eq!(None, birthday.years_on(date(2025, 2, 3), LeapDayPolicy::default()));
+
+    let leap_day = DateAndOrTimeOrText::from(Date::from_year_month_day(2000, 2, 29).unwrap());
+
+    assert_eq!(
+        Some(date(2025, 2, 28)),
+        leap_day.next_occurrence(date(2025, 1, 1), LeapDayPolicy::February28)
+    );
+    assert_eq!(
+        Some(date(2025, 3, 1)),
+        leap_day.next_occurrence(date(2025, 1, 1), LeapDayPolicy::March1)
+    );
+    assert_eq!(
+        Some(date(2104, 2, 29)),
+        leap_day.next_occurrence(date(2096, 3, 1), LeapDayPolicy::Skip)
+    );
+
+    assert_eq!(Some(25), leap_day.years_on(date(2025, 2, 28), LeapDayPolicy::February28));
+    assert_eq!(Some(24), leap_day.years_on(date(2025, 2, 28), LeapDayPolicy::Skip));
+    assert_eq!(Some(25), leap_day.years_on(date(2025, 3, 1), LeapDayPolicy::Skip));
+    assert_eq!(None, leap_day.years_on(date(1999, 3, 1), LeapDayPolicy::default()));
+
+    let text = DateAndOrTimeOrText::Text(String::from("circa 1800"));
+
+    assert_eq!(None, text.next_occurrence(date(2025, 1, 1), LeapDayPolicy::default()));
+}
+
+#[test]
+fn occasions() {
+    let mut vcard = vcard("Magic Len", Some(Date::from_year_month_day(1990, 6, 15).unwrap()));
+
+    vcard.anniversary =
+        Some(Anniversary::new(Date::from_year_month_day(2015, 5, 20).unwrap().into()));
+
+    let now = Utc.with_ymd_and_hms(2025, 5, 1, 12, 0, 0).unwrap();
+
+    let occasions = vcard.upcoming_occasions(&now, LeapDayPolicy::default());
+
+    assert_eq!(2, occasions.len());
+    assert_eq!(OccasionKind::Anniversary, occasions[0].kind);
+    assert_eq!(19, occasions[0].days_until);
+    assert_eq!(Some(10), occasions[0].years);
+    assert_eq!(OccasionKind::Birthday, occasions[1].kind);
+    assert_eq!(date(2025, 6, 15), occasions[1].date);
+    assert_eq!(Some(35), occasions[1].years);
+
+    assert_eq!(Some(34), vcard.age(&now, LeapDayPolicy::default()));
+
+    // An unknown calendar system cannot be computed.
+    vcard.birthday.as_mut().unwrap().parameters.calscale =
+        Some("x-lunar".parse::<Calscale>().unwrap());
+
+    assert_eq!(None, vcard.next_occasion(OccasionKind::Birthday, &now, LeapDayPolicy::default()));
+}
+
+#[test]
+fn other_calendars() {
+    // 15 Nisan 5750 in the Hebrew calendar, which was April 10, 1990.
+    let mut vcard = vcard("Hebrew", Some(Date::from_year_month_day(5750, 7, 15).unwrap()));
+
+    vcard.birthday.as_mut().unwrap().parameters.calscale =
+        Some("hebrew".parse::<Calscale>().unwrap());
+
+    let now = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+
+    let birthday =
+        vcard.next_occasion(OccasionKind::Birthday, &now, LeapDayPolicy::default()).unwrap();
+
+    assert_eq!(date(2025, 4, 13), birthday.date);
+    assert_eq!(Some(35), birthday.years);
+    assert_eq!(Some(34), vcard.age(&now, LeapDayPolicy::default()));
+
+    // 30 Kislev only exists in some Hebrew years, e.g. not in 5784.
+    let kislev = DateAndOrTimeOrText::from(Date::from_month_day(3, 30).unwrap());
+    let from = date(2023, 10, 1);
+
+    assert_eq!(
+        Some(date(2023, 12, 12)),
+        kislev.next_occurrence_in(CalendarSystem::Hebrew, from, LeapDayPolicy::February28)
+    );
+    assert_eq!(
+        Some(date(2023, 12, 13)),
+        kislev.next_occurrence_in(CalendarSystem::Hebrew, from, LeapDayPolicy::March1)
+    );
+    assert_eq!(
+        Some(date(2024, 12, 31)),
+        kislev.next_occurrence_in(CalendarSystem::Hebrew, from, LeapDayPolicy::Skip)
+    );
+}
+
+#[test]
+fn sort_by_upcoming() {
+    let mut vcards = vec![
+        vcard("No Birthday", None),
+        vcard("December", Some(Date::from_month_day(12, 1).unwrap())),
+        vcard("Year Only", Some(Date::from_year(1990))),
+        vcard("March", Some(Date::from_month_day(3, 1).unwrap())),
+        vcard("January", Some(Date::from_month_day(1, 10).unwrap())),
+    ];
+
+    let now = Utc.with_ymd_and_hms(2025, 2, 1, 0, 0, 0).unwrap();
+
+    VCard::sort_by_upcoming(&mut vcards, OccasionKind::Birthday, &now, LeapDayPolicy::default());
+
+    let names: Vec<&str> =
+        vcards.iter().map(|vcard| vcard.formatted_names[0].value.as_str()).collect();
+
+    assert_eq!(vec!["March", "December", "January", "No Birthday", "Year Only"], names);
+}
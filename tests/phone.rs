@@ -0,0 +1,67 @@
+use vcard::values::{PhoneFormat, PhoneNumber, TelValue, Uri};
+
+#[test]
+fn parse() {
+    let number: PhoneNumber = "+1 (650) 253-0000 ext. 123".parse().unwrap();
+
+    assert_eq!(1, number.country_code());
+    assert_eq!("6502530000", number.national_number());
+    assert_eq!(Some("123"), number.extension());
+    assert_eq!(Some("US"), number.region().as_deref());
+    assert!(number.is_valid());
+    assert_eq!("+16502530000", number.to_string());
+
+    // A national number needs a default region.
+    assert!("(650) 253-0000".parse::<PhoneNumber>().is_err());
+    assert_eq!(
+        number.to_e164(),
+        PhoneNumber::parse("(650) 253-0000", Some("us")).unwrap().to_e164()
+    );
+    assert!(PhoneNumber::parse("(650) 253-0000", Some("XX")).is_err());
+
+    let number = PhoneNumber::parse("tel:+39-06-1234-5678", None).unwrap();
+
+    assert_eq!(39, number.country_code());
+    assert_eq!("0612345678", number.national_number());
+}
+
+#[test]
+fn format() {
+    let number = PhoneNumber::parse("0912-345-678", Some("TW")).unwrap();
+
+    assert_eq!("+886912345678", number.format(PhoneFormat::E164));
+    assert_eq!("+886 912 345 678", number.format(PhoneFormat::International));
+    assert_eq!("0912 345 678", number.format(PhoneFormat::National));
+    assert_eq!("tel:+886-912-345-678", number.format(PhoneFormat::Rfc3966));
+    assert_eq!("tel:+886912345678", number.to_tel_uri().as_str());
+}
+
+#[test]
+fn tel_value() {
+    let text = TelValue::Text(String::from("(650) 253-0000 x123"));
+
+    assert_eq!(
+        TelValue::Text(String::from("+16502530000 ext. 123")),
+        text.normalized(Some("US")).unwrap()
+    );
+
+    let uri = text.to_uri(Some("US")).unwrap();
+
+    assert_eq!(TelValue::Uri("tel:+1-650-253-0000;ext=123".parse().unwrap()), uri);
+    assert_eq!(
+        TelValue::Uri("tel:+16502530000;ext=123".parse().unwrap()),
+        uri.normalized(None).unwrap()
+    );
+    assert_eq!(
+        TelValue::Text(String::from("+1 650-253-0000 ext. 123")),
+        uri.to_text(None).unwrap()
+    );
+
+    let sip = TelValue::Uri("sip:alice@example.com".parse::<Uri>().unwrap());
+
+    assert!(sip.phone_number(None).is_err());
+
+    let number = PhoneNumber::parse("+886912345678", None).unwrap();
+
+    assert_eq!(TelValue::Uri(number.to_tel_uri()), TelValue::from(number));
+}
@@ -0,0 +1,93 @@
+use vcard::{Email, Pref, Tel, TypeValue, VCard, values::TelValue};
+
+const CARD: &str = concat!(
+    "BEGIN:VCARD\r\n",
+    "VERSION:4.0\r\n",
+    "FN:Magic Len\r\n",
+    "TEL;TYPE=work,voice:+886 2 1234 5678\r\n",
+    "TEL;TYPE=cell,work;PREF=2:+886 912 345 678\r\n",
+    "TEL;TYPE=cell,home;PREF=1:+886 987 654 321\r\n",
+    "TEL;TYPE=cell,work;PREF=2:+886 911 111 111\r\n",
+    "EMAIL;TYPE=home:len@example.com\r\n",
+    "EMAIL;TYPE=work:magic@example.org\r\n",
+    "END:VCARD\r\n",
+);
+
+fn tel(property: &Tel) -> &str {
+    match &property.value {
+        TelValue::Text(text) => text,
+        TelValue::Uri(uri) => uri.as_str(),
+    }
+}
+
+#[test]
+fn ordering_and_filtering() {
+    let vcard: VCard = CARD.parse().unwrap();
+
+    let all: Vec<&str> = vcard.telephones_by_preference(&[]).into_iter().map(tel).collect();
+
+    // Ties keep their order, and properties without PREF come last.
+    assert_eq!(
+        ["+886 987 654 321", "+886 912 345 678", "+886 911 111 111", "+886 2 1234 5678"],
+        all.as_slice()
+    );
+
+    let cell_work: Vec<&str> = vcard
+        .telephones_by_preference(&[TypeValue::Cell, TypeValue::Work])
+        .into_iter()
+        .map(tel)
+        .collect();
+
+    assert_eq!(["+886 912 345 678", "+886 911 111 111"], cell_work.as_slice());
+
+    assert_eq!(Some("+886 987 654 321"), vcard.preferred_telephone(&[]).map(tel));
+    assert_eq!(Some("+886 2 1234 5678"), vcard.preferred_telephone(&[TypeValue::Voice]).map(tel));
+    assert_eq!(None, vcard.preferred_telephone(&[TypeValue::Fax]));
+
+    // Without PREF, the first one is preferred.
+    assert_eq!("len@example.com", vcard.preferred_email(&[]).unwrap().value.as_str());
+    assert_eq!(
+        "magic@example.org",
+        vcard.preferred_email(&[TypeValue::Work]).unwrap().value.as_str()
+    );
+    assert_eq!(2, vcard.emails_by_preference(&[]).len());
+}
+
+#[test]
+fn setting_preferred() {
+    let mut vcard: VCard = CARD.parse().unwrap();
+
+    VCard::set_preferred(&mut vcard.telephones, 3);
+
+    let prefs: Vec<Option<u8>> =
+        vcard.telephones.iter().map(|tel| tel.parameters.pref.map(|pref| pref.get())).collect();
+
+    assert_eq!([None, Some(3), Some(2), Some(1)], prefs.as_slice());
+    assert_eq!(Some("+886 911 111 111"), vcard.preferred_telephone(&[]).map(tel));
+
+    let parsed: VCard = vcard.to_string().parse().unwrap();
+
+    assert_eq!(Some("+886 911 111 111"), parsed.preferred_telephone(&[]).map(tel));
+
+    VCard::set_preferred(&mut vcard.emails, 1);
+
+    assert_eq!("magic@example.org", vcard.preferred_email(&[]).unwrap().value.as_str());
+    assert_eq!(None, vcard.emails[0].parameters.pref);
+
+    // The numbers stop at 100.
+    let mut emails: Vec<Email> = (0..120)
+        .map(|i| {
+            let mut email = Email::new(format!("user{i}@example.com").parse().unwrap());
+
+            email.parameters.pref = Some(Pref::new(1).unwrap());
+
+            email
+        })
+        .collect();
+
+    VCard::set_preferred(&mut emails, 119);
+
+    assert_eq!(Some(1), emails[119].parameters.pref.map(|pref| pref.get()));
+    assert_eq!(Some(2), emails[0].parameters.pref.map(|pref| pref.get()));
+    assert_eq!(Some(100), emails[118].parameters.pref.map(|pref| pref.get()));
+}
@@ -0,0 +1,39 @@
+use vcard::VCard;
+
+const INPUT: &str = "BEGIN:VCARD\r\nVERSION:4.0\r\nFN:Magic Len\r\nN:Len;Magic;;;\r\nTEL;TYPE=cell:+886-912-345-678\r\nPHOTO:https://example.com/len.png\r\nNOTE:Hello\r\nX-NICK:Mimi\r\nEND:VCARD\r\n";
+
+#[test]
+fn project() {
+    let vcard: VCard = INPUT.parse().unwrap();
+
+    let projected = vcard.project(&["tel", "PHOTO", "x-nick"]);
+
+    assert_eq!(
+        "BEGIN:VCARD\r\nVERSION:4.0\r\nFN:Magic Len\r\nPHOTO:https://example.com/len.png\r\nTEL;TYPE=cell:+886-912-345-678\r\nX-NICK:Mimi\r\nEND:VCARD\r\n",
+        projected.to_string()
+    );
+
+    assert_eq!(projected.to_string(), vcard.projected(&["tel", "PHOTO", "x-nick"]).to_string());
+}
+
+#[test]
+fn parse_projected() {
+    let vcard = VCard::parse_projected(INPUT, &["TEL"]).unwrap();
+
+    assert_eq!("Magic Len", vcard.formatted_names[0].value);
+    assert_eq!(1, vcard.telephones.len());
+    assert!(vcard.names.is_empty());
+    assert!(vcard.notes.is_empty());
+    assert!(vcard.extensions.is_empty());
+
+    // Skipped properties are never parsed, so their invalid values do not matter.
+    let input = INPUT.replace("NOTE:Hello", "BDAY:not a date");
+
+    assert!(input.parse::<VCard>().is_err());
+    assert!(VCard::parse_projected(&input, &["TEL"]).is_ok());
+
+    let vcards = VCard::parse_multiple_projected(&format!("{INPUT}{INPUT}"), &[]).unwrap();
+
+    assert_eq!(2, vcards.len());
+    assert_eq!(VCard::new("Magic Len"), vcards[1]);
+}
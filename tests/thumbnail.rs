@@ -0,0 +1,120 @@
+#![cfg(feature = "image")]
+
+use std::io::Cursor;
+
+use vcard::{
+    ImageEncoding, ImageOptions, ImageProcessingError, Logo, Photo, VCard,
+    image::{self, GenericImageView, ImageFormat, Rgba, RgbaImage},
+    mime,
+    values::Uri,
+};
+
+fn png(image: RgbaImage) -> Uri {
+    let mut bytes = Vec::new();
+
+    image.write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png).unwrap();
+
+    Uri::from_data(&mime::IMAGE_PNG, &bytes)
+}
+
+/// Noise compresses badly, so it needs both a lower quality and a smaller size to fit a budget.
+fn noise(width: u32, height: u32) -> RgbaImage {
+    let mut state = 0x2545_F491_u32;
+
+    RgbaImage::from_fn(width, height, |_, _| {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+
+        let [r, g, b, _] = state.to_le_bytes();
+
+        Rgba([r, g, b, 255])
+    })
+}
+
+#[test]
+fn shrink_photo() {
+    let mut photo = Photo::new(png(noise(1200, 800)));
+
+    photo.parameters.media_type = Some(mime::IMAGE_PNG);
+
+    photo.shrink_image(&ImageOptions::new(300).with_max_bytes(20_000)).unwrap();
+
+    let (mime, data) = photo.value.data().unwrap();
+
+    assert_eq!(mime::IMAGE_JPEG, mime);
+    assert_eq!(Some(mime::IMAGE_JPEG), photo.parameters.media_type);
+    assert!(data.len() <= 20_000);
+
+    let image = image::load_from_memory(&data).unwrap();
+
+    assert!(image.width() <= 300);
+    assert_eq!(image.width() * 2, image.height() * 3);
+}
+
+#[test]
+fn transparency() {
+    let transparent = RgbaImage::from_pixel(40, 20, Rgba([0, 0, 0, 0]));
+
+    let mut logo = Logo::new(png(transparent.clone()));
+
+    logo.shrink_image(&ImageOptions::default()).unwrap();
+
+    let (_, data) = logo.value.data().unwrap();
+    let image = image::load_from_memory(&data).unwrap();
+
+    // JPEG puts transparent pixels on white.
+    assert!(image.get_pixel(10, 10).0[..3].iter().all(|&c| c > 250));
+    assert_eq!(None, logo.parameters.media_type);
+
+    let mut logo = Logo::new(png(transparent));
+
+    logo.shrink_image(&ImageOptions::new(10).with_encoding(ImageEncoding::WebP)).unwrap();
+
+    let (mime, data) = logo.value.data().unwrap();
+    let image = image::load_from_memory(&data).unwrap();
+
+    assert_eq!("image/webp", mime.essence_str());
+    assert_eq!((10, 5), image.dimensions());
+    assert_eq!(0, image.get_pixel(3, 3).0[3]);
+}
+
+#[test]
+fn errors() {
+    let mut photo = Photo::new("https://example.com/photo.jpg".parse().unwrap());
+
+    assert!(matches!(
+        photo.shrink_image(&ImageOptions::default()),
+        Err(ImageProcessingError::NotEmbedded)
+    ));
+
+    let mut photo = Photo::new(Uri::from_data(&mime::IMAGE_PNG, b"not an image"));
+
+    assert!(matches!(
+        photo.shrink_image(&ImageOptions::default()),
+        Err(ImageProcessingError::Decode(_))
+    ));
+
+    let mut photo = Photo::new(png(noise(64, 64)));
+
+    assert!(matches!(
+        photo.shrink_image(&ImageOptions::default().with_max_bytes(10)),
+        Err(ImageProcessingError::OverBudget)
+    ));
+}
+
+#[test]
+fn shrink_images() {
+    let mut vcard = VCard::new("Magic Len");
+
+    vcard.photos.push(Photo::new("https://example.com/photo.jpg".parse().unwrap()));
+    vcard.photos.push(Photo::new(png(noise(100, 100))));
+
+    vcard.shrink_images(&ImageOptions::new(50)).unwrap();
+
+    assert_eq!("https://example.com/photo.jpg", vcard.photos[0].value.as_str());
+
+    let (_, data) = vcard.photos[1].value.data().unwrap();
+
+    assert_eq!((50, 50), image::load_from_memory(&data).unwrap().dimensions());
+}
@@ -0,0 +1,85 @@
+use vcard::{
+    Address, ResolvedTimeZone, TimeZone, TzParam, VCard,
+    chrono::{FixedOffset, TimeZone as _, Utc},
+    chrono_tz::{America, Asia, Europe},
+    values::{AddressValue, TzValue, UtcOffset},
+};
+
+fn text(s: &str) -> Option<ResolvedTimeZone> {
+    TzValue::Text(String::from(s)).resolve()
+}
+
+fn fixed(hours: i32, minutes: i32) -> Option<ResolvedTimeZone> {
+    Some(ResolvedTimeZone::Fixed(FixedOffset::east_opt(hours * 3600 + minutes * 60).unwrap()))
+}
+
+#[test]
+fn resolve_text() {
+    assert_eq!(Some(ResolvedTimeZone::Named(Asia::Taipei)), text("Asia/Taipei"));
+    assert_eq!(Some(ResolvedTimeZone::Named(Asia::Taipei)), text("asia/taipei"));
+    // Legacy names are links in the IANA database.
+    assert_eq!(Some(ResolvedTimeZone::Named(Asia::Calcutta)), text("Asia/Calcutta"));
+    assert_eq!(Some(ResolvedTimeZone::Named(America::New_York)), text("Eastern Standard Time"));
+    assert_eq!(Some(ResolvedTimeZone::Named(Europe::Berlin)), text("W. Europe Standard Time"));
+    assert_eq!(Some(ResolvedTimeZone::Named(America::Los_Angeles)), text("PDT"));
+
+    assert_eq!(fixed(8, 0), text("+08:00"));
+    assert_eq!(fixed(-5, 0), text("UTC-5"));
+    assert_eq!(fixed(5, 30), text("GMT+0530"));
+    assert_eq!(fixed(-3, -30), text("-03:30"));
+
+    assert_eq!(None, text(""));
+    assert_eq!(None, text("Mars/Olympus_Mons"));
+    assert_eq!(None, text("+25:00"));
+    assert_eq!(None, text("+é1"));
+    // BST is British Summer Time or Bangladesh Standard Time.
+    assert_eq!(None, text("BST"));
+    assert_eq!(None, text("UTC+1é"));
+    assert_eq!(None, text("+1:2:3"));
+}
+
+#[test]
+fn resolve_uri_and_offset() {
+    let uri =
+        TzValue::Uri("http://tzurl.org/zoneinfo/America/Argentina/Buenos_Aires".parse().unwrap());
+
+    assert_eq!(Some(ResolvedTimeZone::Named(America::Argentina::Buenos_Aires)), uri.resolve());
+
+    let offset = TzValue::UtcOffset(UtcOffset::new(true, 5, 0).unwrap());
+
+    assert_eq!(fixed(-5, 0), offset.resolve());
+}
+
+#[test]
+fn vcard_time_zone() {
+    let mut vcard = VCard::new("Magic Len");
+
+    assert_eq!(None, vcard.time_zone());
+
+    let mut address = Address::new(AddressValue::default());
+
+    address.parameters.tz = Some(TzParam::Text(String::from("Tokyo Standard Time")));
+    vcard.addresses.push(address);
+
+    assert_eq!(Some(ResolvedTimeZone::Named(Asia::Tokyo)), vcard.time_zone());
+
+    vcard.time_zones.push(TimeZone::new(TzValue::Text(String::from("Somewhere"))));
+    vcard.time_zones.push(TimeZone::new(TzValue::Text(String::from("Europe/Paris"))));
+
+    let mut preferred = TimeZone::new(TzValue::Text(String::from("America/New_York")));
+
+    preferred.parameters.pref = Some("1".parse().unwrap());
+    vcard.time_zones.push(preferred);
+
+    let tz = vcard.time_zone().unwrap();
+
+    assert_eq!(ResolvedTimeZone::Named(America::New_York), tz);
+    assert_eq!("America/New_York", tz.to_string());
+
+    // Daylight saving time is followed.
+    let summer = Utc.with_ymd_and_hms(2025, 7, 1, 12, 0, 0).unwrap();
+    let winter = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+
+    assert_eq!(-4 * 3600, tz.offset_at(&summer).local_minus_utc());
+    assert_eq!("2025-01-01 07:00:00 -05:00", tz.to_local(&winter).to_string());
+}
@@ -0,0 +1,138 @@
+use vcard::{
+    Created, Email, Note, Tel, TrackedVCard, VCard,
+    values::{TelValue, Timestamp},
+};
+
+fn at(s: &str) -> Timestamp {
+    s.parse().unwrap()
+}
+
+#[test]
+fn revision_and_created() {
+    let mut tracked = TrackedVCard::new(VCard::new("Magic Len"));
+
+    assert!(tracked.is_modified());
+    assert_eq!(None, tracked.saved());
+    assert!(tracked.save_at(at("20240101T000000Z")));
+
+    assert_eq!(Some(at("20240101T000000Z")), tracked.created.as_ref().map(|created| created.value));
+    assert_eq!(Some(at("20240101T000000Z")), tracked.revision.as_ref().map(|rev| rev.value));
+    assert_eq!(Some(&*tracked), tracked.saved());
+
+    // Nothing changed.
+    assert!(!tracked.is_modified());
+    assert!(!tracked.save_at(at("20240102T000000Z")));
+    assert_eq!(Some(at("20240101T000000Z")), tracked.revision.as_ref().map(|rev| rev.value));
+
+    tracked.notes.push(Note::new(String::from("Hello")));
+
+    assert!(tracked.save_at(at("20240103T000000Z")));
+    assert_eq!(Some(at("20240101T000000Z")), tracked.created.as_ref().map(|created| created.value));
+    assert_eq!(Some(at("20240103T000000Z")), tracked.revision.as_ref().map(|rev| rev.value));
+
+    tracked.notes.clear();
+
+    assert!(tracked.is_modified());
+
+    tracked.revert();
+
+    assert!(!tracked.is_modified());
+    assert_eq!(1, tracked.notes.len());
+
+    // Properties are not stamped unless enabled.
+    assert!(tracked.notes[0].parameters.created.is_none());
+
+    let vcard = tracked.into_inner();
+
+    assert_eq!(1, vcard.notes.len());
+}
+
+#[test]
+fn saved_vcards() {
+    let mut vcard = VCard::new("Magic Len");
+
+    vcard.created = Some(Created::new(at("20200101T000000Z")));
+
+    let mut tracked = TrackedVCard::from_saved(vcard.clone());
+
+    assert!(!tracked.is_modified());
+    assert!(!tracked.save_at(at("20240101T000000Z")));
+    assert_eq!(None, tracked.revision);
+
+    tracked.formatted_names[0].value = String::from("Len");
+
+    assert!(tracked.save_at(at("20240101T000000Z")));
+    assert_eq!(Some(at("20200101T000000Z")), tracked.created.as_ref().map(|created| created.value));
+
+    // A loaded vCard without CREATED does not get one, because it was not created now.
+    let mut tracked = TrackedVCard::from_saved(VCard::new("Magic Len"));
+
+    tracked.notes.push(Note::new(String::from("Hello")));
+
+    assert!(tracked.save_at(at("20240101T000000Z")));
+    assert_eq!(None, tracked.created);
+
+    // A new vCard keeps its own CREATED.
+    let mut tracked = TrackedVCard::from(vcard);
+
+    assert!(tracked.save_at(at("20240101T000000Z")));
+    assert_eq!(Some(at("20200101T000000Z")), tracked.created.as_ref().map(|created| created.value));
+}
+
+#[test]
+fn property_stamps() {
+    let mut vcard = VCard::new("Magic Len");
+
+    vcard.emails.push(Email::new("len@example.com".parse().unwrap()));
+
+    let mut tracked = TrackedVCard::from_saved(vcard)
+        .with_author("mailto:admin@example.com".parse().unwrap())
+        .with_author_name("Admin");
+
+    tracked.emails.push(Email::new("magic@example.org".parse().unwrap()));
+    tracked.telephones.push(Tel::new(TelValue::Text(String::from("+886 2 1234 5678"))));
+    tracked.formatted_names[0].value = String::from("Len");
+
+    assert!(tracked.save_at(at("20240101T000000Z")));
+
+    // The unchanged email address is not stamped.
+    assert_eq!(None, tracked.emails[0].parameters.created);
+    assert_eq!(None, tracked.emails[0].parameters.author);
+
+    for parameters in [
+        &tracked.emails[1].parameters,
+        &tracked.telephones[0].parameters,
+        &tracked.formatted_names[0].parameters,
+    ] {
+        assert_eq!(Some(at("20240101T000000Z")), parameters.created);
+        assert_eq!(
+            Some("mailto:admin@example.com"),
+            parameters.author.as_ref().map(|uri| uri.as_str())
+        );
+        assert_eq!(Some("Admin"), parameters.author_name.as_deref());
+    }
+
+    // REV itself is not stamped.
+    assert_eq!(None, tracked.revision.as_ref().unwrap().parameters.created);
+
+    // Modifying a stamped property keeps its CREATED parameter.
+    tracked.telephones[0].value = TelValue::Text(String::from("+886 2 8765 4321"));
+
+    assert!(tracked.save_at(at("20240202T000000Z")));
+    assert_eq!(Some(at("20240101T000000Z")), tracked.telephones[0].parameters.created);
+    assert_eq!(Some(at("20240202T000000Z")), tracked.revision.as_ref().map(|rev| rev.value));
+
+    // Duplicates are matched one by one.
+    let mut tracked = TrackedVCard::new(VCard::new("Magic Len")).with_property_stamps();
+
+    tracked.save_at(at("20240101T000000Z"));
+    tracked.notes.push(Note::new(String::from("Hello")));
+    tracked.save_at(at("20240102T000000Z"));
+    tracked.notes.push(Note::new(String::from("Hello")));
+    tracked.save_at(at("20240103T000000Z"));
+
+    assert_eq!(Some(at("20240101T000000Z")), tracked.formatted_names[0].parameters.created);
+    assert_eq!(Some(at("20240102T000000Z")), tracked.notes[0].parameters.created);
+    assert_eq!(Some(at("20240103T000000Z")), tracked.notes[1].parameters.created);
+    assert_eq!(None, tracked.notes[1].parameters.author);
+}
@@ -0,0 +1,124 @@
+use vcard::{
+    Email, Tel, Uid, VCard,
+    uuid::{Uuid, Version},
+    values::{TelValue, TextOrUri},
+};
+
+fn uri(s: &str) -> TextOrUri {
+    TextOrUri::Uri(s.parse().unwrap())
+}
+
+fn text(s: &str) -> TextOrUri {
+    TextOrUri::Text(String::from(s))
+}
+
+#[test]
+fn generate() {
+    let v4 = TextOrUri::new_uuid_v4();
+
+    assert!(matches!(&v4, TextOrUri::Uri(uri) if uri.as_str().starts_with("urn:uuid:")));
+    assert_eq!(Some(Version::Random), v4.uuid().unwrap().get_version());
+    assert_ne!(v4, TextOrUri::new_uuid_v4());
+
+    let earlier = TextOrUri::new_uuid_v7().uuid().unwrap();
+    let later = TextOrUri::new_uuid_v7().uuid().unwrap();
+
+    assert_eq!(Some(Version::SortRand), earlier.get_version());
+    assert!(earlier < later);
+
+    let v5 = TextOrUri::new_uuid_v5(&Uuid::NAMESPACE_DNS, b"example.com");
+
+    assert_eq!(uri("urn:uuid:cfbff0d1-9375-5685-968c-48ce8b15ae17"), v5);
+
+    assert_eq!(
+        TextOrUri::from_uuid_str("f81d4fae-7dec-11d0-a765-00a0c91e6bf6").unwrap(),
+        TextOrUri::from_uuid(Uuid::parse_str("F81D4FAE7DEC11D0A76500A0C91E6BF6").unwrap())
+    );
+}
+
+#[test]
+fn normalize() {
+    let expected = uri("urn:uuid:f81d4fae-7dec-11d0-a765-00a0c91e6bf6");
+
+    for value in [
+        uri("urn:uuid:f81d4fae-7dec-11d0-a765-00a0c91e6bf6"),
+        uri("URN:UUID:F81D4FAE-7DEC-11D0-A765-00A0C91E6BF6"),
+        text(" f81d4fae-7dec-11d0-a765-00a0c91e6bf6 "),
+        text("F81D4FAE7DEC11D0A76500A0C91E6BF6"),
+        text("{f81d4fae-7dec-11d0-a765-00a0c91e6bf6}"),
+        text("urn:uuid:F81D4FAE-7DEC-11D0-A765-00A0C91E6BF6"),
+    ] {
+        assert_eq!(expected, value.normalized_uid(), "{value:?}");
+    }
+
+    assert_eq!(
+        uri("https://example.com/contacts/1"),
+        uri("HTTPS://Example.COM/contacts/1").normalized_uid()
+    );
+    assert_eq!(
+        uri("https://example.com/contacts/1"),
+        text(" https://example.com/contacts/1").normalized_uid()
+    );
+    assert_eq!(text("contact-42"), text(" contact-42 ").normalized_uid());
+    assert_eq!(text("id: 42"), text("id: 42").normalized_uid());
+
+    assert_eq!(None, uri("urn:isbn:0451450523").uuid());
+    assert_eq!(None, text("contact-42").uuid());
+
+    let mut vcard = VCard::new("Magic Len");
+
+    vcard.normalize_uid();
+
+    assert_eq!(None, vcard.uid);
+
+    vcard.uid = Some(Uid::new(text("F81D4FAE-7DEC-11D0-A765-00A0C91E6BF6")));
+    vcard.normalize_uid();
+
+    assert_eq!(Some(expected), vcard.uid.map(|uid| uid.value));
+}
+
+#[test]
+fn ensure_uid() {
+    let mut vcard = VCard::new("Magic Len");
+
+    let uid = vcard.ensure_uid().clone();
+
+    assert_eq!(Some(Version::Random), uid.value.uuid().unwrap().get_version());
+    assert_eq!(&uid, vcard.ensure_uid());
+
+    let mut vcard = VCard::new("Magic Len");
+
+    vcard.uid = Some(Uid::new(text("contact-42")));
+
+    assert_eq!(text("contact-42"), vcard.ensure_uid().value);
+}
+
+#[test]
+fn derive_uid() {
+    let namespace = Uuid::NAMESPACE_URL;
+
+    let mut a = VCard::new("Magic Len");
+
+    a.emails.push(Email::new("len@example.com".parse().unwrap()));
+    a.emails.push(Email::new("magic@example.org".parse().unwrap()));
+    a.telephones.push(Tel::new(TelValue::Text(String::from("+886 2 1234 5678"))));
+
+    let mut b = VCard::new("Magic Len ");
+
+    b.emails.push(Email::new("Magic@Example.org".parse().unwrap()));
+    b.emails.push(Email::new("len@example.com".parse().unwrap()));
+    b.telephones.push(Tel::new(TelValue::Uri("tel:+886-2-1234-5678".parse().unwrap())));
+    // An existing UID does not affect the derived one.
+    b.uid = Some(Uid::new(TextOrUri::new_uuid_v4()));
+
+    let uid = a.derive_uid(&namespace);
+
+    assert_eq!(Some(Version::Sha1), uid.value.uuid().unwrap().get_version());
+    assert_eq!(uid, b.derive_uid(&namespace));
+    assert_ne!(uid, a.derive_uid(&Uuid::NAMESPACE_DNS));
+
+    b.telephones.clear();
+
+    assert_ne!(uid, b.derive_uid(&namespace));
+    assert_ne!(uid, VCard::new("Magic").derive_uid(&namespace));
+}